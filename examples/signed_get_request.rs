@@ -41,7 +41,7 @@ impl Signer for FtxSigner {
         Ok(FtxSignConfig {
             api_key: self.api_key.as_str(),
             time: Utc::now(),
-            method: Request::method(),
+            method: request.method(),
             path: request.path(),
         })
     }
@@ -110,7 +110,7 @@ impl RestRequest for FetchBalancesRequest {
         Cow::Borrowed("/api/wallet/balances")
     }
 
-    fn method() -> reqwest::Method {
+    fn method(&self) -> reqwest::Method {
         reqwest::Method::GET
     }
 }
@@ -0,0 +1,157 @@
+use std::borrow::Cow;
+
+use barter_integration::{
+    error::SocketError,
+    model::instrument::symbol::Symbol,
+    protocol::http::{
+        private::{
+            credentials::{Credentials, PassphraseHeaders},
+            encoder::Base64Encoder,
+            RequestSigner, Signer,
+        },
+        rest::{client::RestClient, RestRequest},
+        HttpParser,
+    },
+};
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{RequestBuilder, StatusCode};
+use serde::Deserialize;
+use thiserror::Error;
+
+struct OkxSigner {
+    credentials: Credentials,
+}
+
+// Configuration required to sign every Okx `RestRequest`
+struct OkxSignConfig<'a> {
+    credentials: &'a Credentials,
+    timestamp: String,
+    method: reqwest::Method,
+    path: Cow<'static, str>,
+}
+
+impl Signer for OkxSigner {
+    type Config<'a>
+        = OkxSignConfig<'a>
+    where
+        Self: 'a;
+
+    fn config<'a, Request>(
+        &'a self,
+        request: Request,
+        _: &RequestBuilder,
+    ) -> Result<Self::Config<'a>, SocketError>
+    where
+        Request: RestRequest,
+    {
+        Ok(OkxSignConfig {
+            credentials: &self.credentials,
+            timestamp: Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string(),
+            method: request.method(),
+            path: request.path(),
+        })
+    }
+
+    fn add_bytes_to_sign<M>(mac: &mut M, config: &Self::Config<'_>)
+    where
+        M: Mac,
+    {
+        mac.update(config.timestamp.as_bytes());
+        mac.update(config.method.as_str().as_bytes());
+        mac.update(config.path.as_bytes());
+    }
+
+    fn build_signed_request(
+        config: Self::Config<'_>,
+        builder: RequestBuilder,
+        signature: String,
+    ) -> Result<reqwest::Request, SocketError> {
+        // Add Okx required key, passphrase, timestamp & signature Headers & build reqwest::Request
+        PassphraseHeaders::OKX
+            .inject(builder, config.credentials, &config.timestamp, &signature)
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+struct OkxParser;
+
+impl HttpParser for OkxParser {
+    type ApiError = OkxError;
+    type OutputError = ExecutionError;
+
+    fn parse_api_error(&self, status: StatusCode, api_error: Self::ApiError) -> Self::OutputError {
+        // Parse Okx error code to determine custom ExecutionError variant
+        match api_error.code.as_str() {
+            "50111" | "50113" => ExecutionError::Unauthorised(api_error.msg),
+            _ => ExecutionError::Socket(SocketError::HttpResponse(status, api_error.msg)),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct OkxError {
+    code: String,
+    msg: String,
+}
+
+#[derive(Debug, Error)]
+enum ExecutionError {
+    #[error("request authorisation invalid: {0}")]
+    Unauthorised(String),
+
+    #[error("SocketError: {0}")]
+    Socket(#[from] SocketError),
+}
+
+struct FetchBalancesRequest;
+
+impl RestRequest for FetchBalancesRequest {
+    type Response = FetchBalancesResponse; // Define Response type
+    type QueryParams = (); // FetchBalances does not require any QueryParams
+    type Body = (); // FetchBalances does not require any Body
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/api/v5/asset/balances")
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct FetchBalancesResponse {
+    data: Vec<OkxBalance>,
+}
+
+#[derive(Deserialize)]
+#[allow(dead_code)]
+struct OkxBalance {
+    #[serde(rename = "ccy")]
+    symbol: Symbol,
+    #[serde(rename = "bal")]
+    total: String,
+}
+
+/// See Barter-Execution for a comprehensive real-life example, as well as code you can use out of the
+/// box to execute trades on many exchanges.
+#[tokio::main]
+async fn main() {
+    // Okx API key, secret & the passphrase chosen when the API key was created
+    let credentials = Credentials::new("api_key", "api_secret").with_passphrase("passphrase");
+
+    // HMAC-SHA256 encoded account API secret used for signing private http requests
+    let mac: Hmac<sha2::Sha256> = credentials.mac().unwrap();
+
+    // Build Okx configured RequestSigner for signing http requests with base64 encoding
+    let request_signer = RequestSigner::new(OkxSigner { credentials }, mac, Base64Encoder);
+
+    // Build RestClient with Okx configuration
+    let rest_client = RestClient::new("https://www.okx.com", request_signer, OkxParser);
+
+    // Fetch Result<FetchBalancesResponse, ExecutionError>
+    let _response = rest_client.execute(FetchBalancesRequest).await;
+}
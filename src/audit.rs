@@ -0,0 +1,361 @@
+use crate::{
+    error::SocketError,
+    protocol::websocket::WsMessage,
+    runtime::{self, Runtime},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fmt::{Debug, Formatter},
+    fs::OpenOptions,
+    io::{BufRead, BufReader, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+/// Hash used as the `prev_hash` of the first [`AuditEntry`] in a chain.
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// Channel an audited outbound message was sent over.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditChannel {
+    Rest,
+    WebSocket,
+}
+
+/// Single append-only [`AuditLog`] record of an outbound message.
+///
+/// Each entry commits to the hash of the previous entry, such that any modification, insertion
+/// or removal of entries is detectable via [`verify`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct AuditEntry {
+    pub sequence: u64,
+    pub time: DateTime<Utc>,
+    pub channel: AuditChannel,
+    /// Destination of the message. eg/ "POST https://api.exchange.com/order"
+    pub target: String,
+    pub payload: String,
+    pub prev_hash: String,
+    pub hash: String,
+}
+
+impl AuditEntry {
+    /// Compute the hex encoded SHA-256 hash committing to every field of this entry, and the
+    /// hash of the previous entry.
+    pub fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.prev_hash.as_bytes());
+        hasher.update(self.sequence.to_be_bytes());
+        hasher.update(self.time.to_rfc3339().as_bytes());
+        hasher.update([self.channel as u8]);
+        hasher.update((self.target.len() as u64).to_be_bytes());
+        hasher.update(self.target.as_bytes());
+        hasher.update(self.payload.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// Optional append-only, hash-chained log of every outbound order-class message (eg/ signed REST
+/// requests, WebSocket order messages), enabling exactly what was sent to a venue to be
+/// reconstructed for compliance purposes.
+///
+/// Entries are written as JSON lines. Cheaply cloneable, with every clone appending to the same
+/// chain.
+#[derive(Clone)]
+pub struct AuditLog {
+    inner: Arc<Mutex<AuditLogInner>>,
+}
+
+struct AuditLogInner {
+    writer: Box<dyn Write + Send>,
+    next_sequence: u64,
+    last_hash: String,
+}
+
+impl Debug for AuditLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let inner = self.inner.lock().expect("AuditLog Mutex poisoned");
+        f.debug_struct("AuditLog")
+            .field("next_sequence", &inner.next_sequence)
+            .field("last_hash", &inner.last_hash)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuditLog {
+    /// Construct a new [`AuditLog`] starting a fresh chain, appending entries to the provided
+    /// writer.
+    pub fn new<W>(writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        Self::with_state(Box::new(writer), 0, GENESIS_HASH.to_string())
+    }
+
+    /// Open an [`AuditLog`] file, creating it if it does not exist. If the file already
+    /// contains entries, the chain is continued from the last entry.
+    pub fn open<P>(path: P) -> Result<Self, SocketError>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+
+        let (next_sequence, last_hash) = match std::fs::File::open(path) {
+            Ok(file) => match last_entry(BufReader::new(file))? {
+                Some(entry) => (entry.sequence + 1, entry.hash),
+                None => (0, GENESIS_HASH.to_string()),
+            },
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                (0, GENESIS_HASH.to_string())
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(Self::with_state(Box::new(file), next_sequence, last_hash))
+    }
+
+    fn with_state(writer: Box<dyn Write + Send>, next_sequence: u64, last_hash: String) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(AuditLogInner {
+                writer,
+                next_sequence,
+                last_hash,
+            })),
+        }
+    }
+
+    /// Append an [`AuditEntry`] for the provided outbound message to the chain.
+    ///
+    /// Blocks the calling thread until the entry is written and flushed, so must not be called
+    /// from an async context - see [`Self::append`].
+    pub fn record<Target, Payload>(
+        &self,
+        channel: AuditChannel,
+        target: Target,
+        payload: Payload,
+    ) -> Result<AuditEntry, SocketError>
+    where
+        Target: Into<String>,
+        Payload: Into<String>,
+    {
+        let mut inner = self.inner.lock().expect("AuditLog Mutex poisoned");
+
+        let mut entry = AuditEntry {
+            sequence: inner.next_sequence,
+            time: Utc::now(),
+            channel,
+            target: target.into(),
+            payload: payload.into(),
+            prev_hash: inner.last_hash.clone(),
+            hash: String::new(),
+        };
+        entry.hash = entry.compute_hash();
+
+        let mut line = serde_json::to_vec(&entry).map_err(SocketError::Serialise)?;
+        line.push(b'\n');
+        inner.writer.write_all(&line)?;
+        inner.writer.flush()?;
+
+        inner.next_sequence += 1;
+        inner.last_hash.clone_from(&entry.hash);
+
+        Ok(entry)
+    }
+
+    /// Append an [`AuditEntry`] for the provided outbound message to the chain, performing the
+    /// blocking write & flush via [`Runtime::spawn_blocking`].
+    ///
+    /// Resolves once the entry is durably written, such that the message can then be sent.
+    pub async fn append<Target, Payload>(
+        &self,
+        channel: AuditChannel,
+        target: Target,
+        payload: Payload,
+        runtime: &dyn Runtime,
+    ) -> Result<AuditEntry, SocketError>
+    where
+        Target: Into<String>,
+        Payload: Into<String>,
+    {
+        let log = self.clone();
+        let target = target.into();
+        let payload = payload.into();
+
+        runtime::spawn_blocking(runtime, move || log.record(channel, target, payload))
+            .await
+            .map_err(|error| SocketError::Io(std::io::Error::other(error)))?
+    }
+
+    /// Append an [`AuditEntry`] for the provided [`reqwest::Request`] that is about to be sent.
+    pub async fn record_http(
+        &self,
+        request: &reqwest::Request,
+        runtime: &dyn Runtime,
+    ) -> Result<AuditEntry, SocketError> {
+        let payload = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .map(String::from_utf8_lossy)
+            .unwrap_or_default()
+            .into_owned();
+
+        self.append(
+            AuditChannel::Rest,
+            format!("{} {}", request.method(), request.url()),
+            payload,
+            runtime,
+        )
+        .await
+    }
+
+    /// Append an [`AuditEntry`] for the provided outbound [`WsMessage`] that is about to be sent.
+    pub async fn record_ws<Target>(
+        &self,
+        target: Target,
+        message: &WsMessage,
+        runtime: &dyn Runtime,
+    ) -> Result<AuditEntry, SocketError>
+    where
+        Target: Into<String>,
+    {
+        let payload = match message {
+            WsMessage::Text(text) => text.clone(),
+            WsMessage::Binary(binary) => hex::encode(binary),
+            other => format!("{other:?}"),
+        };
+
+        self.append(AuditChannel::WebSocket, target, payload, runtime)
+            .await
+    }
+}
+
+/// Verify the integrity of an [`AuditLog`] chain, returning the number of valid entries.
+pub fn verify<Reader>(reader: Reader) -> Result<u64, SocketError>
+where
+    Reader: BufRead,
+{
+    let mut expected_prev = GENESIS_HASH.to_string();
+    let mut count = 0;
+
+    for entry in entries(reader) {
+        let entry = entry?;
+        if entry.sequence != count
+            || entry.prev_hash != expected_prev
+            || entry.hash != entry.compute_hash()
+        {
+            return Err(SocketError::AuditChain {
+                sequence: entry.sequence,
+            });
+        }
+        expected_prev = entry.hash;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Iterate over the [`AuditEntry`]s of an [`AuditLog`].
+pub fn entries<Reader>(reader: Reader) -> impl Iterator<Item = Result<AuditEntry, SocketError>>
+where
+    Reader: BufRead,
+{
+    reader
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| {
+            let line = line?;
+            serde_json::from_str(&line).map_err(|error| SocketError::Deserialise {
+                error,
+                payload: line,
+            })
+        })
+}
+
+fn last_entry<Reader>(reader: Reader) -> Result<Option<AuditEntry>, SocketError>
+where
+    Reader: BufRead,
+{
+    entries(reader).try_fold(None, |_, entry| entry.map(Some))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::TokioRuntime;
+    use std::io::Cursor;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_hash_chain() {
+        let buffer = SharedBuffer::default();
+        let log = AuditLog::new(buffer.clone());
+
+        let first = log
+            .record(AuditChannel::Rest, "POST /order", r#"{"qty":1}"#)
+            .unwrap();
+        let second = log
+            .record_ws(
+                "wss://venue",
+                &WsMessage::text(r#"{"op":"cancel"}"#),
+                &TokioRuntime,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(first.prev_hash, GENESIS_HASH);
+        assert_eq!(second.prev_hash, first.hash);
+        assert_eq!(second.sequence, 1);
+
+        let written = buffer.0.lock().unwrap().clone();
+        assert_eq!(verify(Cursor::new(&written)).unwrap(), 2);
+
+        // Tampering with any payload breaks the chain
+        let tampered = String::from_utf8(written)
+            .unwrap()
+            .replace(r#"{\"qty\":1}"#, r#"{\"qty\":9}"#);
+        assert!(matches!(
+            verify(Cursor::new(tampered)),
+            Err(SocketError::AuditChain { sequence: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_audit_log_open_resumes_chain() {
+        let path =
+            std::env::temp_dir().join(format!("barter_audit_{}.jsonl", rand::random::<u64>()));
+
+        let first = AuditLog::open(&path)
+            .unwrap()
+            .record(AuditChannel::Rest, "POST /order", r#"{"qty":1}"#)
+            .unwrap();
+
+        // Re-opening continues the chain from the last persisted entry
+        let reopened = AuditLog::open(&path).unwrap();
+        let second = reopened
+            .record(AuditChannel::Rest, "DELETE /order", r#"{"id":1}"#)
+            .unwrap();
+        assert_eq!(second.sequence, 1);
+        assert_eq!(second.prev_hash, first.hash);
+
+        let file = BufReader::new(std::fs::File::open(&path).unwrap());
+        assert_eq!(verify(file).unwrap(), 2);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
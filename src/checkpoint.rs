@@ -0,0 +1,258 @@
+use crate::{error::SocketError, model::SubscriptionId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+use tracing::debug;
+
+/// Last processed position of a subscription.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Checkpoint {
+    /// Exchange sequence number of the last processed message, if the exchange provides one.
+    pub sequence: Option<u64>,
+
+    /// Time of the last processed message.
+    pub time: DateTime<Utc>,
+}
+
+impl Checkpoint {
+    /// Construct a new [`Checkpoint`].
+    pub fn new(sequence: Option<u64>, time: DateTime<Utc>) -> Self {
+        Self { sequence, time }
+    }
+}
+
+/// Window of data that was not processed between a restored [`Checkpoint`] and the first
+/// message received after a restart.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct DataLossWindow {
+    pub subscription: SubscriptionId,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Sequence numbers known to be missing, if the exchange provides sequence numbers.
+    pub missing_sequences: Option<RangeInclusive<u64>>,
+}
+
+/// Pluggable persistent storage for [`Checkpoint`]s (eg/ file, sled, Redis).
+#[async_trait]
+pub trait CheckpointStore {
+    /// Load every persisted [`Checkpoint`].
+    async fn load(&self) -> Result<HashMap<SubscriptionId, Checkpoint>, SocketError>;
+
+    /// Persist the provided [`Checkpoint`]s, replacing any previously persisted state.
+    async fn save(
+        &self,
+        checkpoints: &HashMap<SubscriptionId, Checkpoint>,
+    ) -> Result<(), SocketError>;
+}
+
+/// [`CheckpointStore`] that persists [`Checkpoint`]s as JSON in a single file.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct FileCheckpointStore {
+    pub path: PathBuf,
+}
+
+impl FileCheckpointStore {
+    /// Construct a new [`FileCheckpointStore`] that persists to the provided file path.
+    pub fn new<Path>(path: Path) -> Self
+    where
+        Path: Into<PathBuf>,
+    {
+        Self { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl CheckpointStore for FileCheckpointStore {
+    async fn load(&self) -> Result<HashMap<SubscriptionId, Checkpoint>, SocketError> {
+        let payload = match tokio::fs::read_to_string(&self.path).await {
+            Ok(payload) => payload,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                debug!(path = ?self.path, "no checkpoint file found, starting from empty");
+                return Ok(HashMap::new());
+            }
+            Err(error) => return Err(SocketError::from(error)),
+        };
+
+        serde_json::from_str(&payload).map_err(|error| SocketError::Deserialise { error, payload })
+    }
+
+    async fn save(
+        &self,
+        checkpoints: &HashMap<SubscriptionId, Checkpoint>,
+    ) -> Result<(), SocketError> {
+        let payload = serde_json::to_vec(checkpoints).map_err(SocketError::Serialise)?;
+
+        // Write to a temporary file and rename so a crash mid-write never corrupts the checkpoint
+        let temp = self.path.with_extension("tmp");
+        tokio::fs::write(&temp, payload).await?;
+        tokio::fs::rename(&temp, &self.path).await?;
+        Ok(())
+    }
+}
+
+/// In-memory [`CheckpointStore`], useful for testing.
+#[derive(Clone, Debug, Default)]
+pub struct InMemoryCheckpointStore {
+    checkpoints: Arc<Mutex<HashMap<SubscriptionId, Checkpoint>>>,
+}
+
+#[async_trait]
+impl CheckpointStore for InMemoryCheckpointStore {
+    async fn load(&self) -> Result<HashMap<SubscriptionId, Checkpoint>, SocketError> {
+        Ok(self
+            .checkpoints
+            .lock()
+            .expect("InMemoryCheckpointStore Mutex poisoned")
+            .clone())
+    }
+
+    async fn save(
+        &self,
+        checkpoints: &HashMap<SubscriptionId, Checkpoint>,
+    ) -> Result<(), SocketError> {
+        checkpoints.clone_into(
+            &mut self
+                .checkpoints
+                .lock()
+                .expect("InMemoryCheckpointStore Mutex poisoned"),
+        );
+        Ok(())
+    }
+}
+
+/// Records the last processed [`Checkpoint`] of each subscription, persisting them to a
+/// [`CheckpointStore`].
+///
+/// On restart, the [`Checkpoint`]s restored from the [`CheckpointStore`] are used to determine
+/// the precise [`DataLossWindow`] of each subscription, driving gap-fill logic.
+#[derive(Debug)]
+pub struct Checkpointer<Store> {
+    store: Store,
+    restored: HashMap<SubscriptionId, Checkpoint>,
+    checkpoints: HashMap<SubscriptionId, Checkpoint>,
+}
+
+impl<Store> Checkpointer<Store>
+where
+    Store: CheckpointStore,
+{
+    /// Construct a new [`Checkpointer`], restoring any [`Checkpoint`]s persisted in the provided
+    /// [`CheckpointStore`].
+    pub async fn restore(store: Store) -> Result<Self, SocketError> {
+        let restored = store.load().await?;
+        Ok(Self {
+            store,
+            checkpoints: restored.clone(),
+            restored,
+        })
+    }
+
+    /// Record the latest processed [`Checkpoint`] for a subscription. Stale [`Checkpoint`]s that
+    /// precede the currently recorded [`Checkpoint`] are ignored.
+    pub fn record(&mut self, subscription: &SubscriptionId, checkpoint: Checkpoint) {
+        match self.checkpoints.get_mut(subscription) {
+            Some(current) if *current >= checkpoint => {}
+            Some(current) => *current = checkpoint,
+            None => {
+                self.checkpoints.insert(subscription.clone(), checkpoint);
+            }
+        }
+    }
+
+    /// Latest recorded [`Checkpoint`] for a subscription.
+    pub fn get(&self, subscription: &SubscriptionId) -> Option<&Checkpoint> {
+        self.checkpoints.get(subscription)
+    }
+
+    /// [`Checkpoint`] for a subscription that was restored from the [`CheckpointStore`].
+    pub fn restored(&self, subscription: &SubscriptionId) -> Option<&Checkpoint> {
+        self.restored.get(subscription)
+    }
+
+    /// Persist every recorded [`Checkpoint`] to the [`CheckpointStore`].
+    pub async fn flush(&self) -> Result<(), SocketError> {
+        self.store.save(&self.checkpoints).await
+    }
+
+    /// Determine the [`DataLossWindow`] between the restored [`Checkpoint`] of a subscription and
+    /// the first message received after restarting.
+    ///
+    /// Returns `None` if there is no restored [`Checkpoint`], or no data was lost.
+    pub fn data_loss_window(
+        &self,
+        subscription: &SubscriptionId,
+        first_received: &Checkpoint,
+    ) -> Option<DataLossWindow> {
+        let restored = self.restored.get(subscription)?;
+
+        let missing_sequences = match (restored.sequence, first_received.sequence) {
+            (Some(last), Some(next)) if next > last.saturating_add(1) => Some(last + 1..=next - 1),
+            (Some(_), Some(_)) => return None,
+            _ => None,
+        };
+
+        (first_received.time > restored.time).then(|| DataLossWindow {
+            subscription: subscription.clone(),
+            from: restored.time,
+            to: first_received.time,
+            missing_sequences,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_checkpointer_restore_and_data_loss_window() {
+        let path =
+            std::env::temp_dir().join(format!("barter_checkpoint_{}.json", rand::random::<u64>()));
+        let store = FileCheckpointStore::new(&path);
+        let subscription = SubscriptionId::from("btcusdt@depth");
+
+        // Record & flush checkpoints before "restarting"
+        let mut checkpointer = Checkpointer::restore(store.clone()).await.unwrap();
+        checkpointer.record(&subscription, Checkpoint::new(Some(10), time(100)));
+        checkpointer.record(&subscription, Checkpoint::new(Some(9), time(90)));
+        checkpointer.flush().await.unwrap();
+
+        let checkpointer = Checkpointer::restore(store).await.unwrap();
+        assert_eq!(
+            checkpointer.restored(&subscription),
+            Some(&Checkpoint::new(Some(10), time(100)))
+        );
+
+        // Contiguous sequence implies no data loss
+        let contiguous = Checkpoint::new(Some(11), time(200));
+        assert_eq!(
+            checkpointer.data_loss_window(&subscription, &contiguous),
+            None
+        );
+
+        // Sequence gap implies data loss
+        let gapped = Checkpoint::new(Some(15), time(200));
+        assert_eq!(
+            checkpointer.data_loss_window(&subscription, &gapped),
+            Some(DataLossWindow {
+                subscription: subscription.clone(),
+                from: time(100),
+                to: time(200),
+                missing_sequences: Some(11..=14),
+            })
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
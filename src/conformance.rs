@@ -0,0 +1,287 @@
+use crate::error::SocketError;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+/// Runs a new integration's deserialisation structs against a directory of captured real
+/// exchange payloads, reporting coverage to accelerate the development of new exchange modules.
+///
+/// Each file in the directory may contain a single JSON payload, or many newline delimited JSON
+/// payloads.
+///
+/// eg/ Determining which enum variants were hit, which fields are always null, and which fields
+/// the exchange sends that are not modelled.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ResponseValidator {
+    pub dir: PathBuf,
+}
+
+/// Coverage report generated by a [`ResponseValidator`].
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ConformanceReport {
+    /// Number of payloads validated.
+    pub payloads: usize,
+
+    /// Payloads that failed to deserialise.
+    pub failures: Vec<ConformanceFailure>,
+
+    /// Number of successfully deserialised payloads per variant.
+    pub variants: BTreeMap<String, usize>,
+
+    /// Payload field paths that were null in every payload they were present in.
+    pub always_null: BTreeSet<String>,
+
+    /// Payload field paths that are not modelled by the deserialisation struct, and the number of
+    /// payloads they were present in.
+    pub unknown_fields: BTreeMap<String, usize>,
+
+    /// Payload field paths that have been observed with a non-null value.
+    non_null: BTreeSet<String>,
+}
+
+/// Captured payload that failed to deserialise.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct ConformanceFailure {
+    /// Source of the payload. eg/ "captures/trades.json:3"
+    pub source: String,
+    /// Path of the field that failed to deserialise. eg/ "data[0].price"
+    pub path: String,
+    pub error: String,
+}
+
+impl ResponseValidator {
+    /// Construct a new [`ResponseValidator`] for the directory of captured payloads.
+    pub fn new<P>(dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { dir: dir.into() }
+    }
+
+    /// Validate every captured payload against the `Response` deserialisation struct.
+    pub fn run<Response>(&self) -> Result<ConformanceReport, SocketError>
+    where
+        Response: DeserializeOwned + Serialize,
+    {
+        self.run_with_variants::<Response, _>(|_| std::any::type_name::<Response>().to_string())
+    }
+
+    /// Validate every captured payload against the `Response` deserialisation struct, using the
+    /// provided closure to determine which variant each deserialised `Response` represents.
+    pub fn run_with_variants<Response, F>(
+        &self,
+        variant: F,
+    ) -> Result<ConformanceReport, SocketError>
+    where
+        Response: DeserializeOwned + Serialize,
+        F: Fn(&Response) -> String,
+    {
+        let mut files = std::fs::read_dir(&self.dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        files.retain(|path| path.is_file());
+        files.sort();
+
+        let mut report = ConformanceReport::default();
+        for file in files {
+            let contents = std::fs::read_to_string(&file)?;
+            for (source, payload) in payloads(&file, &contents) {
+                report.observe(source, payload, &variant);
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+impl ConformanceReport {
+    /// Determine if every validated payload deserialised successfully.
+    pub fn is_conformant(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Validate a single payload against the `Response` deserialisation struct, updating the
+    /// report coverage.
+    pub fn observe<Response, F>(&mut self, source: String, payload: &str, variant: F)
+    where
+        Response: DeserializeOwned + Serialize,
+        F: Fn(&Response) -> String,
+    {
+        self.payloads += 1;
+
+        let raw = match serde_json::from_str::<Value>(payload) {
+            Ok(raw) => raw,
+            Err(error) => {
+                self.failures.push(ConformanceFailure {
+                    source,
+                    path: String::new(),
+                    error: error.to_string(),
+                });
+                return;
+            }
+        };
+
+        // Track null coverage of every raw payload field
+        let mut raw_fields = BTreeMap::new();
+        flatten(&raw, String::new(), &mut raw_fields);
+        for (path, is_null) in &raw_fields {
+            if *is_null {
+                if !self.non_null.contains(path) {
+                    self.always_null.insert(path.clone());
+                }
+            } else {
+                self.always_null.remove(path);
+                self.non_null.insert(path.clone());
+            }
+        }
+
+        let response = match serde_path_to_error::deserialize::<_, Response>(&raw) {
+            Ok(response) => response,
+            Err(error) => {
+                self.failures.push(ConformanceFailure {
+                    source,
+                    path: error.path().to_string(),
+                    error: error.into_inner().to_string(),
+                });
+                return;
+            }
+        };
+
+        *self.variants.entry(variant(&response)).or_default() += 1;
+
+        // Fields present in the raw payload that do not survive a round trip are not modelled
+        let Ok(modelled) = serde_json::to_value(&response) else {
+            return;
+        };
+        let mut modelled_fields = BTreeMap::new();
+        flatten(&modelled, String::new(), &mut modelled_fields);
+        raw_fields
+            .into_keys()
+            .filter(|path| !modelled_fields.contains_key(path))
+            .filter(|path| !is_parent_unknown(path, &modelled_fields))
+            .for_each(|path| *self.unknown_fields.entry(path).or_default() += 1);
+    }
+}
+
+/// Split the contents of a captured payload file into individual payloads.
+fn payloads<'a>(file: &Path, contents: &'a str) -> Vec<(String, &'a str)> {
+    if serde_json::from_str::<serde::de::IgnoredAny>(contents).is_ok() {
+        return vec![(file.display().to_string(), contents)];
+    }
+
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| (format!("{}:{}", file.display(), index + 1), line))
+        .collect()
+}
+
+/// Flatten every object field in the [`Value`] into its path, and whether it is null.
+///
+/// eg/ `{"data": [{"price": null}]}` -> `{"data": false, "data[].price": true}`
+fn flatten(value: &Value, path: String, fields: &mut BTreeMap<String, bool>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                let path = match path.is_empty() {
+                    true => key.clone(),
+                    false => format!("{path}.{key}"),
+                };
+                let is_null = value.is_null();
+                fields
+                    .entry(path.clone())
+                    .and_modify(|null| *null &= is_null)
+                    .or_insert(is_null);
+                flatten(value, path, fields);
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                flatten(value, format!("{path}[]"), fields);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Determine if an ancestor of the path is already reported as unknown, avoiding reporting
+/// every child of an unmodelled object.
+fn is_parent_unknown(path: &str, modelled: &BTreeMap<String, bool>) -> bool {
+    path.rmatch_indices('.')
+        .map(|(index, _)| path[..index].trim_end_matches("[]"))
+        .any(|parent| !modelled.contains_key(parent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Serialize)]
+    struct Message {
+        channel: Channel,
+        price: f64,
+        id: Option<u64>,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Channel {
+        Trade,
+        Liquidation,
+    }
+
+    fn variant(message: &Message) -> String {
+        match message.channel {
+            Channel::Trade => "trade".to_string(),
+            Channel::Liquidation => "liquidation".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_response_validator() {
+        let dir = std::env::temp_dir().join(format!("conformance_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("trades.jsonl"),
+            concat!(
+                r#"{"channel":"trade","price":1.0,"id":null,"meta":{"venue":"x"}}"#,
+                "\n",
+                r#"{"channel":"trade","price":2.0,"id":null}"#,
+                "\n",
+                r#"{"channel":"trade","price":"bad","id":null}"#,
+                "\n",
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("liquidation.json"),
+            r#"{"channel":"liquidation","price":3.0}"#,
+        )
+        .unwrap();
+
+        let report = ResponseValidator::new(&dir)
+            .run_with_variants::<Message, _>(variant)
+            .unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(report.payloads, 4);
+        assert!(!report.is_conformant());
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].path, "price");
+        assert!(report.failures[0].source.ends_with("trades.jsonl:3"));
+        assert_eq!(
+            report.variants,
+            BTreeMap::from([("liquidation".to_string(), 1), ("trade".to_string(), 2)])
+        );
+        assert_eq!(report.always_null, BTreeSet::from(["id".to_string()]));
+        assert_eq!(
+            report.unknown_fields,
+            BTreeMap::from([("meta".to_string(), 1)])
+        );
+    }
+}
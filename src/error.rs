@@ -1,5 +1,6 @@
 use crate::model::SubscriptionId;
-use reqwest::Error;
+use reqwest::{Error, StatusCode};
+use std::fmt::{Display, Formatter};
 use thiserror::Error;
 
 /// All socket IO related errors generated in `barter-integration`.
@@ -14,12 +15,31 @@ pub enum SocketError {
         payload: String,
     },
 
+    #[error("Deserialising JSON error at path '{path}': {error} for payload: {payload}")]
+    DeserialisePath {
+        path: String,
+        error: serde_json::Error,
+        payload: String,
+    },
+
     #[error("Deserialising JSON error: {error} for binary payload: {payload:?}")]
     DeserialiseBinary {
         error: serde_json::Error,
         payload: Vec<u8>,
     },
 
+    /// XML deserialisation is only available with the `xml` feature enabled, otherwise every
+    /// [`ResponseFormat::Xml`](crate::protocol::http::ResponseFormat::Xml) payload fails with
+    /// this error.
+    #[error("Deserialising XML error: {error} for payload: {payload}")]
+    DeserialiseXml { error: String, payload: String },
+
+    #[error("Deserialising plain text error: {error} for payload: {payload}")]
+    DeserialisePlain {
+        error: serde::de::value::Error,
+        payload: String,
+    },
+
     #[error("Serialising JSON error: {0}")]
     Serialise(serde_json::Error),
 
@@ -32,6 +52,12 @@ pub enum SocketError {
     #[error("error parsing Url: {0}")]
     UrlParse(#[from] url::ParseError),
 
+    #[error("invalid Url template: {0}")]
+    UrlTemplate(String),
+
+    #[error("invalid header: {0}")]
+    InvalidHeader(String),
+
     #[error("error subscribing to resources over the socket: {0}")]
     Subscribe(String),
 
@@ -41,12 +67,30 @@ pub enum SocketError {
     #[error("{entity} does not support: {item}")]
     Unsupported { entity: &'static str, item: String },
 
+    /// Boxed, since the [`tungstenite::Error`](tokio_tungstenite::tungstenite::Error) is large
+    /// relative to every other variant.
     #[error("WebSocket error: {0}")]
-    WebSocket(#[from] tokio_tungstenite::tungstenite::Error),
+    WebSocket(Box<tokio_tungstenite::tungstenite::Error>),
 
     #[error("HTTP error: {0}")]
     Http(reqwest::Error),
 
+    /// Failure of an in-flight request shared by coalesced identical requests.
+    #[error("coalesced HTTP request failed: {0}")]
+    Coalesced(String),
+
+    /// Request short-circuited by an open
+    /// [`CircuitBreaker`](crate::protocol::http::rest::circuit::CircuitBreaker) endpoint circuit.
+    #[error("circuit open for {endpoint}, retry after {retry_after:?}")]
+    CircuitOpen {
+        endpoint: String,
+        retry_after: std::time::Duration,
+    },
+
+    /// Request signing key could not be loaded or used.
+    #[error("invalid signing key: {0}")]
+    SigningKey(String),
+
     #[error("HTTP request timed out")]
     HttpTimeout(reqwest::Error),
 
@@ -59,6 +103,31 @@ pub enum SocketError {
 
     #[error("consumed error message from exchange: {0}")]
     Exchange(String),
+
+    #[error("sequence gap detected: expected {expected}, received {received}")]
+    SequenceGap { expected: u64, received: u64 },
+
+    #[error("no message received within {0:?}")]
+    ReadTimeout(std::time::Duration),
+
+    /// User data stream listen key expired & could not be kept alive.
+    #[error("listen key expired: {0}")]
+    ListenKeyExpired(String),
+
+    #[error("audit log hash chain broken at entry sequence {sequence}")]
+    AuditChain { sequence: u64 },
+
+    #[error("invalid configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for SocketError {
+    fn from(error: tokio_tungstenite::tungstenite::Error) -> Self {
+        SocketError::WebSocket(Box::new(error))
+    }
 }
 
 impl From<reqwest::Error> for SocketError {
@@ -69,3 +138,233 @@ impl From<reqwest::Error> for SocketError {
         }
     }
 }
+
+impl SocketError {
+    /// Construct an [`ErrorReport`] that renders this error alongside actionable remediation
+    /// hints for common integration failures.
+    pub fn report(&self) -> ErrorReport<'_> {
+        ErrorReport {
+            error: self,
+            context: Vec::new(),
+        }
+    }
+}
+
+/// Common integration failures that an [`ErrorReport`] can provide remediation hints for.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum FailureKind {
+    SignatureInvalid,
+    TimestampDrift,
+    UnsupportedInstrument,
+    GeoBlocked,
+    Unauthorised,
+    RateLimited,
+    Timeout,
+    SchemaMismatch,
+}
+
+impl FailureKind {
+    /// Classify the provided [`SocketError`] as a common integration failure, if possible.
+    pub fn classify(error: &SocketError) -> Option<Self> {
+        match error {
+            SocketError::HttpResponse(status, body) => Self::classify_http(*status, body),
+            SocketError::Exchange(message) | SocketError::Subscribe(message) => {
+                Self::classify_message(message)
+            }
+            SocketError::Unsupported { .. } => Some(Self::UnsupportedInstrument),
+            SocketError::HttpTimeout(_) | SocketError::ReadTimeout(_) => Some(Self::Timeout),
+            SocketError::Deserialise { .. }
+            | SocketError::DeserialisePath { .. }
+            | SocketError::DeserialiseBinary { .. }
+            | SocketError::DeserialisePlain { .. } => Some(Self::SchemaMismatch),
+            _ => None,
+        }
+    }
+
+    fn classify_http(status: StatusCode, body: &str) -> Option<Self> {
+        match status {
+            StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS => Some(Self::GeoBlocked),
+            StatusCode::TOO_MANY_REQUESTS => Some(Self::RateLimited),
+            status => Self::classify_message(body).or_else(|| {
+                matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+                    .then_some(Self::Unauthorised)
+            }),
+        }
+    }
+
+    fn classify_message(message: &str) -> Option<Self> {
+        let message = message.to_lowercase();
+        let contains_any = |patterns: &[&str]| patterns.iter().any(|p| message.contains(p));
+
+        if contains_any(&["signature"]) {
+            Some(Self::SignatureInvalid)
+        } else if contains_any(&["timestamp", "recvwindow", "recv_window"]) {
+            Some(Self::TimestampDrift)
+        } else if contains_any(&[
+            "invalid symbol",
+            "unknown symbol",
+            "unknown instrument",
+            "instrument not found",
+            "symbol not found",
+        ]) {
+            Some(Self::UnsupportedInstrument)
+        } else if contains_any(&["restricted location", "restricted jurisdiction"]) {
+            Some(Self::GeoBlocked)
+        } else {
+            None
+        }
+    }
+
+    /// Actionable remediation hint for this failure.
+    pub fn hint(&self) -> &'static str {
+        match self {
+            Self::SignatureInvalid => {
+                "the request signature was rejected: verify the API secret, and that the signed \
+                 payload (eg/ query string, body, timestamp) exactly matches what was sent"
+            }
+            Self::TimestampDrift => {
+                "the request timestamp is outside the venue's receive window: synchronise the \
+                 system clock (eg/ via NTP), or increase the receive window if supported"
+            }
+            Self::UnsupportedInstrument => {
+                "the instrument is not supported by the venue: check the symbol format expected \
+                 by the venue (eg/ BTCUSDT vs btc_usdt) and that the market is listed"
+            }
+            Self::GeoBlocked => {
+                "the venue is blocking requests from this region (HTTP 451): check the venue's \
+                 supported jurisdictions, or use a regional endpoint if one is available"
+            }
+            Self::Unauthorised => {
+                "the request was not authorised: verify the API key is correct, enabled, has the \
+                 required permissions, and that the calling IP is whitelisted"
+            }
+            Self::RateLimited => {
+                "the venue rate limit was exceeded: reduce request frequency, or back off before \
+                 retrying"
+            }
+            Self::Timeout => {
+                "the venue did not respond in time: check network connectivity and the venue \
+                 status page, then retry"
+            }
+            Self::SchemaMismatch => {
+                "the payload did not match the expected data model: the venue may have changed \
+                 its schema, inspect the payload & deserialisation path for the offending field"
+            }
+        }
+    }
+}
+
+/// Renders a [`SocketError`] alongside actionable remediation hints & relevant context for common
+/// integration failures (eg/ invalid signature, timestamp drift, 451 geo-block).
+///
+/// Construct via [`SocketError::report`].
+#[derive(Debug)]
+pub struct ErrorReport<'a> {
+    pub error: &'a SocketError,
+    pub context: Vec<(&'static str, String)>,
+}
+
+impl ErrorReport<'_> {
+    /// Add relevant context to the report. eg/ ("exchange", "binance_spot")
+    pub fn with_context<Value>(mut self, key: &'static str, value: Value) -> Self
+    where
+        Value: Display,
+    {
+        self.context.push((key, value.to_string()));
+        self
+    }
+
+    /// Common integration failure the error was classified as, if any.
+    pub fn kind(&self) -> Option<FailureKind> {
+        FailureKind::classify(self.error)
+    }
+}
+
+impl Display for ErrorReport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "error: {}", self.error)?;
+        for (key, value) in &self.context {
+            write!(f, "\n  {key}: {value}")?;
+        }
+        if let Some(kind) = self.kind() {
+            write!(f, "\n  hint: {}", kind.hint())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_kind_classify() {
+        struct TestCase {
+            error: SocketError,
+            expected: Option<FailureKind>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: invalid signature in response body
+                error: SocketError::HttpResponse(
+                    StatusCode::BAD_REQUEST,
+                    r#"{"code":-1022,"msg":"Signature for this request is not valid."}"#
+                        .to_string(),
+                ),
+                expected: Some(FailureKind::SignatureInvalid),
+            },
+            TestCase {
+                // TC1: timestamp outside recvWindow
+                error: SocketError::HttpResponse(
+                    StatusCode::BAD_REQUEST,
+                    "Timestamp for this request is outside of the recvWindow.".to_string(),
+                ),
+                expected: Some(FailureKind::TimestampDrift),
+            },
+            TestCase {
+                // TC2: geo-block
+                error: SocketError::HttpResponse(
+                    StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS,
+                    String::new(),
+                ),
+                expected: Some(FailureKind::GeoBlocked),
+            },
+            TestCase {
+                // TC3: unsupported instrument
+                error: SocketError::Exchange("Invalid symbol".to_string()),
+                expected: Some(FailureKind::UnsupportedInstrument),
+            },
+            TestCase {
+                // TC4: unauthorised without a recognised body
+                error: SocketError::HttpResponse(StatusCode::UNAUTHORIZED, String::new()),
+                expected: Some(FailureKind::Unauthorised),
+            },
+            TestCase {
+                // TC5: unclassified
+                error: SocketError::Sink,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = FailureKind::classify(&test.error);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_error_report_display() {
+        let error =
+            SocketError::HttpResponse(StatusCode::UNAVAILABLE_FOR_LEGAL_REASONS, String::new());
+        let report = error.report().with_context("exchange", "binance_spot");
+
+        assert_eq!(
+            report.to_string(),
+            format!(
+                "error: {error}\n  exchange: binance_spot\n  hint: {}",
+                FailureKind::GeoBlocked.hint()
+            )
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::error::SocketError;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{fmt::Debug, path::PathBuf};
+
+/// Environment variable that, when set, makes [`GoldenFile::assert_stable`] re-record the
+/// expected output of every [`GoldenCase`] rather than asserting it.
+pub const GOLDEN_UPDATE_ENV: &str = "BARTER_GOLDEN_UPDATE";
+
+/// Golden file of raw real exchange payloads paired with the `Debug` representation of the struct
+/// they deserialise into.
+///
+/// Used to generate regression tests (see [`golden_test!`](crate::golden_test)) asserting parse
+/// results remain stable, so refactors of message structs cannot silently change them.
+///
+/// eg/ Record a payload once via [`record`](Self::record), then assert stability in a test via
+/// `golden_test!(binance_trade_golden, BinanceTrade, "tests/golden/binance_trade.json");`
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct GoldenFile {
+    pub path: PathBuf,
+}
+
+/// Recorded payload & the expected `Debug` representation of its deserialised struct.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GoldenCase {
+    pub name: String,
+    /// Raw payload exactly as received on the wire, such that formatting, key order, number
+    /// precision & duplicate keys are preserved when it is re-parsed.
+    pub payload: String,
+    pub expected: String,
+}
+
+/// [`GoldenCase`] whose current parse result differs from the recorded expected output.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct GoldenMismatch {
+    pub name: String,
+    pub expected: String,
+    /// Current `Debug` representation, or the error if the payload no longer deserialises.
+    pub actual: String,
+}
+
+impl GoldenFile {
+    /// Construct a new [`GoldenFile`] at the provided path.
+    pub fn new<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { path: path.into() }
+    }
+
+    /// Every recorded [`GoldenCase`], or none if the file does not yet exist.
+    pub fn cases(&self) -> Result<Vec<GoldenCase>, SocketError> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(contents) => {
+                serde_json::from_str(&contents).map_err(|error| SocketError::Deserialise {
+                    error,
+                    payload: contents,
+                })
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(error) => Err(SocketError::from(error)),
+        }
+    }
+
+    /// Deserialise the payload into `T` and record the resulting [`GoldenCase`], replacing any
+    /// existing case with the same name.
+    pub fn record<T>(&self, name: &str, payload: &str) -> Result<GoldenCase, SocketError>
+    where
+        T: DeserializeOwned + Debug,
+    {
+        let case = GoldenCase {
+            name: name.to_string(),
+            expected: parse::<T>(payload)?,
+            payload: payload.to_string(),
+        };
+
+        let mut cases = self.cases()?;
+        match cases.iter_mut().find(|existing| existing.name == case.name) {
+            Some(existing) => *existing = case.clone(),
+            None => cases.push(case.clone()),
+        }
+        self.write(&cases)?;
+
+        Ok(case)
+    }
+
+    /// Re-parse every recorded payload, returning the [`GoldenMismatch`]es.
+    pub fn verify<T>(&self) -> Result<Vec<GoldenMismatch>, SocketError>
+    where
+        T: DeserializeOwned + Debug,
+    {
+        Ok(self
+            .cases()?
+            .into_iter()
+            .filter_map(|case| {
+                let actual = parse::<T>(&case.payload).unwrap_or_else(|error| error.to_string());
+
+                (actual != case.expected).then_some(GoldenMismatch {
+                    name: case.name,
+                    expected: case.expected,
+                    actual,
+                })
+            })
+            .collect())
+    }
+
+    /// Assert every recorded payload still parses into the expected output, panicking with
+    /// every [`GoldenMismatch`] otherwise.
+    ///
+    /// If the [`GOLDEN_UPDATE_ENV`] environment variable is set, every expected output is
+    /// re-recorded instead.
+    pub fn assert_stable<T>(&self)
+    where
+        T: DeserializeOwned + Debug,
+    {
+        if std::env::var_os(GOLDEN_UPDATE_ENV).is_some() {
+            for case in self.cases().expect("failed to read golden file") {
+                self.record::<T>(&case.name, &case.payload)
+                    .expect("failed to re-record golden case");
+            }
+            return;
+        }
+
+        let mismatches = self.verify::<T>().expect("failed to read golden file");
+        assert!(
+            mismatches.is_empty(),
+            "{} golden case(s) in {} changed (set {GOLDEN_UPDATE_ENV} to re-record):\n{}",
+            mismatches.len(),
+            self.path.display(),
+            mismatches
+                .iter()
+                .map(|mismatch| format!(
+                    "--- {}\nexpected:\n{}\nactual:\n{}",
+                    mismatch.name, mismatch.expected, mismatch.actual
+                ))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+
+    fn write(&self, cases: &[GoldenCase]) -> Result<(), SocketError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(cases).map_err(SocketError::Serialise)?;
+        std::fs::write(&self.path, contents + "\n")?;
+        Ok(())
+    }
+}
+
+/// Deserialise the payload into `T`, returning its pretty `Debug` representation.
+fn parse<T>(payload: &str) -> Result<String, SocketError>
+where
+    T: DeserializeOwned + Debug,
+{
+    serde_json::from_str::<T>(payload)
+        .map(|parsed| format!("{parsed:#?}"))
+        .map_err(|error| SocketError::Deserialise {
+            error,
+            payload: payload.to_string(),
+        })
+}
+
+/// Generate a `#[test]` asserting the parse results of every [`GoldenCase`] in a
+/// [`GoldenFile`](crate::golden::GoldenFile) remain stable.
+///
+/// eg/ `golden_test!(binance_trade_golden, BinanceTrade, "tests/golden/binance_trade.json");`
+#[macro_export]
+macro_rules! golden_test {
+    ($name:ident, $message:ty, $path:expr) => {
+        #[test]
+        fn $name() {
+            $crate::golden::GoldenFile::new($path).assert_stable::<$message>();
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Trade {
+        price: f64,
+        amount: f64,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct RenamedTrade {
+        #[serde(rename = "price")]
+        px: f64,
+        amount: f64,
+    }
+
+    #[test]
+    fn test_golden_file() {
+        let golden = GoldenFile::new(
+            std::env::temp_dir().join(format!("barter_golden_{}.json", rand::random::<u64>())),
+        );
+
+        golden
+            .record::<Trade>("trade", r#"{"price":1.5,"amount":2.0}"#)
+            .unwrap();
+        golden
+            .record::<Trade>("trade", r#"{"price":1.0,"amount":2.0}"#)
+            .unwrap();
+        assert_eq!(golden.cases().unwrap().len(), 1);
+        assert!(golden.verify::<Trade>().unwrap().is_empty());
+
+        // Raw payload is preserved byte for byte, including number precision
+        let payload = r#"{ "amount": 2.0, "price": 0.10000000000000000001 }"#;
+        assert_eq!(
+            golden.record::<Trade>("precise", payload).unwrap().payload,
+            payload
+        );
+        assert_eq!(golden.cases().unwrap()[1].payload, payload);
+
+        // Renaming a field changes the Debug representation, so is detected
+        let mismatches = golden.verify::<RenamedTrade>().unwrap();
+        assert_eq!(mismatches.len(), 2);
+        assert!(mismatches[0].actual.contains("px: 1.0"));
+
+        std::fs::remove_file(&golden.path).unwrap();
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{
+    model::ExchangeId,
+    runtime::{Runtime, TokioRuntime},
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Concurrency limits applied by an [`InitLimiter`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct InitLimits {
+    /// Maximum number of concurrent initialisations across every exchange.
+    pub global: usize,
+
+    /// Maximum number of concurrent initialisations for each exchange.
+    pub per_exchange: usize,
+
+    /// Maximum random delay applied after acquiring capacity, staggering connection attempts.
+    pub jitter: Duration,
+}
+
+impl Default for InitLimits {
+    fn default() -> Self {
+        Self {
+            global: 32,
+            per_exchange: 5,
+            jitter: Duration::from_millis(250),
+        }
+    }
+}
+
+/// Bounds the number of concurrent stream initialisations (eg/ WebSocket handshakes, REST
+/// metadata fetches) globally & per exchange, since many exchanges rate limit connection attempts.
+///
+/// Cheaply cloneable, with every clone sharing the same limits.
+#[derive(Debug, Clone)]
+pub struct InitLimiter {
+    limits: InitLimits,
+    global: Arc<Semaphore>,
+    exchanges: Arc<Mutex<HashMap<ExchangeId, Arc<Semaphore>>>>,
+    runtime: Arc<dyn Runtime>,
+}
+
+/// Capacity acquired from an [`InitLimiter`], released when dropped.
+#[derive(Debug)]
+pub struct InitPermit {
+    _exchange: OwnedSemaphorePermit,
+    _global: OwnedSemaphorePermit,
+}
+
+impl Default for InitLimiter {
+    fn default() -> Self {
+        Self::new(InitLimits::default())
+    }
+}
+
+impl InitLimiter {
+    /// Construct a new [`InitLimiter`] using the provided [`InitLimits`].
+    pub fn new(limits: InitLimits) -> Self {
+        Self {
+            limits,
+            global: Arc::new(Semaphore::new(limits.global)),
+            exchanges: Arc::new(Mutex::new(HashMap::new())),
+            runtime: Arc::new(TokioRuntime),
+        }
+    }
+
+    /// Use the provided [`Runtime`] timer for the jitter delay, rather than tokio.
+    pub fn with_runtime<R>(self, runtime: R) -> Self
+    where
+        R: Runtime + 'static,
+    {
+        Self {
+            runtime: Arc::new(runtime),
+            ..self
+        }
+    }
+
+    /// Wait for initialisation capacity for the provided [`ExchangeId`], followed by a random
+    /// jitter delay.
+    pub async fn acquire(&self, exchange: &ExchangeId) -> InitPermit {
+        let exchange_semaphore = self
+            .exchanges
+            .lock()
+            .expect("InitLimiter Mutex poisoned")
+            .entry(exchange.clone())
+            .or_insert_with(|| Arc::new(Semaphore::new(self.limits.per_exchange)))
+            .clone();
+
+        // Acquire exchange capacity first to avoid holding global capacity while waiting
+        let exchange_permit = exchange_semaphore
+            .acquire_owned()
+            .await
+            .expect("InitLimiter Semaphores are never closed");
+        let global_permit = Arc::clone(&self.global)
+            .acquire_owned()
+            .await
+            .expect("InitLimiter Semaphores are never closed");
+
+        if !self.limits.jitter.is_zero() {
+            let jitter = rand::thread_rng().gen_range(Duration::ZERO..=self.limits.jitter);
+            self.runtime.sleep(jitter).await;
+        }
+
+        InitPermit {
+            _exchange: exchange_permit,
+            _global: global_permit,
+        }
+    }
+
+    /// Run the provided initialisation future once capacity for the [`ExchangeId`] is acquired.
+    pub async fn run<Init>(&self, exchange: &ExchangeId, init: Init) -> Init::Output
+    where
+        Init: Future,
+    {
+        let _permit = self.acquire(exchange).await;
+        init.await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::future::join_all;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_init_limiter_bounds_concurrency() {
+        let limiter = InitLimiter::new(InitLimits {
+            global: 3,
+            per_exchange: 2,
+            jitter: Duration::ZERO,
+        });
+
+        let active = Arc::new(Mutex::new(HashMap::<ExchangeId, usize>::new()));
+        let max_exchange = Arc::new(AtomicUsize::new(0));
+        let max_global = Arc::new(AtomicUsize::new(0));
+
+        let inits = ["binance", "kraken"]
+            .into_iter()
+            .cycle()
+            .take(12)
+            .map(|name| {
+                let exchange = ExchangeId::from(name);
+                let (limiter, active) = (limiter.clone(), Arc::clone(&active));
+                let (max_exchange, max_global) =
+                    (Arc::clone(&max_exchange), Arc::clone(&max_global));
+
+                async move {
+                    limiter
+                        .run(&exchange, async {
+                            {
+                                let mut active = active.lock().unwrap();
+                                *active.entry(exchange.clone()).or_default() += 1;
+                                max_exchange.fetch_max(active[&exchange], Ordering::SeqCst);
+                                max_global.fetch_max(active.values().sum(), Ordering::SeqCst);
+                            }
+                            tokio::time::sleep(Duration::from_millis(5)).await;
+                            *active.lock().unwrap().get_mut(&exchange).unwrap() -= 1;
+                        })
+                        .await
+                }
+            });
+
+        join_all(inits).await;
+
+        assert_eq!(max_exchange.load(Ordering::SeqCst), 2);
+        assert_eq!(max_global.load(Ordering::SeqCst), 3);
+    }
+}
@@ -18,17 +18,31 @@
     rust_2018_idioms
 )]
 
-use crate::{error::SocketError, protocol::StreamParser};
-use futures::Stream;
+use crate::{
+    error::SocketError,
+    metric::{Field, LatencyTags, Metric, MetricSink, Tag},
+    model::{notice::Notice, SubscriptionId},
+    protocol::StreamParser,
+    stream::{
+        context::{CancellationFlag, ConnectionMeta, TransformContext},
+        pause::PauseHandle,
+        stats::{StreamStats, StreamStatsSnapshot},
+        timeout::Timeout,
+    },
+};
+use async_trait::async_trait;
+use futures::{stream::ReadyChunks, Stream, StreamExt};
 use pin_project::pin_project;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::{
     collections::VecDeque,
-    fmt::Debug,
+    fmt::{Debug, Formatter},
     marker::PhantomData,
     pin::Pin,
     task::{Context, Poll},
+    time::Instant,
 };
+use tracing::warn;
 
 /// Foundational data structures that define the building blocks used by the rest of the `Barter`
 /// ecosystem.
@@ -43,12 +57,57 @@ pub mod error;
 /// messages into a generic output data structure.
 pub mod protocol;
 
+/// Composable [`Stream`] adapters that can wrap [`ExchangeStream`]s.
+///
+/// eg/ `StreamMerge`, etc.
+pub mod stream;
+
 /// Contains the flexible `Metric` type used for representing real-time metrics generically.
 pub mod metric;
 
 /// Utilities to assist deserialisation.
 pub mod de;
 
+/// Records the last processed position of each subscription using pluggable storage, used on
+/// restart to drive gap-fill & report data loss.
+pub mod checkpoint;
+
+/// Bounds the concurrency of stream initialisation (eg/ WebSocket handshakes, REST metadata
+/// fetches) globally & per exchange.
+pub mod init;
+
+/// [`ResponseValidator`](conformance::ResponseValidator) harness that reports the coverage of an
+/// integration's deserialisation structs against captured real exchange payloads.
+pub mod conformance;
+
+/// [`GoldenFile`](golden::GoldenFile)s of recorded payloads & their parse results, used to
+/// generate regression tests asserting message structs parse stably.
+#[cfg(any(test, feature = "test-util"))]
+pub mod golden;
+
+/// Optional append-only, hash-chained audit log of every outbound order-class message.
+pub mod audit;
+
+/// Orchestrates the orderly teardown of many components in dependency order.
+pub mod shutdown;
+
+/// [`Runtime`](runtime::Runtime) abstraction over task spawning & timers, with a tokio default.
+pub mod runtime;
+
+/// Shared test fixtures, eg/ a [`MockServer`](test_util::MockServer) & `TestParser`.
+#[cfg(test)]
+pub(crate) mod test_util;
+
+/// Convenient glob import of the supported stable public API surface, tracking the latest stable
+/// version.
+///
+/// eg/ `use barter_integration::prelude::*;`
+pub mod prelude;
+
+/// Version 1 of the supported stable public API surface, re-exported at fixed paths such that
+/// downstream crates are insulated from internal module reorganisation.
+pub mod v1;
+
 /// [`Validator`]s are capable of determining if their internal state is satisfactory to fulfill
 /// some use case defined by the implementor.
 pub trait Validator {
@@ -66,10 +125,107 @@ pub trait Transformer {
     type Output;
     type OutputIter: IntoIterator<Item = Result<Self::Output, Self::Error>>;
     fn transform(&mut self, input: Self::Input) -> Self::OutputIter;
+
+    /// Transform the input with access to the [`TransformContext`] (elapsed time budget,
+    /// cancellation & connection metadata), enabling long-running transforms to cooperatively
+    /// yield or abort.
+    ///
+    /// Defaults to [`transform`](Self::transform), ignoring the context.
+    fn transform_with_context(
+        &mut self,
+        input: Self::Input,
+        _context: &TransformContext<'_>,
+    ) -> Self::OutputIter {
+        self.transform(input)
+    }
+}
+
+/// Generic [`Transformer`] output that distinguishes modelled events from venue [`Notice`]s and
+/// messages received on a recognised channel that the [`Transformer`] does not yet model.
+///
+/// Emitting [`Output::Unhandled`] rather than dropping such messages allows downstream consumers
+/// to persist them for later schema work, while [`Output::Notice`] gives operators early warning
+/// of disruptive venue changes (eg/ delistings, maintenance).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Output<T, Raw = serde_json::Value> {
+    Event(T),
+    Notice(Notice),
+    Unhandled {
+        subscription: SubscriptionId,
+        raw: Raw,
+    },
+}
+
+impl<T, Raw> Output<T, Raw> {
+    /// Construct an [`Output::Unhandled`] for a message received on the provided subscription.
+    pub fn unhandled<Id>(subscription: Id, raw: Raw) -> Self
+    where
+        Id: Into<SubscriptionId>,
+    {
+        Self::Unhandled {
+            subscription: subscription.into(),
+            raw,
+        }
+    }
+
+    /// Determine if this is an [`Output::Unhandled`] message.
+    pub fn is_unhandled(&self) -> bool {
+        matches!(self, Self::Unhandled { .. })
+    }
+
+    /// Determine if this is an [`Output::Notice`].
+    pub fn is_notice(&self) -> bool {
+        matches!(self, Self::Notice(_))
+    }
+
+    /// Return the modelled event, if any.
+    pub fn event(self) -> Option<T> {
+        match self {
+            Self::Event(event) => Some(event),
+            Self::Notice(_) | Self::Unhandled { .. } => None,
+        }
+    }
+
+    /// Return the venue [`Notice`], if any.
+    pub fn notice(self) -> Option<Notice> {
+        match self {
+            Self::Notice(notice) => Some(notice),
+            Self::Event(_) | Self::Unhandled { .. } => None,
+        }
+    }
+
+    /// Map the modelled event using the provided function, leaving unhandled messages untouched.
+    pub fn map<F, U>(self, f: F) -> Output<U, Raw>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Self::Event(event) => Output::Event(f(event)),
+            Self::Notice(notice) => Output::Notice(notice),
+            Self::Unhandled { subscription, raw } => Output::Unhandled { subscription, raw },
+        }
+    }
+}
+
+/// [`TransformerConstructor`]s are [`Transformer`]s that require fallible, asynchronous
+/// initialisation using the subscriptions they will be processing before streaming can begin.
+///
+/// eg/ Fetching an initial order book snapshot via REST, or instrument metadata.
+#[async_trait]
+pub trait TransformerConstructor<Subscription>: Transformer + Sized
+where
+    Subscription: Sync,
+{
+    /// Initialise a new [`Transformer`] for the provided subscriptions.
+    async fn init(subscriptions: &[Subscription]) -> Result<Self, SocketError>;
 }
 
 /// An [`ExchangeStream`] is a communication protocol agnostic [`Stream`]. It polls protocol
 /// messages from the inner [`Stream`], and transforms them into the desired output data structure.
+///
+/// Construct via [`new`](Self::new) (or [`init`](Self::init)) & the `with_*` methods, or using a
+/// struct literal with a default [`ErrorState`].
 #[derive(Debug)]
 #[pin_project]
 pub struct ExchangeStream<Protocol, InnerStream, StreamTransformer>
@@ -82,7 +238,136 @@ where
     pub stream: InnerStream,
     pub transformer: StreamTransformer,
     pub buffer: VecDeque<Result<StreamTransformer::Output, StreamTransformer::Error>>,
+    pub error_policy: ErrorPolicy,
+    pub latency: Option<LatencyMetrics<StreamTransformer::Output>>,
+    pub stats: Option<StreamStats>,
+    pub pause: PauseHandle,
+    /// Optional time budget for transforming each input message, exposed via the
+    /// [`TransformContext`].
+    pub transform_budget: Option<std::time::Duration>,
+    pub cancellation: CancellationFlag,
+    pub connection: ConnectionMeta,
     pub protocol_marker: PhantomData<Protocol>,
+    pub error_state: ErrorState<StreamTransformer::Error>,
+}
+
+/// [`ErrorPolicy`] state of an [`ExchangeStream`], tracking the running error count & whether
+/// the stream has been terminated.
+///
+/// Errors skipped by [`ErrorPolicy::SkipAndLog`] are logged using their [`Debug`]
+/// implementation if the state is constructed via [`ErrorState::debug`] (as
+/// [`ExchangeStream::with_error_policy`] does), otherwise only the error type name is logged.
+pub struct ErrorState<Error> {
+    errors: usize,
+    terminated: bool,
+    fmt: fn(&Error, &mut Formatter<'_>) -> std::fmt::Result,
+}
+
+impl<Error> ErrorState<Error> {
+    /// Number of errors handled so far.
+    pub fn errors(&self) -> usize {
+        self.errors
+    }
+
+    /// Whether the [`ErrorPolicy`] has terminated the stream.
+    pub fn terminated(&self) -> bool {
+        self.terminated
+    }
+
+    /// [`Debug`] representation of the provided error, used when logging.
+    fn log<'a>(&self, error: &'a Error) -> impl Debug + 'a {
+        struct Log<'a, Error>(
+            &'a Error,
+            fn(&Error, &mut Formatter<'_>) -> std::fmt::Result,
+        );
+
+        impl<Error> Debug for Log<'_, Error> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                (self.1)(self.0, f)
+            }
+        }
+
+        Log(error, self.fmt)
+    }
+}
+
+impl<Error> ErrorState<Error>
+where
+    Error: Debug,
+{
+    /// Construct an [`ErrorState`] that logs skipped errors using their [`Debug`] implementation.
+    pub fn debug() -> Self {
+        Self {
+            fmt: Error::fmt,
+            ..Self::default()
+        }
+    }
+}
+
+impl<Error> Default for ErrorState<Error> {
+    fn default() -> Self {
+        Self {
+            errors: 0,
+            terminated: false,
+            fmt: |_, f| f.write_str(std::any::type_name::<Error>()),
+        }
+    }
+}
+
+impl<Error> Debug for ErrorState<Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErrorState")
+            .field("errors", &self.errors)
+            .field("terminated", &self.terminated)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Opt-in per-message latency instrumentation for an [`ExchangeStream`].
+///
+/// For every output, a [`Metric`] measuring the duration taken to parse & transform the input
+/// message it was generated from is sent, tagged using the output [`LatencyTags`].
+pub struct LatencyMetrics<Output> {
+    pub sink: Box<dyn MetricSink>,
+    pub tags: fn(&Output) -> Vec<Tag>,
+}
+
+impl<Output> Debug for LatencyMetrics<Output> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LatencyMetrics")
+            .field("sink", &self.sink)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Output> LatencyMetrics<Output> {
+    /// Send a latency [`Metric`] for the provided output.
+    fn send(&self, output: &Output, duration: std::time::Duration) {
+        self.sink.send(Metric {
+            name: "exchange_stream_latency",
+            time: chrono::Utc::now().timestamp_millis() as u64,
+            tags: (self.tags)(output),
+            fields: vec![Field::new("duration_us", duration.as_micros() as u64)],
+        });
+    }
+}
+
+/// Determines how an [`ExchangeStream`] handles errors produced by its [`StreamParser`] or
+/// [`Transformer`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum ErrorPolicy {
+    /// Log the error & running error count, and continue without yielding the error downstream.
+    SkipAndLog,
+    /// Yield the error downstream and continue.
+    #[default]
+    Emit,
+    /// Yield the error downstream, then terminate the stream.
+    Terminate,
+    /// Yield errors downstream, terminating the stream after the Nth error.
+    ///
+    /// `TerminateAfterN(0)` & `TerminateAfterN(1)` both behave exactly like
+    /// [`ErrorPolicy::Terminate`].
+    TerminateAfterN(usize),
 }
 
 impl<Protocol, InnerStream, StreamTransformer> Stream
@@ -97,9 +382,25 @@ where
 
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         loop {
-            // Flush Self::Item buffer if it is not currently empty
-            if let Some(output) = self.buffer.pop_front() {
-                return Poll::Ready(Some(output));
+            // Terminated by the ErrorPolicy
+            if self.error_state.terminated {
+                return Poll::Ready(None);
+            }
+
+            // Flush Self::Item buffer if it is not currently empty, applying the ErrorPolicy
+            match self.buffer.pop_front() {
+                Some(Ok(output)) => return Poll::Ready(Some(Ok(output))),
+                Some(Err(error)) => match self.handle_error(error) {
+                    Some(error) => return Poll::Ready(Some(Err(error))),
+                    None => continue,
+                },
+                None => {}
+            }
+
+            // Stop pulling from the inner `Stream` while paused, such that messages are left
+            // unread (applying TCP backpressure), registering to be woken upon resume
+            if self.pause.poll_paused(cx) {
+                return Poll::Pending;
             }
 
             // Poll inner `Stream` for next the next input protocol message
@@ -109,13 +410,52 @@ where
                 Poll::Pending => return Poll::Pending,
             };
 
+            // Record input protocol message throughput if statistics are enabled
+            if let (Some(stats), Ok(message)) = (&mut self.stats, &input) {
+                stats.record_message(Protocol::message_size(message));
+            }
+
+            // Start measuring parse & transform latency if instrumentation is enabled
+            let received = Instant::now();
+            let start = (self.latency.is_some() || self.stats.is_some()).then_some(received);
+
+            #[cfg(feature = "tracing-spans")]
+            let (parse_span, parse_start) = (
+                tracing::debug_span!(
+                    "exchange_stream_parse",
+                    exchange = self
+                        .connection
+                        .exchange
+                        .as_ref()
+                        .map(tracing::field::display),
+                    frame_size = input.as_ref().map_or(0, Protocol::message_size),
+                    parse_duration_us = tracing::field::Empty,
+                ),
+                Instant::now(),
+            );
+
             // Parse input protocol message into `ExchangeMessage`
-            let exchange_message = match Protocol::parse::<StreamTransformer::Input>(input) {
+            let parsed = {
+                #[cfg(feature = "tracing-spans")]
+                let _guard = parse_span.enter();
+                Protocol::parse::<StreamTransformer::Input>(input)
+            };
+
+            #[cfg(feature = "tracing-spans")]
+            parse_span.record(
+                "parse_duration_us",
+                parse_start.elapsed().as_micros() as u64,
+            );
+
+            let exchange_message = match parsed {
                 // `StreamParser` successfully deserialised `ExchangeMessage`
                 Some(Ok(exchange_message)) => exchange_message,
 
-                // If `StreamParser` returns an Err pass it downstream
-                Some(Err(err)) => return Poll::Ready(Some(Err(err.into()))),
+                // If `StreamParser` returns an Err pass it downstream via the ErrorPolicy
+                Some(Err(err)) => {
+                    self.buffer.push_back(Err(err.into()));
+                    continue;
+                }
 
                 // If `StreamParser` returns None it's a safe-to-skip message
                 None => continue,
@@ -123,14 +463,72 @@ where
 
             // Transform `ExchangeMessage` into `Transformer::OutputIter`
             // ie/ IntoIterator<Item = Result<Output, SocketError>>
-            self.transformer
-                .transform(exchange_message)
-                .into_iter()
-                .for_each(
-                    |output_result: Result<StreamTransformer::Output, StreamTransformer::Error>| {
-                        self.buffer.push_back(output_result)
-                    },
+            let this = &mut *self;
+            let buffered = this.buffer.len();
+
+            #[cfg(feature = "tracing-spans")]
+            let (transform_span, transform_start) = (
+                tracing::debug_span!(
+                    "exchange_stream_transform",
+                    exchange = this
+                        .connection
+                        .exchange
+                        .as_ref()
+                        .map(tracing::field::display),
+                    subscription_id = tracing::field::Empty,
+                    outputs = tracing::field::Empty,
+                    transform_duration_us = tracing::field::Empty,
+                ),
+                Instant::now(),
+            );
+
+            {
+                #[cfg(feature = "tracing-spans")]
+                let _guard = transform_span.enter();
+                let context = TransformContext::new(
+                    received,
+                    this.transform_budget,
+                    &this.cancellation,
+                    &this.connection,
+                );
+                #[cfg(feature = "tracing-spans")]
+                let context = context.with_span(&transform_span);
+                this.transformer
+                    .transform_with_context(exchange_message, &context)
+                    .into_iter()
+                    .for_each(
+                        |output_result: Result<
+                            StreamTransformer::Output,
+                            StreamTransformer::Error,
+                        >| { this.buffer.push_back(output_result) },
+                    );
+            }
+
+            #[cfg(feature = "tracing-spans")]
+            transform_span
+                .record("outputs", (this.buffer.len() - buffered) as u64)
+                .record(
+                    "transform_duration_us",
+                    transform_start.elapsed().as_micros() as u64,
                 );
+
+            let Some(start) = start else {
+                continue;
+            };
+            let duration = start.elapsed();
+
+            // Send latency Metrics for each successfully transformed output
+            if let Some(latency) = &this.latency {
+                this.buffer
+                    .range(buffered..)
+                    .filter_map(|output| output.as_ref().ok())
+                    .for_each(|output| latency.send(output, duration));
+            }
+
+            if let Some(stats) = &mut this.stats {
+                let depth = this.buffer.len();
+                stats.record_transform(duration, depth - buffered, depth);
+            }
         }
     }
 }
@@ -147,7 +545,639 @@ where
             stream,
             transformer,
             buffer: VecDeque::with_capacity(6),
+            error_policy: ErrorPolicy::default(),
+            latency: None,
+            stats: None,
+            pause: PauseHandle::default(),
+            transform_budget: None,
+            cancellation: CancellationFlag::default(),
+            connection: ConnectionMeta::default(),
             protocol_marker: PhantomData,
+            error_state: ErrorState::default(),
+        }
+    }
+
+    /// Set the [`ErrorPolicy`] used to handle [`StreamParser`] & [`Transformer`] errors.
+    ///
+    /// Errors skipped by [`ErrorPolicy::SkipAndLog`] are logged using their [`Debug`]
+    /// implementation. For error types that do not implement [`Debug`], set the `error_policy`
+    /// field directly, in which case only the error type name is logged.
+    pub fn with_error_policy(self, error_policy: ErrorPolicy) -> Self
+    where
+        StreamTransformer::Error: Debug,
+    {
+        Self {
+            error_policy,
+            error_state: ErrorState::debug(),
+            ..self
+        }
+    }
+
+    /// Enable per-message latency [`Metric`]s, tagged using the output [`LatencyTags`], and sent
+    /// to the provided [`MetricSink`] (eg/ a [`UnboundedSender`](tokio::sync::mpsc::UnboundedSender)).
+    pub fn with_latency_metrics<Sink>(self, sink: Sink) -> Self
+    where
+        StreamTransformer::Output: LatencyTags,
+        Sink: MetricSink + 'static,
+    {
+        Self {
+            latency: Some(LatencyMetrics {
+                sink: Box::new(sink),
+                tags: <StreamTransformer::Output as LatencyTags>::latency_tags,
+            }),
+            ..self
+        }
+    }
+
+    /// Enable throughput & latency [`StreamStats`] collection.
+    ///
+    /// eg/ `StreamStats::new().with_publisher(metric_tx, Duration::from_secs(10))` to also
+    /// periodically publish the statistics as [`Metric`]s.
+    pub fn with_stats(self, stats: StreamStats) -> Self {
+        Self {
+            stats: Some(stats),
+            ..self
+        }
+    }
+
+    /// Set the time budget for transforming each input message, exposed to the [`Transformer`]
+    /// via the [`TransformContext`].
+    pub fn with_transform_budget(self, budget: std::time::Duration) -> Self {
+        Self {
+            transform_budget: Some(budget),
+            ..self
+        }
+    }
+
+    /// Share the provided [`CancellationFlag`] with the [`Transformer`] via the
+    /// [`TransformContext`].
+    ///
+    /// eg/ Share one [`CancellationFlag`] across every [`ExchangeStream`], cancelled upon
+    /// shutdown.
+    pub fn with_cancellation(self, cancellation: CancellationFlag) -> Self {
+        Self {
+            cancellation,
+            ..self
+        }
+    }
+
+    /// Set the [`ConnectionMeta`] exposed to the [`Transformer`] via the [`TransformContext`].
+    pub fn with_connection_meta(self, connection: ConnectionMeta) -> Self {
+        Self { connection, ..self }
+    }
+
+    /// Set the [`ExchangeId`](model::ExchangeId) of the [`ConnectionMeta`], which is also
+    /// recorded on the parse & transform tracing spans if the `tracing-spans` feature is enabled.
+    ///
+    /// [`Transformer`]s may record the `subscription_id` field of the transform span via
+    /// [`TransformContext::record_subscription_id`].
+    pub fn with_exchange<E>(self, exchange: E) -> Self
+    where
+        E: Into<model::ExchangeId>,
+    {
+        Self {
+            connection: ConnectionMeta {
+                exchange: Some(exchange.into()),
+                ..self.connection
+            },
+            ..self
         }
     }
+
+    /// Stop pulling messages from the inner [`Stream`] until [`resume`](Self::resume)d.
+    ///
+    /// Whilst paused, messages are left unread such that TCP backpressure applies, and are
+    /// yielded in order once resumed. Outputs that have already been transformed are still
+    /// yielded.
+    pub fn pause(&self) {
+        self.pause.pause()
+    }
+
+    /// Resume pulling messages from the inner [`Stream`], waking the paused consumer.
+    pub fn resume(&self) {
+        self.pause.resume()
+    }
+
+    /// Determine if the [`ExchangeStream`] is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.pause.is_paused()
+    }
+
+    /// Cloneable [`PauseHandle`] that can pause & resume this [`ExchangeStream`] from another
+    /// task, including whilst it is being polled.
+    pub fn pause_handle(&self) -> PauseHandle {
+        self.pause.clone()
+    }
+
+    /// Wrap this [`ExchangeStream`] in a [`Timeout`] that yields a [`SocketError::ReadTimeout`]
+    /// whenever no item arrives within the provided [`Duration`](std::time::Duration).
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn timeout(self, duration: std::time::Duration) -> Timeout<Self> {
+        Timeout::new(self, duration)
+    }
+
+    /// Batch every output that is currently ready into a single `Vec`, waking the consumer once
+    /// per batch rather than once per output. Batches contain at most `max_batch` items.
+    ///
+    /// Reduces per-item scheduling overhead for high-frequency feeds.
+    ///
+    /// # Panics
+    /// Panics if `max_batch` is zero.
+    pub fn batched(self, max_batch: usize) -> ReadyChunks<Self>
+    where
+        Self: Stream,
+    {
+        self.ready_chunks(max_batch)
+    }
+
+    /// [`StreamStatsSnapshot`] of the current statistics, if collection is enabled.
+    pub fn stats(&self) -> Option<StreamStatsSnapshot> {
+        self.stats.as_ref().map(StreamStats::snapshot)
+    }
+
+    /// Apply the [`ErrorPolicy`] to an error, returning it if it should be yielded downstream.
+    fn handle_error(
+        &mut self,
+        error: StreamTransformer::Error,
+    ) -> Option<StreamTransformer::Error> {
+        let state = &mut self.error_state;
+        state.errors += 1;
+        match self.error_policy {
+            ErrorPolicy::SkipAndLog => {
+                warn!(
+                    error = ?state.log(&error),
+                    errors = state.errors,
+                    "ExchangeStream skipping error"
+                );
+                None
+            }
+            ErrorPolicy::Emit => Some(error),
+            ErrorPolicy::Terminate => {
+                state.terminated = true;
+                Some(error)
+            }
+            ErrorPolicy::TerminateAfterN(limit) => {
+                if state.errors >= limit {
+                    state.terminated = true;
+                }
+                Some(error)
+            }
+        }
+    }
+
+    /// Construct a new [`ExchangeStream`], initialising the [`TransformerConstructor`] using the
+    /// provided subscriptions.
+    pub async fn init<Subscription>(
+        stream: InnerStream,
+        subscriptions: &[Subscription],
+    ) -> Result<Self, SocketError>
+    where
+        StreamTransformer: TransformerConstructor<Subscription>,
+        Subscription: Sync,
+    {
+        let transformer = StreamTransformer::init(subscriptions).await?;
+        Ok(Self::new(stream, transformer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use serde::de::DeserializeOwned;
+    use tokio::sync::mpsc;
+
+    struct TestParser;
+
+    impl StreamParser for TestParser {
+        type Stream = futures::stream::Iter<std::vec::IntoIter<Result<String, SocketError>>>;
+        type Message = String;
+        type Error = SocketError;
+
+        fn parse<Output>(
+            input: Result<Self::Message, Self::Error>,
+        ) -> Option<Result<Output, SocketError>>
+        where
+            Output: DeserializeOwned,
+        {
+            Some(input.and_then(|payload| {
+                serde_json::from_str(&payload)
+                    .map_err(|error| SocketError::Deserialise { error, payload })
+            }))
+        }
+
+        fn message_size(message: &Self::Message) -> usize {
+            message.len()
+        }
+    }
+
+    /// Yields positive inputs, and errors for negative inputs.
+    struct TestTransformer;
+
+    impl Transformer for TestTransformer {
+        type Error = SocketError;
+        type Input = i64;
+        type Output = i64;
+        type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+        fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+            match input {
+                input if input < 0 => vec![Err(SocketError::Exchange(input.to_string()))],
+                input => vec![Ok(input)],
+            }
+        }
+    }
+
+    impl LatencyTags for i64 {
+        fn kind(&self) -> &'static str {
+            "test"
+        }
+
+        fn instrument_bucket(&self) -> Option<String> {
+            (*self > 2).then(|| "large".to_string())
+        }
+    }
+
+    fn test_stream(
+        inputs: &[&str],
+    ) -> ExchangeStream<TestParser, <TestParser as StreamParser>::Stream, TestTransformer> {
+        let inputs = inputs
+            .iter()
+            .map(|input| Ok(input.to_string()))
+            .collect::<Vec<_>>();
+        ExchangeStream::new(futures::stream::iter(inputs), TestTransformer)
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_non_debug_error() {
+        /// Error type that does not implement Debug.
+        struct OpaqueError;
+
+        impl From<SocketError> for OpaqueError {
+            fn from(_: SocketError) -> Self {
+                Self
+            }
+        }
+
+        struct OpaqueTransformer;
+
+        impl Transformer for OpaqueTransformer {
+            type Error = OpaqueError;
+            type Input = i64;
+            type Output = i64;
+            type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+            fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+                match input {
+                    input if input < 0 => vec![Err(OpaqueError)],
+                    input => vec![Ok(input)],
+                }
+            }
+        }
+
+        let inputs = ["1", "-2", "3"].map(|input| Ok(input.to_string()));
+        let mut stream = ExchangeStream::<TestParser, _, _>::new(
+            futures::stream::iter(inputs),
+            OpaqueTransformer,
+        );
+        stream.error_policy = ErrorPolicy::SkipAndLog;
+
+        let actual = stream
+            .filter_map(|result| std::future::ready(result.ok()))
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual, vec![1, 3]);
+    }
+
+    #[test]
+    fn test_error_state_log() {
+        let state = ErrorState::<SocketError>::debug();
+        let error = SocketError::Unsupported {
+            entity: "Binance",
+            item: "orders".to_string(),
+        };
+        assert_eq!(format!("{:?}", state.log(&error)), format!("{error:?}"));
+
+        let state = ErrorState::<SocketError>::default();
+        assert_eq!(
+            format!("{:?}", state.log(&error)),
+            std::any::type_name::<SocketError>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_error_policy() {
+        struct TestCase {
+            policy: ErrorPolicy,
+            expected: Vec<Result<i64, ()>>,
+        }
+
+        let inputs = ["1", "-2", "not_json", "4", "-5", "6"];
+
+        let cases = vec![
+            TestCase {
+                // TC0: Emit every error
+                policy: ErrorPolicy::Emit,
+                expected: vec![Ok(1), Err(()), Err(()), Ok(4), Err(()), Ok(6)],
+            },
+            TestCase {
+                // TC1: Skip every error
+                policy: ErrorPolicy::SkipAndLog,
+                expected: vec![Ok(1), Ok(4), Ok(6)],
+            },
+            TestCase {
+                // TC2: Terminate after first error
+                policy: ErrorPolicy::Terminate,
+                expected: vec![Ok(1), Err(())],
+            },
+            TestCase {
+                // TC3: Terminate after second error
+                policy: ErrorPolicy::TerminateAfterN(2),
+                expected: vec![Ok(1), Err(()), Err(())],
+            },
+            TestCase {
+                // TC4: TerminateAfterN(0) behaves like Terminate
+                policy: ErrorPolicy::TerminateAfterN(0),
+                expected: vec![Ok(1), Err(())],
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test_stream(&inputs)
+                .with_error_policy(test.policy)
+                .map(|result| result.map_err(|_| ()))
+                .collect::<Vec<_>>()
+                .await;
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_latency_metrics() {
+        let (metric_tx, mut metric_rx) = mpsc::unbounded_channel();
+        let outputs = test_stream(&["1", "-2", "3"])
+            .with_latency_metrics(metric_tx)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(outputs.len(), 3);
+
+        // Only successful outputs produce latency Metrics
+        let mut tags = Vec::new();
+        while let Ok(metric) = metric_rx.try_recv() {
+            assert_eq!(metric.name, "exchange_stream_latency");
+            tags.push(metric.tags);
+        }
+        assert_eq!(
+            tags,
+            vec![
+                vec![Tag::new("kind", "test")],
+                vec![
+                    Tag::new("kind", "test"),
+                    Tag::new("instrument_bucket", "large")
+                ],
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_stats() {
+        let (metric_tx, mut metric_rx) = mpsc::unbounded_channel();
+        let mut stream = test_stream(&["1", "-2", "not_json", "40"])
+            .with_stats(StreamStats::new().with_publisher(metric_tx, std::time::Duration::ZERO));
+        assert_eq!(stream.stats().unwrap().messages, 0);
+
+        while stream.next().await.is_some() {}
+
+        let stats = stream.stats().unwrap();
+        assert_eq!(stats.messages, 4);
+        assert_eq!(stats.bytes, 13);
+        assert_eq!(stats.outputs, 3);
+        assert_eq!(stats.buffer_depth, 1);
+
+        // Published after every transform when the publish interval is zero
+        let mut published = 0;
+        while let Ok(metric) = metric_rx.try_recv() {
+            assert_eq!(metric.name, "exchange_stream_stats");
+            published += 1;
+        }
+        assert_eq!(published, 3);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_batched() {
+        let batches = test_stream(&["1", "2", "-3", "4"])
+            .batched(3)
+            .map(|batch| batch.into_iter().map(|item| item.ok()).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(batches, vec![vec![Some(1), Some(2), None], vec![Some(4)]]);
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_pause_resume() {
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut stream = ExchangeStream::<TestParser, _, _>::new(rx, TestTransformer);
+        let handle = stream.pause_handle();
+
+        stream.pause();
+        assert!(handle.is_paused());
+
+        // Inner stream is not polled while paused, so messages are left unread
+        tx.unbounded_send(Ok("1".to_string())).unwrap();
+        assert!(futures::poll!(stream.next()).is_pending());
+
+        // Paused consumer is woken upon resume, receiving messages sent during the pause first
+        let next = tokio::spawn(async move {
+            let output = stream.next().await;
+            (output, stream)
+        });
+        tokio::task::yield_now().await;
+        handle.resume();
+        let (output, mut stream) = next.await.unwrap();
+        assert_eq!(output.unwrap().unwrap(), 1);
+        assert!(!stream.is_paused());
+
+        tx.unbounded_send(Ok("2".to_string())).unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), 2);
+
+        // Inner stream termination is surfaced once resumed
+        stream.pause();
+        drop(tx);
+        assert!(futures::poll!(stream.next()).is_pending());
+        stream.resume();
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_transform_context() {
+        /// Aborts transforms once cancelled, otherwise echoes the input.
+        struct CancellableTransformer;
+
+        impl Transformer for CancellableTransformer {
+            type Error = SocketError;
+            type Input = i64;
+            type Output = i64;
+            type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+            fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+                vec![Ok(input)]
+            }
+
+            fn transform_with_context(
+                &mut self,
+                input: Self::Input,
+                context: &TransformContext<'_>,
+            ) -> Self::OutputIter {
+                if context.should_yield() {
+                    return vec![];
+                }
+                self.transform(input)
+            }
+        }
+
+        let cancellation = CancellationFlag::default();
+        let inputs = vec![Ok("1".to_string()), Ok("2".to_string())];
+        let mut stream = ExchangeStream::<TestParser, _, _>::new(
+            futures::stream::iter(inputs),
+            CancellableTransformer,
+        )
+        .with_cancellation(cancellation.clone());
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        cancellation.cancel();
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_init() {
+        /// Scales inputs by the multiplier subscribed to, failing if there are no subscriptions.
+        struct ScaleTransformer(i64);
+
+        impl Transformer for ScaleTransformer {
+            type Error = SocketError;
+            type Input = i64;
+            type Output = i64;
+            type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+            fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+                vec![Ok(input * self.0)]
+            }
+        }
+
+        #[async_trait]
+        impl TransformerConstructor<i64> for ScaleTransformer {
+            async fn init(subscriptions: &[i64]) -> Result<Self, SocketError> {
+                subscriptions
+                    .iter()
+                    .copied()
+                    .reduce(|a, b| a * b)
+                    .map(Self)
+                    .ok_or_else(|| SocketError::Subscribe("no subscriptions".to_string()))
+            }
+        }
+
+        let inputs = || futures::stream::iter(vec![Ok("1".to_string()), Ok("2".to_string())]);
+
+        let stream = ExchangeStream::<TestParser, _, ScaleTransformer>::init(inputs(), &[2, 5])
+            .await
+            .unwrap();
+        let actual = stream.map(Result::unwrap).collect::<Vec<_>>().await;
+        assert_eq!(actual, vec![10, 20]);
+
+        // Initialisation failures are surfaced rather than constructing the ExchangeStream
+        let error = ExchangeStream::<TestParser, _, ScaleTransformer>::init(inputs(), &[]).await;
+        assert!(matches!(error, Err(SocketError::Subscribe(_))));
+    }
+
+    #[cfg(feature = "tracing-spans")]
+    #[test]
+    fn test_exchange_stream_tracing_spans() {
+        use std::sync::{Arc, Mutex};
+        use tracing::{
+            field::{Field, Visit},
+            span::{Attributes, Id, Record},
+            Event, Metadata,
+        };
+
+        /// Captures every span field value as a "{field}={value}" string.
+        #[derive(Clone, Default)]
+        struct FieldCollector(Arc<Mutex<Vec<String>>>);
+
+        impl Visit for FieldCollector {
+            fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("{}={value:?}", field.name()));
+            }
+        }
+
+        impl tracing::Subscriber for FieldCollector {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                span.record(&mut self.clone());
+                Id::from_u64(1)
+            }
+
+            fn record(&self, _: &Id, values: &Record<'_>) {
+                values.record(&mut self.clone());
+            }
+
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        /// Records the input as the SubscriptionId of the transform span.
+        struct SubscribedTransformer;
+
+        impl Transformer for SubscribedTransformer {
+            type Error = SocketError;
+            type Input = i64;
+            type Output = i64;
+            type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+            fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+                vec![Ok(input)]
+            }
+
+            fn transform_with_context(
+                &mut self,
+                input: Self::Input,
+                context: &TransformContext<'_>,
+            ) -> Self::OutputIter {
+                context.record_subscription_id(&SubscriptionId(format!("trades-{input}")));
+                self.transform(input)
+            }
+        }
+
+        let collector = FieldCollector::default();
+        let outputs = tracing::subscriber::with_default(collector.clone(), || {
+            let inputs = vec![Ok("7".to_string())];
+            let stream = ExchangeStream::<TestParser, _, _>::new(
+                futures::stream::iter(inputs),
+                SubscribedTransformer,
+            )
+            .with_exchange(model::ExchangeId::Kraken);
+            futures::executor::block_on(stream.collect::<Vec<_>>())
+        });
+        assert_eq!(outputs.len(), 1);
+
+        let fields = collector.0.lock().unwrap().clone();
+        assert!(
+            fields.contains(&"exchange=kraken".to_string()),
+            "{fields:?}"
+        );
+        assert!(
+            fields.contains(&"subscription_id=trades-7".to_string()),
+            "{fields:?}"
+        );
+        assert!(fields.contains(&"outputs=1".to_string()), "{fields:?}");
+    }
 }
@@ -1,4 +1,9 @@
 use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+use tokio::sync::mpsc;
 
 #[derive(Debug, Clone, PartialOrd, PartialEq, Serialize)]
 pub struct Metric {
@@ -15,6 +20,83 @@ pub struct Metric {
     pub fields: Vec<Field>,
 }
 
+/// Destination that generated [`Metric`]s are sent to.
+///
+/// Implemented for a [`mpsc::UnboundedSender`], a [`NoopMetricSink`] that discards every
+/// [`Metric`], and a [`MetricCallback`] that invokes a closure. Users not interested in metrics
+/// are therefore not forced to create & drop a channel.
+pub trait MetricSink: Debug + Send + Sync {
+    /// Send the provided [`Metric`], discarding it if the sink is no longer available.
+    fn send(&self, metric: Metric);
+}
+
+impl MetricSink for mpsc::UnboundedSender<Metric> {
+    fn send(&self, metric: Metric) {
+        let _ = mpsc::UnboundedSender::send(self, metric);
+    }
+}
+
+impl<Sink> MetricSink for Arc<Sink>
+where
+    Sink: MetricSink + ?Sized,
+{
+    fn send(&self, metric: Metric) {
+        Sink::send(self, metric)
+    }
+}
+
+/// [`MetricSink`] that discards every [`Metric`].
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NoopMetricSink;
+
+impl MetricSink for NoopMetricSink {
+    fn send(&self, _: Metric) {}
+}
+
+/// [`MetricSink`] that invokes the provided closure with every [`Metric`].
+///
+/// eg/ `MetricCallback(|metric| println!("{metric:?}"))`
+#[derive(Copy, Clone)]
+pub struct MetricCallback<F>(pub F);
+
+impl<F> Debug for MetricCallback<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("MetricCallback").finish_non_exhaustive()
+    }
+}
+
+impl<F> MetricSink for MetricCallback<F>
+where
+    F: Fn(Metric) + Send + Sync,
+{
+    fn send(&self, metric: Metric) {
+        (self.0)(metric)
+    }
+}
+
+/// Provides the [`Tag`]s used to categorise per-message latency [`Metric`]s, enabling slow paths
+/// to be identified by data kind & instrument.
+///
+/// eg/ Identifying that l2 update processing is slow, while trade processing is fine.
+pub trait LatencyTags {
+    /// Kind of data this message represents. eg/ "trade", "l2_update", "ticker".
+    fn kind(&self) -> &'static str;
+
+    /// Optional instrument bucket this message belongs to. eg/ "btc_usdt", "majors".
+    fn instrument_bucket(&self) -> Option<String> {
+        None
+    }
+
+    /// Generate the latency [`Tag`]s for this message.
+    fn latency_tags(&self) -> Vec<Tag> {
+        let mut tags = vec![Tag::new("kind", self.kind())];
+        if let Some(bucket) = self.instrument_bucket() {
+            tags.push(Tag::new("instrument_bucket", bucket));
+        }
+        tags
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Tag {
     pub key: &'static str,
@@ -36,6 +118,18 @@ pub enum Value {
     String(String),
 }
 
+impl Metric {
+    /// Construct an empty [`Metric`] with no time, tags or fields, without allocating.
+    pub fn empty(name: &'static str) -> Self {
+        Self {
+            name,
+            time: 0,
+            tags: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+}
+
 impl<S> From<(&'static str, S)> for Tag
 where
     S: Into<String>,
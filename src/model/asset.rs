@@ -0,0 +1,103 @@
+use crate::model::instrument::symbol::Symbol;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Barter representation of a currency held in balances & moved by deposits and withdrawals,
+/// with the optional network it is held on & the precision of its amounts.
+///
+/// eg/ Asset { symbol: "usdt", network: Some("trc20"), precision: Some(6) }
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Asset {
+    pub symbol: Symbol,
+    /// Network / chain the asset is held on, if relevant. eg/ "erc20", "trc20", "bep20"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<Symbol>,
+    /// Number of decimal places of a valid amount, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<u32>,
+}
+
+impl Display for Asset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.network {
+            Some(network) => write!(f, "{}_{}", self.symbol, network),
+            None => write!(f, "{}", self.symbol),
+        }
+    }
+}
+
+impl AsRef<Symbol> for Asset {
+    fn as_ref(&self) -> &Symbol {
+        &self.symbol
+    }
+}
+
+impl From<Symbol> for Asset {
+    fn from(symbol: Symbol) -> Self {
+        Self {
+            symbol,
+            network: None,
+            precision: None,
+        }
+    }
+}
+
+impl From<Asset> for Symbol {
+    fn from(asset: Asset) -> Self {
+        asset.symbol
+    }
+}
+
+impl Asset {
+    /// Construct a new [`Asset`] using the provided `Into<Symbol>` value, without a network or
+    /// precision.
+    pub fn new<S>(symbol: S) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self::from(symbol.into())
+    }
+
+    /// Hold the [`Asset`] on the provided network.
+    pub fn with_network<S>(self, network: S) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self {
+            network: Some(network.into()),
+            ..self
+        }
+    }
+
+    /// Set the number of decimal places of a valid amount.
+    pub fn with_precision(self, precision: u32) -> Self {
+        Self {
+            precision: Some(precision),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asset() {
+        let asset = serde_json::from_str::<Asset>(
+            r#"{"symbol": "USDT", "network": "TRC20", "precision": 6}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            asset,
+            Asset::new("usdt").with_network("trc20").with_precision(6)
+        );
+        assert_eq!(asset.to_string(), "usdt_trc20");
+        assert_eq!(Symbol::from(asset), Symbol::new("usdt"));
+        assert_eq!(
+            serde_json::to_string(&Asset::from(Symbol::new("btc"))).unwrap(),
+            r#"{"symbol":"btc"}"#
+        );
+    }
+}
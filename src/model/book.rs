@@ -0,0 +1,209 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Aggregated [`OrderBook`] price [`Level`].
+///
+/// Deserialises from an object or the common exchange `["price", "amount"]` array.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Level {
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+impl From<(Decimal, Decimal)> for Level {
+    fn from((price, amount): (Decimal, Decimal)) -> Self {
+        Self { price, amount }
+    }
+}
+
+impl Level {
+    /// Construct a new [`Level`] using the provided price & amount.
+    pub fn new(price: Decimal, amount: Decimal) -> Self {
+        Self { price, amount }
+    }
+}
+
+/// Update to apply to an [`OrderBook`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderBookUpdate {
+    /// Full depth replacing every [`Level`].
+    Snapshot { bids: Vec<Level>, asks: Vec<Level> },
+    /// Changed [`Level`]s, where a zero amount removes the price level.
+    Delta { bids: Vec<Level>, asks: Vec<Level> },
+}
+
+/// Normalised price keyed [`OrderBook`].
+///
+/// Bids are sorted by descending price & asks by ascending price, such that the best
+/// [`Level`] of each side is first, and no [`Level`] has a zero amount. Construct via
+/// [`OrderBook::new`] or an [`OrderBookUpdate::Snapshot`], rather than deserialising directly,
+/// such that the invariants always hold.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default, Serialize)]
+pub struct OrderBook {
+    bids: Vec<Level>,
+    asks: Vec<Level>,
+}
+
+impl OrderBook {
+    /// Construct a new [`OrderBook`] from the provided snapshot [`Level`]s, in any order.
+    pub fn new<Bids, Asks>(bids: Bids, asks: Asks) -> Self
+    where
+        Bids: IntoIterator<Item = Level>,
+        Asks: IntoIterator<Item = Level>,
+    {
+        let mut book = Self::default();
+        book.apply(OrderBookUpdate::Snapshot {
+            bids: bids.into_iter().collect(),
+            asks: asks.into_iter().collect(),
+        });
+        book
+    }
+
+    /// Apply the provided [`OrderBookUpdate`], maintaining the sorting invariants.
+    pub fn apply(&mut self, update: OrderBookUpdate) {
+        match update {
+            OrderBookUpdate::Snapshot { bids, asks } => {
+                self.bids.clear();
+                self.asks.clear();
+                bids.into_iter()
+                    .for_each(|level| upsert(&mut self.bids, level, bid_order));
+                asks.into_iter()
+                    .for_each(|level| upsert(&mut self.asks, level, ask_order));
+            }
+            OrderBookUpdate::Delta { bids, asks } => {
+                bids.into_iter()
+                    .for_each(|level| upsert(&mut self.bids, level, bid_order));
+                asks.into_iter()
+                    .for_each(|level| upsert(&mut self.asks, level, ask_order));
+            }
+        }
+    }
+
+    /// Bid [`Level`]s, best (highest price) first.
+    pub fn bids(&self) -> &[Level] {
+        &self.bids
+    }
+
+    /// Ask [`Level`]s, best (lowest price) first.
+    pub fn asks(&self) -> &[Level] {
+        &self.asks
+    }
+
+    /// Best bid [`Level`], if any.
+    pub fn best_bid(&self) -> Option<&Level> {
+        self.bids.first()
+    }
+
+    /// Best ask [`Level`], if any.
+    pub fn best_ask(&self) -> Option<&Level> {
+        self.asks.first()
+    }
+
+    /// Difference between the best ask & best bid prices.
+    pub fn spread(&self) -> Option<Decimal> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// Average of the best bid & best ask prices.
+    pub fn mid_price(&self) -> Option<Decimal> {
+        Some((self.best_bid()?.price + self.best_ask()?.price) / Decimal::TWO)
+    }
+
+    /// Average of the best bid & best ask prices, weighted towards the side with less depth,
+    /// since price is more likely to move through it.
+    ///
+    /// eg/ bid 100 @ 1 & ask 101 @ 3 => 100.25
+    pub fn volume_weighted_mid_price(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.best_bid()?, self.best_ask()?);
+        let total = bid.amount + ask.amount;
+        Some((bid.price * ask.amount + ask.price * bid.amount) / total)
+    }
+}
+
+fn bid_order(existing: &Level, price: Decimal) -> Ordering {
+    price.cmp(&existing.price)
+}
+
+fn ask_order(existing: &Level, price: Decimal) -> Ordering {
+    existing.price.cmp(&price)
+}
+
+/// Insert, replace, or remove (if the amount is zero) the provided [`Level`], keeping the
+/// levels sorted by the provided order.
+fn upsert<Order>(levels: &mut Vec<Level>, level: Level, order: Order)
+where
+    Order: Fn(&Level, Decimal) -> Ordering,
+{
+    match (
+        levels.binary_search_by(|existing| order(existing, level.price)),
+        level.amount.is_zero(),
+    ) {
+        (Ok(index), true) => {
+            levels.remove(index);
+        }
+        (Ok(index), false) => levels[index] = level,
+        (Err(_), true) => {}
+        (Err(index), false) => levels.insert(index, level),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_order_book_apply() {
+        let mut book = OrderBook::new(
+            [
+                Level::new(dec!(99), dec!(2)),
+                Level::new(dec!(100), dec!(1)),
+            ],
+            [
+                Level::new(dec!(102), dec!(2)),
+                Level::new(dec!(101), dec!(3)),
+            ],
+        );
+
+        assert_eq!(book.best_bid(), Some(&Level::new(dec!(100), dec!(1))));
+        assert_eq!(book.best_ask(), Some(&Level::new(dec!(101), dec!(3))));
+        assert_eq!(book.spread(), Some(dec!(1)));
+        assert_eq!(book.mid_price(), Some(dec!(100.5)));
+        assert_eq!(book.volume_weighted_mid_price(), Some(dec!(100.25)));
+
+        let delta = serde_json::from_str::<OrderBookUpdate>(
+            r#"{"delta": {
+                "bids": [["100", "0"], ["100.5", "4"], ["98", "0"]],
+                "asks": [["101", "1"], ["103", "5"]]
+            }}"#,
+        )
+        .unwrap();
+        book.apply(delta);
+
+        assert_eq!(
+            book.bids(),
+            &[
+                Level::new(dec!(100.5), dec!(4)),
+                Level::new(dec!(99), dec!(2))
+            ]
+        );
+        assert_eq!(
+            book.asks(),
+            &[
+                Level::new(dec!(101), dec!(1)),
+                Level::new(dec!(102), dec!(2)),
+                Level::new(dec!(103), dec!(5))
+            ]
+        );
+
+        book.apply(OrderBookUpdate::Snapshot {
+            bids: vec![],
+            asks: vec![Level::new(dec!(110), dec!(1))],
+        });
+        assert_eq!(book.bids(), &[]);
+        assert_eq!(book.mid_price(), None);
+        assert_eq!(book.best_ask(), Some(&Level::new(dec!(110), dec!(1))));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::model::ParseError;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// Normalised OHLCV [`Candle`] aggregated over a [`TimeInterval`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Candle {
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+    pub trade_count: u64,
+}
+
+/// Interval a [`Candle`] is aggregated over.
+///
+/// Round-trips via [`FromStr`], [`Display`] & serde using the common exchange notation, where
+/// `m` is minutes & `M` is months. Hours, days & weeks are also parsed from uppercase units.
+///
+/// eg/ "1m", "15m", "4h", "1d", "1w", "1M"
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum TimeInterval {
+    M1,
+    M3,
+    M5,
+    M15,
+    M30,
+    H1,
+    H2,
+    H4,
+    H6,
+    H8,
+    H12,
+    D1,
+    D3,
+    W1,
+    Month1,
+}
+
+impl TimeInterval {
+    /// Every [`TimeInterval`], shortest first.
+    pub const ALL: [Self; 15] = [
+        Self::M1,
+        Self::M3,
+        Self::M5,
+        Self::M15,
+        Self::M30,
+        Self::H1,
+        Self::H2,
+        Self::H4,
+        Self::H6,
+        Self::H8,
+        Self::H12,
+        Self::D1,
+        Self::D3,
+        Self::W1,
+        Self::Month1,
+    ];
+
+    /// `str` representation of the [`TimeInterval`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::M1 => "1m",
+            Self::M3 => "3m",
+            Self::M5 => "5m",
+            Self::M15 => "15m",
+            Self::M30 => "30m",
+            Self::H1 => "1h",
+            Self::H2 => "2h",
+            Self::H4 => "4h",
+            Self::H6 => "6h",
+            Self::H8 => "8h",
+            Self::H12 => "12h",
+            Self::D1 => "1d",
+            Self::D3 => "3d",
+            Self::W1 => "1w",
+            Self::Month1 => "1M",
+        }
+    }
+
+    /// Fixed duration of the [`TimeInterval`], or `None` for calendar months.
+    pub fn duration(&self) -> Option<chrono::Duration> {
+        Some(match self {
+            Self::M1 => chrono::Duration::minutes(1),
+            Self::M3 => chrono::Duration::minutes(3),
+            Self::M5 => chrono::Duration::minutes(5),
+            Self::M15 => chrono::Duration::minutes(15),
+            Self::M30 => chrono::Duration::minutes(30),
+            Self::H1 => chrono::Duration::hours(1),
+            Self::H2 => chrono::Duration::hours(2),
+            Self::H4 => chrono::Duration::hours(4),
+            Self::H6 => chrono::Duration::hours(6),
+            Self::H8 => chrono::Duration::hours(8),
+            Self::H12 => chrono::Duration::hours(12),
+            Self::D1 => chrono::Duration::days(1),
+            Self::D3 => chrono::Duration::days(3),
+            Self::W1 => chrono::Duration::weeks(1),
+            Self::Month1 => return None,
+        })
+    }
+}
+
+impl FromStr for TimeInterval {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalised = match input.strip_suffix(['H', 'D', 'W']) {
+            Some(quantity) => format!("{quantity}{}", &input[quantity.len()..].to_lowercase()),
+            None => input.to_string(),
+        };
+
+        Self::ALL
+            .into_iter()
+            .find(|interval| interval.as_str() == normalised)
+            .ok_or_else(|| ParseError::new("TimeInterval", input, "unsupported interval"))
+    }
+}
+
+impl Display for TimeInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for TimeInterval {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TimeInterval {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let input = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        TimeInterval::from_str(&input).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_interval_round_trip() {
+        for interval in TimeInterval::ALL {
+            assert_eq!(interval.as_str().parse::<TimeInterval>().unwrap(), interval);
+            assert_eq!(
+                serde_json::from_str::<TimeInterval>(&serde_json::to_string(&interval).unwrap())
+                    .unwrap(),
+                interval
+            );
+        }
+
+        assert_eq!("4H".parse::<TimeInterval>().unwrap(), TimeInterval::H4);
+        assert_eq!("1M".parse::<TimeInterval>().unwrap(), TimeInterval::Month1);
+        assert_eq!("1m".parse::<TimeInterval>().unwrap(), TimeInterval::M1);
+        assert!("2m".parse::<TimeInterval>().is_err());
+        assert_eq!(
+            TimeInterval::H4.duration(),
+            Some(chrono::Duration::hours(4))
+        );
+        assert_eq!(TimeInterval::Month1.duration(), None);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::model::{instrument::Instrument, ExchangeId, Market};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Normalised event envelope identifying the [`ExchangeId`] & [`Instrument`] an event `kind` was
+/// generated for, timestamped by the exchange & on receipt.
+///
+/// eg/ `Event<PublicTrade>`, `Event<OrderBook>`, `Event<Candle>`
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Event<T, InstrumentId = Instrument> {
+    pub exchange_time: DateTime<Utc>,
+    pub received_time: DateTime<Utc>,
+    pub exchange: ExchangeId,
+    pub instrument: InstrumentId,
+    pub kind: T,
+}
+
+impl<T, InstrumentId> Event<T, InstrumentId> {
+    /// Construct a new [`Event`] received now, using the provided configuration.
+    pub fn new<E, I>(exchange_time: DateTime<Utc>, exchange: E, instrument: I, kind: T) -> Self
+    where
+        E: Into<ExchangeId>,
+        I: Into<InstrumentId>,
+    {
+        Self {
+            exchange_time,
+            received_time: Utc::now(),
+            exchange: exchange.into(),
+            instrument: instrument.into(),
+            kind,
+        }
+    }
+
+    /// Set the time the [`Event`] was received, eg/ when replaying recorded events.
+    pub fn with_received_time(self, received_time: DateTime<Utc>) -> Self {
+        Self {
+            received_time,
+            ..self
+        }
+    }
+
+    /// Map the event `kind` using the provided function, keeping the envelope.
+    pub fn map<F, U>(self, f: F) -> Event<U, InstrumentId>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Event {
+            exchange_time: self.exchange_time,
+            received_time: self.received_time,
+            exchange: self.exchange,
+            instrument: self.instrument,
+            kind: f(self.kind),
+        }
+    }
+
+    /// Time elapsed between the exchange generating & receiving the [`Event`].
+    pub fn latency(&self) -> chrono::Duration {
+        self.received_time - self.exchange_time
+    }
+
+    /// [`Market`] the [`Event`] was generated for.
+    pub fn market(&self) -> Market<InstrumentId>
+    where
+        InstrumentId: Clone,
+    {
+        Market::new(self.exchange.clone(), self.instrument.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{instrument::kind::InstrumentKind, Side};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_event() {
+        let exchange_time = Utc.timestamp_millis_opt(1_700_000_000_000).unwrap();
+        let received_time = Utc.timestamp_millis_opt(1_700_000_000_250).unwrap();
+
+        let event = Event::<Side>::new(
+            exchange_time,
+            "binance_spot",
+            ("btc", "usdt", InstrumentKind::Spot),
+            Side::Buy,
+        )
+        .with_received_time(received_time);
+
+        assert_eq!(event.latency(), chrono::Duration::milliseconds(250));
+        assert_eq!(
+            event.market(),
+            Market::from(("binance_spot", "btc", "usdt", InstrumentKind::Spot))
+        );
+
+        let event = event.map(Side::invert);
+        assert_eq!(event.kind, Side::Sell);
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert_eq!(serde_json::from_str::<Event<Side>>(&json).unwrap(), event);
+    }
+}
@@ -0,0 +1,343 @@
+use crate::model::{
+    instrument::{symbol::Symbol, Instrument},
+    ExchangeId, Fee, Liquidity, Side,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Display, Formatter};
+
+/// New type representing an exchange assigned [`Order`] `String` identifier.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct OrderId(pub String);
+
+impl Debug for OrderId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Display for OrderId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl<S> From<S> for OrderId
+where
+    S: Into<String>,
+{
+    fn from(input: S) -> Self {
+        Self(input.into())
+    }
+}
+
+/// [`Order`] type.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderKind {
+    #[serde(alias = "MARKET", alias = "Market")]
+    Market,
+    #[serde(alias = "LIMIT", alias = "Limit")]
+    Limit,
+}
+
+/// [`Order`] time in force.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeInForce {
+    #[serde(alias = "GTC")]
+    GoodUntilCancelled,
+    #[serde(alias = "IOC")]
+    ImmediateOrCancel,
+    #[serde(alias = "FOK")]
+    FillOrKill,
+    #[serde(alias = "GTX", alias = "POST_ONLY")]
+    PostOnly,
+}
+
+/// [`Order`] lifecycle state.
+///
+/// An [`Order`] starts as [`OrderStatus::New`], may be [`OrderStatus::PartiallyFilled`] any
+/// number of times, and ends in one of the terminal states.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    #[serde(alias = "NEW")]
+    New,
+    #[serde(alias = "PARTIALLY_FILLED")]
+    PartiallyFilled,
+    #[serde(alias = "FILLED")]
+    Filled,
+    #[serde(alias = "CANCELED", alias = "CANCELLED", alias = "canceled")]
+    Cancelled,
+    #[serde(alias = "REJECTED")]
+    Rejected,
+    #[serde(alias = "EXPIRED")]
+    Expired,
+}
+
+impl OrderStatus {
+    /// Determine if the [`Order`] is resting on the book & may still be filled.
+    pub fn is_open(self) -> bool {
+        matches!(self, Self::New | Self::PartiallyFilled)
+    }
+
+    /// Determine if the [`Order`] lifecycle has ended.
+    pub fn is_terminal(self) -> bool {
+        !self.is_open()
+    }
+
+    /// Determine if the transition to the provided [`OrderStatus`] is valid.
+    ///
+    /// eg/ `New` => `Filled` is valid, but `Filled` => `Cancelled` & `PartiallyFilled` => `New`
+    /// are not.
+    pub fn can_transition_to(self, next: Self) -> bool {
+        match (self, next) {
+            (Self::New, _) => next != Self::New,
+            (Self::PartiallyFilled, next) => next != Self::New && next != Self::Rejected,
+            _ => false,
+        }
+    }
+}
+
+/// Normalised exchange [`Order`].
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct Order<InstrumentId = Instrument> {
+    pub exchange: ExchangeId,
+    pub instrument: InstrumentId,
+    pub id: OrderId,
+    /// Client provided identifier, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    pub side: Side,
+    pub kind: OrderKind,
+    pub time_in_force: TimeInForce,
+    /// Limit price, `None` for [`OrderKind::Market`] orders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    pub quantity: Decimal,
+    pub filled_quantity: Decimal,
+    pub status: OrderStatus,
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentId> Order<InstrumentId> {
+    /// Quantity still to be filled.
+    pub fn remaining_quantity(&self) -> Decimal {
+        self.quantity - self.filled_quantity
+    }
+
+    /// Apply the provided [`Fill`] of this [`Order`], updating the filled quantity & status.
+    ///
+    /// Returns a [`FillError`] without modifying the [`Order`] if it is no longer open, the
+    /// [`Fill`] belongs to a different order or instrument, or it would over-fill the order.
+    ///
+    /// Note: fills are not deduplicated by `trade_id`, since the [`Order`] does not retain the
+    /// trade ids already applied. Consumers of at-least-once fill streams (eg/ fills redelivered
+    /// after a reconnect) must dedupe by `trade_id` before applying.
+    pub fn apply_fill(&mut self, fill: &Fill<InstrumentId>) -> Result<(), FillError>
+    where
+        InstrumentId: PartialEq,
+    {
+        if self.status.is_terminal() {
+            return Err(FillError::OrderClosed(self.id.clone()));
+        }
+        if fill.order_id != self.id {
+            return Err(FillError::OrderMismatch {
+                order: self.id.clone(),
+                fill: fill.order_id.clone(),
+            });
+        }
+        if fill.instrument != self.instrument {
+            return Err(FillError::InstrumentMismatch(self.id.clone()));
+        }
+        if self.filled_quantity + fill.quantity > self.quantity {
+            return Err(FillError::Overfill {
+                order: self.id.clone(),
+                trade_id: fill.trade_id.clone(),
+            });
+        }
+
+        self.filled_quantity += fill.quantity;
+        self.status = if self.filled_quantity >= self.quantity {
+            OrderStatus::Filled
+        } else {
+            OrderStatus::PartiallyFilled
+        };
+        self.time = self.time.max(fill.time);
+        Ok(())
+    }
+}
+
+/// Reason a [`Fill`] could not be applied to an [`Order`].
+#[derive(Clone, Eq, PartialEq, Debug, thiserror::Error)]
+pub enum FillError {
+    #[error("order {0} is no longer open")]
+    OrderClosed(OrderId),
+
+    #[error("fill of order {fill} cannot be applied to order {order}")]
+    OrderMismatch { order: OrderId, fill: OrderId },
+
+    #[error("fill instrument does not match the instrument of order {0}")]
+    InstrumentMismatch(OrderId),
+
+    #[error("fill {trade_id} would over-fill order {order}")]
+    Overfill { order: OrderId, trade_id: String },
+}
+
+/// Normalised execution (trade) of an [`Order`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Fill<InstrumentId = Instrument> {
+    pub instrument: InstrumentId,
+    pub order_id: OrderId,
+    pub trade_id: String,
+    pub side: Side,
+    pub price: Decimal,
+    pub quantity: Decimal,
+    pub liquidity: Liquidity,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee: Option<Fee>,
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentId> Fill<InstrumentId> {
+    /// Notional value of the [`Fill`].
+    pub fn notional(&self) -> Decimal {
+        self.price * self.quantity
+    }
+}
+
+/// Normalised account [`Balance`] of an asset.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Balance {
+    pub asset: Symbol,
+    pub total: Decimal,
+    /// Amount available for trading & withdrawal.
+    pub free: Decimal,
+    pub time: DateTime<Utc>,
+}
+
+impl Balance {
+    /// Amount locked in open orders or positions.
+    pub fn used(&self) -> Decimal {
+        self.total - self.free
+    }
+}
+
+/// Normalised derivatives [`Position`], where a [`Side::Buy`] is long & a [`Side::Sell`] is
+/// short.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct Position<InstrumentId = Instrument> {
+    pub instrument: InstrumentId,
+    pub side: Side,
+    pub quantity: Decimal,
+    pub entry_price: Decimal,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liquidation_price: Option<Decimal>,
+    pub unrealised_pnl: Decimal,
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentId> Position<InstrumentId> {
+    /// Signed quantity of the [`Position`], negative when short.
+    pub fn signed_quantity(&self) -> Decimal {
+        self.quantity * Decimal::from(i8::from(self.side))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::instrument::kind::InstrumentKind;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_order_status_transitions() {
+        assert!(OrderStatus::New.can_transition_to(OrderStatus::Filled));
+        assert!(OrderStatus::New.can_transition_to(OrderStatus::Rejected));
+        assert!(OrderStatus::PartiallyFilled.can_transition_to(OrderStatus::PartiallyFilled));
+        assert!(!OrderStatus::PartiallyFilled.can_transition_to(OrderStatus::New));
+        assert!(!OrderStatus::Filled.can_transition_to(OrderStatus::Cancelled));
+        assert_eq!(
+            serde_json::from_str::<OrderStatus>(r#""CANCELED""#).unwrap(),
+            OrderStatus::Cancelled
+        );
+    }
+
+    #[test]
+    fn test_order_apply_fill() {
+        let time = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let instrument = Instrument::new("btc", "usdt", InstrumentKind::Spot);
+
+        let mut order = serde_json::from_str::<Order>(
+            r#"{
+                "exchange": "binance_spot",
+                "instrument": {"base": "btc", "quote": "usdt", "instrument_kind": "spot"},
+                "id": "28",
+                "side": "BUY",
+                "kind": "LIMIT",
+                "time_in_force": "GTC",
+                "price": "42000",
+                "quantity": "1",
+                "filled_quantity": "0",
+                "status": "NEW",
+                "time": "2024-01-01T00:00:00Z"
+            }"#,
+        )
+        .unwrap();
+
+        let mut fill = Fill {
+            instrument,
+            order_id: OrderId::from("28"),
+            trade_id: "1".to_string(),
+            side: Side::Buy,
+            price: dec!(42000),
+            quantity: dec!(0.4),
+            liquidity: Liquidity::Maker,
+            fee: None,
+            time,
+        };
+
+        assert_eq!(order.apply_fill(&fill), Ok(()));
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(order.remaining_quantity(), dec!(0.6));
+
+        // Fills of other orders, other instruments, or exceeding the quantity are rejected
+        let foreign = Fill {
+            order_id: OrderId::from("29"),
+            ..fill.clone()
+        };
+        assert!(matches!(
+            order.apply_fill(&foreign),
+            Err(FillError::OrderMismatch { .. })
+        ));
+        let foreign = Fill {
+            instrument: Instrument::new("eth", "usdt", InstrumentKind::Spot),
+            ..fill.clone()
+        };
+        assert!(matches!(
+            order.apply_fill(&foreign),
+            Err(FillError::InstrumentMismatch(_))
+        ));
+        let overfill = Fill {
+            quantity: dec!(0.7),
+            ..fill.clone()
+        };
+        assert!(matches!(
+            order.apply_fill(&overfill),
+            Err(FillError::Overfill { .. })
+        ));
+        assert_eq!(order.filled_quantity, dec!(0.4));
+
+        fill.quantity = dec!(0.6);
+        assert_eq!(order.apply_fill(&fill), Ok(()));
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert!(matches!(
+            order.apply_fill(&fill),
+            Err(FillError::OrderClosed(_))
+        ));
+        assert_eq!(order.filled_quantity, dec!(1));
+    }
+}
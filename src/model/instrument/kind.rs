@@ -1,25 +1,27 @@
-use chrono::{DateTime, Utc};
+use crate::model::ParseError;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
-use std::fmt::{Display, Formatter};
+use serde::{Deserialize, Serialize, Serializer};
+use std::{
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
 
 /// Defines the type of [`Instrument`](super::Instrument) which is being traded on a
 /// given `base_quote` market.
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum InstrumentKind {
+    #[default]
     Spot,
     Future(FutureContract),
     Perpetual,
     Option(OptionContract),
 }
 
-impl Default for InstrumentKind {
-    fn default() -> Self {
-        Self::Spot
-    }
-}
-
 impl Display for InstrumentKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -28,13 +30,13 @@ impl Display for InstrumentKind {
             match self {
                 InstrumentKind::Spot => "spot".to_string(),
                 InstrumentKind::Future(future) =>
-                    format!("future_{}-UTC", future.expiry.date_naive()),
+                    format!("future_{}-UTC", fmt_expiry(&future.expiry)),
                 InstrumentKind::Perpetual => "perpetual".to_string(),
                 InstrumentKind::Option(option) => format!(
                     "option_{}_{}_{}-UTC_{}",
                     option.kind,
                     option.exercise,
-                    option.expiry.date_naive(),
+                    fmt_expiry(&option.expiry),
                     option.strike,
                 ),
             }
@@ -42,6 +44,107 @@ impl Display for InstrumentKind {
     }
 }
 
+/// Parses the [`Display`] representation of an [`InstrumentKind`], case-insensitively.
+///
+/// Expiries at midnight UTC are represented by their date only, and any other expiry by its full
+/// time, such that every [`InstrumentKind`] round trips losslessly.
+///
+/// eg/ "spot", "perpetual", "future_2023-12-31-UTC", "future_2023-12-29T08:00:00-UTC",
+/// "option_call_american_2023-12-31-UTC_50000"
+impl FromStr for InstrumentKind {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut tokens = input.split('_');
+        let kind = InstrumentKind::parse_tokens(&mut tokens)
+            .map_err(|reason| ParseError::new("InstrumentKind", input, reason))?;
+
+        match tokens.next() {
+            None => Ok(kind),
+            Some(token) => Err(ParseError::new(
+                "InstrumentKind",
+                input,
+                format!("unexpected trailing token '{token}'"),
+            )),
+        }
+    }
+}
+
+impl InstrumentKind {
+    /// Parse an [`InstrumentKind`] from the next `_` separated tokens of a [`Display`]
+    /// representation, leaving any subsequent tokens unconsumed.
+    pub(crate) fn parse_tokens<'a, Tokens>(tokens: &mut Tokens) -> Result<Self, String>
+    where
+        Tokens: Iterator<Item = &'a str>,
+    {
+        let mut next = |field: &str| {
+            tokens
+                .next()
+                .map(str::to_lowercase)
+                .ok_or_else(|| format!("missing {field}"))
+        };
+
+        match next("kind")?.as_str() {
+            "spot" => Ok(InstrumentKind::Spot),
+            "perpetual" => Ok(InstrumentKind::Perpetual),
+            "future" => Ok(InstrumentKind::Future(FutureContract {
+                expiry: parse_expiry(&next("expiry")?)?,
+            })),
+            "option" => {
+                let kind = match next("option kind")?.as_str() {
+                    "call" => OptionKind::Call,
+                    "put" => OptionKind::Put,
+                    other => return Err(format!("invalid option kind '{other}'")),
+                };
+                let exercise = match next("option exercise")?.as_str() {
+                    "american" => OptionExercise::American,
+                    "bermudan" => OptionExercise::Bermudan,
+                    "european" => OptionExercise::European,
+                    other => return Err(format!("invalid option exercise '{other}'")),
+                };
+                let expiry = parse_expiry(&next("expiry")?)?;
+                let strike = next("strike")?;
+                let strike = Decimal::from_str(&strike)
+                    .map_err(|error| format!("invalid strike '{strike}': {error}"))?;
+
+                Ok(InstrumentKind::Option(OptionContract::new(
+                    kind, exercise, expiry, strike,
+                )))
+            }
+            other => Err(format!("invalid kind '{other}'")),
+        }
+    }
+}
+
+/// Format an expiry as its date if it is midnight UTC, otherwise as its full time.
+///
+/// eg/ "2023-12-31", "2023-12-29T08:00:00", "2023-12-29T08:00:00.500"
+fn fmt_expiry(expiry: &DateTime<Utc>) -> String {
+    if expiry.time() == NaiveTime::MIN {
+        expiry.format("%Y-%m-%d").to_string()
+    } else {
+        expiry.format("%Y-%m-%dT%H:%M:%S%.f").to_string()
+    }
+}
+
+/// Parse a lowercase `2023-12-31-utc` expiry date as midnight UTC, or a lowercase
+/// `2023-12-29t08:00:00-utc` expiry time.
+fn parse_expiry(input: &str) -> Result<DateTime<Utc>, String> {
+    input
+        .strip_suffix("-utc")
+        .and_then(|expiry| {
+            NaiveDateTime::parse_from_str(expiry, "%Y-%m-%dt%H:%M:%S%.f")
+                .ok()
+                .or_else(|| {
+                    NaiveDate::parse_from_str(expiry, "%Y-%m-%d")
+                        .ok()
+                        .map(|date| date.and_time(NaiveTime::MIN))
+                })
+        })
+        .map(|expiry| expiry.and_utc())
+        .ok_or_else(|| format!("invalid expiry '{input}'"))
+}
+
 /// Configuration of an [`InstrumentKind::Future`] contract.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
 pub struct FutureContract {
@@ -50,15 +153,65 @@ pub struct FutureContract {
 }
 
 /// Configuration of an [`InstrumentKind::Option`] contract.
-#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
+///
+/// Ordered as in an option chain: by expiry, strike, [`PutCall`], then exercise style. The
+/// strike is serialised normalised (eg/ `50000.0` => `"50000"`), so equal contracts always
+/// serialise identically.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Deserialize, Serialize)]
 pub struct OptionContract {
     pub kind: OptionKind,
     pub exercise: OptionExercise,
     #[serde(with = "chrono::serde::ts_milliseconds")]
     pub expiry: DateTime<Utc>,
+    #[serde(serialize_with = "se_normalised")]
     pub strike: Decimal,
 }
 
+impl OptionContract {
+    /// Construct a new [`OptionContract`] using the provided configuration, normalising the
+    /// strike.
+    pub fn new(
+        kind: PutCall,
+        exercise: OptionExercise,
+        expiry: DateTime<Utc>,
+        strike: Decimal,
+    ) -> Self {
+        Self {
+            kind,
+            exercise,
+            expiry,
+            strike: strike.normalize(),
+        }
+    }
+}
+
+impl PartialOrd for OptionContract {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OptionContract {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.expiry
+            .cmp(&other.expiry)
+            .then_with(|| self.strike.cmp(&other.strike))
+            .then_with(|| self.kind.cmp(&other.kind))
+            .then_with(|| self.exercise.cmp(&other.exercise))
+    }
+}
+
+/// Serialise a [`Decimal`] with trailing zeros removed.
+fn se_normalised<S>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    Serialize::serialize(&value.normalize(), serializer)
+}
+
+/// Put or Call [`OptionContract`] kind, as named by eg/ Deribit & OKX.
+pub type PutCall = OptionKind;
+
 /// [`OptionContract`] kind - Put or Call.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
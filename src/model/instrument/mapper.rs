@@ -0,0 +1,160 @@
+use crate::{
+    error::SocketError,
+    model::{instrument::Instrument, SubscriptionId},
+};
+use std::{collections::HashMap, fmt::Display, hash::Hash};
+
+/// Bidirectional mapping between [`Instrument`]s & exchange native symbols.
+///
+/// Typically populated from an exchange-info response by collecting `(Instrument, symbol)`
+/// pairs, such that subscriptions & requests use the exchange symbol, and received messages are
+/// mapped back to the [`Instrument`].
+///
+/// eg/ `Instrument::new("btc", "usdt", Perpetual)` <=> OKX "BTC-USDT-SWAP"
+#[derive(Clone, Debug)]
+pub struct SymbolMapper<InstrumentId = Instrument> {
+    to_exchange: HashMap<InstrumentId, String>,
+    to_instrument: HashMap<String, InstrumentId>,
+}
+
+impl<InstrumentId> Default for SymbolMapper<InstrumentId> {
+    fn default() -> Self {
+        Self {
+            to_exchange: HashMap::default(),
+            to_instrument: HashMap::default(),
+        }
+    }
+}
+
+impl<InstrumentId, S> FromIterator<(InstrumentId, S)> for SymbolMapper<InstrumentId>
+where
+    InstrumentId: Clone + Eq + Hash,
+    S: Into<String>,
+{
+    fn from_iter<Iter>(iter: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (InstrumentId, S)>,
+    {
+        let mut mapper = Self::default();
+        mapper.extend(iter);
+        mapper
+    }
+}
+
+impl<InstrumentId, S> Extend<(InstrumentId, S)> for SymbolMapper<InstrumentId>
+where
+    InstrumentId: Clone + Eq + Hash,
+    S: Into<String>,
+{
+    fn extend<Iter>(&mut self, iter: Iter)
+    where
+        Iter: IntoIterator<Item = (InstrumentId, S)>,
+    {
+        iter.into_iter()
+            .for_each(|(instrument, symbol)| self.insert(instrument, symbol))
+    }
+}
+
+impl<InstrumentId> SymbolMapper<InstrumentId>
+where
+    InstrumentId: Clone + Eq + Hash,
+{
+    /// Map the provided [`Instrument`] to the provided exchange symbol, replacing any existing
+    /// mapping of either, such that the mapping remains one-to-one.
+    pub fn insert<S>(&mut self, instrument: InstrumentId, symbol: S)
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into();
+
+        if let Some(stale_symbol) = self.to_exchange.remove(&instrument) {
+            self.to_instrument.remove(&stale_symbol);
+        }
+        if let Some(stale_instrument) = self.to_instrument.remove(&symbol) {
+            self.to_exchange.remove(&stale_instrument);
+        }
+
+        self.to_exchange.insert(instrument.clone(), symbol.clone());
+        self.to_instrument.insert(symbol, instrument);
+    }
+
+    /// Exchange native symbol of the provided [`Instrument`].
+    pub fn exchange_symbol(&self, instrument: &InstrumentId) -> Result<&str, SocketError>
+    where
+        InstrumentId: Display,
+    {
+        self.to_exchange
+            .get(instrument)
+            .map(String::as_str)
+            .ok_or_else(|| {
+                SocketError::Unidentifiable(SubscriptionId::from(instrument.to_string()))
+            })
+    }
+
+    /// [`Instrument`] of the provided exchange native symbol.
+    pub fn instrument(&self, symbol: &str) -> Result<&InstrumentId, SocketError> {
+        self.to_instrument
+            .get(symbol)
+            .ok_or_else(|| SocketError::Unidentifiable(SubscriptionId::from(symbol)))
+    }
+
+    /// Number of mapped [`Instrument`]s.
+    pub fn len(&self) -> usize {
+        self.to_exchange.len()
+    }
+
+    /// Determine if no [`Instrument`]s are mapped.
+    pub fn is_empty(&self) -> bool {
+        self.to_exchange.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::instrument::kind::InstrumentKind;
+    use serde::Deserialize;
+
+    #[test]
+    fn test_symbol_mapper() {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct OkxInstrument {
+            inst_id: String,
+            base_ccy: String,
+            quote_ccy: String,
+        }
+
+        let exchange_info = serde_json::from_str::<Vec<OkxInstrument>>(
+            r#"[
+                {"instId": "BTC-USDT", "baseCcy": "BTC", "quoteCcy": "USDT"},
+                {"instId": "ETH-USDT", "baseCcy": "ETH", "quoteCcy": "USDT"}
+            ]"#,
+        )
+        .unwrap();
+
+        let mut mapper = exchange_info
+            .into_iter()
+            .map(|info| {
+                let instrument =
+                    Instrument::new(info.base_ccy, info.quote_ccy, InstrumentKind::Spot);
+                (instrument, info.inst_id)
+            })
+            .collect::<SymbolMapper>();
+
+        let btc = Instrument::new("btc", "usdt", InstrumentKind::Spot);
+        assert_eq!(mapper.len(), 2);
+        assert_eq!(mapper.exchange_symbol(&btc).unwrap(), "BTC-USDT");
+        assert_eq!(mapper.instrument("ETH-USDT").unwrap().base.as_str(), "eth");
+        assert!(matches!(
+            mapper.instrument("SOL-USDT"),
+            Err(SocketError::Unidentifiable(id)) if id == SubscriptionId::from("SOL-USDT")
+        ));
+
+        // Re-mapping replaces the stale exchange symbol in both directions
+        mapper.insert(btc.clone(), "BTC-USDT-V2");
+        assert_eq!(mapper.len(), 2);
+        assert_eq!(mapper.exchange_symbol(&btc).unwrap(), "BTC-USDT-V2");
+        assert!(mapper.instrument("BTC-USDT").is_err());
+    }
+}
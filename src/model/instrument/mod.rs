@@ -1,26 +1,106 @@
-use crate::model::instrument::{kind::InstrumentKind, symbol::Symbol};
+use crate::model::{
+    instrument::{kind::InstrumentKind, symbol::Symbol},
+    ParseError,
+};
 use serde::{Deserialize, Serialize};
-use std::fmt::{Debug, Display, Formatter};
+use std::{
+    fmt::{Debug, Display, Formatter},
+    str::FromStr,
+};
 
 pub mod kind;
+pub mod mapper;
+pub mod spec;
 pub mod symbol;
 
 /// Barter representation of an `Instrument`. Used to uniquely identify a `base_quote` pair, and it's
 /// associated instrument type.
 ///
-/// eg/ Instrument { base: "btc", quote: "usdt", kind: Spot }
+/// eg/ Instrument { base: "btc", quote: "usdt", kind: Spot, settle: None }
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case", from = "InstrumentFields")]
 pub struct Instrument {
     pub base: Symbol,
     pub quote: Symbol,
     #[serde(rename = "instrument_kind")]
     pub kind: InstrumentKind,
+    /// Settlement [`Symbol`] of a derivative, if it differs from the quote, such that
+    /// coin-margined (inverse) & quote-margined contracts are distinguishable.
+    ///
+    /// Always `None` if the [`Instrument`] settles in the quote, such that equal contracts have
+    /// equal Eq, Hash & Display representations.
+    ///
+    /// eg/ Coin-margined BTCUSD perpetual settles in "btc".
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settle: Option<Symbol>,
+}
+
+/// Deserialised [`Instrument`] fields, normalised via [`Instrument::normalise_settle`].
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct InstrumentFields {
+    base: Symbol,
+    quote: Symbol,
+    #[serde(rename = "instrument_kind")]
+    kind: InstrumentKind,
+    #[serde(default)]
+    settle: Option<Symbol>,
+}
+
+impl From<InstrumentFields> for Instrument {
+    fn from(fields: InstrumentFields) -> Self {
+        Self {
+            base: fields.base,
+            quote: fields.quote,
+            kind: fields.kind,
+            settle: fields.settle,
+        }
+        .normalise_settle()
+    }
 }
 
 impl Display for Instrument {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}_{}_{}", self.base, self.quote, self.kind)
+        write!(f, "{}_{}_{}", self.base, self.quote, self.kind)?;
+        match &self.settle {
+            Some(settle) => write!(f, "_{settle}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses the [`Display`] representation of an [`Instrument`], ie/ `base_quote_kind[_settle]`.
+///
+/// eg/ "btc_usdt_spot", "btc_usd_perpetual_btc"
+impl FromStr for Instrument {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = |reason: String| ParseError::new("Instrument", input, reason);
+        let mut tokens = input.split('_');
+
+        let mut symbol = |field: &str| match tokens.next() {
+            Some(symbol) if !symbol.is_empty() => Ok(Symbol::intern(symbol)),
+            _ => Err(error(format!("missing {field}"))),
+        };
+        let base = symbol("base")?;
+        let quote = symbol("quote")?;
+
+        let kind = InstrumentKind::parse_tokens(&mut tokens).map_err(error)?;
+
+        let settle = match (tokens.next(), tokens.next()) {
+            (None, _) => None,
+            (Some(settle), None) if !settle.is_empty() => Some(Symbol::intern(settle)),
+            (Some(_), _) => return Err(error("unexpected trailing tokens".to_string())),
+        };
+
+        Ok(Self {
+            base,
+            quote,
+            kind,
+            settle,
+        }
+        .normalise_settle())
     }
 }
 
@@ -29,11 +109,7 @@ where
     S: Into<Symbol>,
 {
     fn from((base, quote, kind): (S, S, InstrumentKind)) -> Self {
-        Self {
-            base: base.into(),
-            quote: quote.into(),
-            kind,
-        }
+        Self::new(base, quote, kind)
     }
 }
 
@@ -47,15 +123,56 @@ impl Instrument {
             base: base.into(),
             quote: quote.into(),
             kind,
+            settle: None,
         }
     }
+
+    /// Settle the [`Instrument`] in the provided [`Symbol`]. Settling in the quote is equivalent
+    /// to not providing a settlement [`Symbol`], so is stored as `None`.
+    ///
+    /// eg/ `Instrument::new("btc", "usd", InstrumentKind::Perpetual).with_settle("btc")`
+    pub fn with_settle<S>(self, settle: S) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self {
+            settle: Some(settle.into()),
+            ..self
+        }
+        .normalise_settle()
+    }
+
+    /// Store a settlement [`Symbol`] equal to the quote as `None`, since both describe the same
+    /// contract.
+    fn normalise_settle(self) -> Self {
+        match &self.settle {
+            Some(settle) if settle == &self.quote => Self {
+                settle: None,
+                ..self
+            },
+            _ => self,
+        }
+    }
+
+    /// [`Symbol`] the [`Instrument`] settles in, defaulting to the quote.
+    pub fn settlement(&self) -> &Symbol {
+        self.settle.as_ref().unwrap_or(&self.quote)
+    }
+
+    /// Determine if the [`Instrument`] is coin-margined (inverse), ie/ settles in the base.
+    pub fn is_inverse(&self) -> bool {
+        self.settlement() == &self.base
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::instrument::kind::{
-        FutureContract, InstrumentKind, OptionContract, OptionExercise, OptionKind,
+    use crate::model::{
+        instrument::kind::{
+            FutureContract, InstrumentKind, OptionContract, OptionExercise, OptionKind, PutCall,
+        },
+        ExchangeId, MarketId,
     };
     use chrono::{TimeZone, Utc};
     use rust_decimal_macros::dec;
@@ -94,7 +211,14 @@ mod tests {
                 expected: Ok(Instrument::from(("btc", "usd", InstrumentKind::Perpetual))),
             },
             TestCase {
-                // TC3: Valid Option Call American
+                // TC3: Valid coin-margined FuturePerpetual
+                input: r#"{"base": "btc", "quote": "usd", "instrument_kind": "perpetual", "settle": "btc" }"#,
+                expected: Ok(
+                    Instrument::from(("btc", "usd", InstrumentKind::Perpetual)).with_settle("btc")
+                ),
+            },
+            TestCase {
+                // TC4: Valid Option Call American
                 input: r#"{
                     "base": "btc",
                     "quote": "usd",
@@ -119,7 +243,7 @@ mod tests {
                 ))),
             },
             TestCase {
-                // TC4: Valid Option Put Bermudan
+                // TC5: Valid Option Put Bermudan
                 input: r#"{
                     "base": "btc",
                     "quote": "usd",
@@ -162,4 +286,148 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_instrument_from_str() {
+        let expiry = Utc.with_ymd_and_hms(2023, 12, 31, 0, 0, 0).unwrap();
+        let deribit_expiry = Utc.with_ymd_and_hms(2023, 12, 29, 8, 0, 0).unwrap();
+        let instruments = [
+            Instrument::new("btc", "usdt", InstrumentKind::Spot),
+            Instrument::new("btc", "usd", InstrumentKind::Perpetual).with_settle("btc"),
+            Instrument::new(
+                "eth",
+                "usd",
+                InstrumentKind::Future(FutureContract { expiry }),
+            ),
+            Instrument::new(
+                "btc",
+                "usd",
+                InstrumentKind::Option(OptionContract::new(
+                    PutCall::Put,
+                    OptionExercise::European,
+                    expiry,
+                    dec!(50000.0),
+                )),
+            ),
+            Instrument::new(
+                "btc",
+                "usd",
+                InstrumentKind::Future(FutureContract {
+                    expiry: deribit_expiry,
+                }),
+            ),
+            Instrument::new(
+                "eth",
+                "usd",
+                InstrumentKind::Option(OptionContract::new(
+                    PutCall::Call,
+                    OptionExercise::European,
+                    deribit_expiry + chrono::Duration::milliseconds(500),
+                    dec!(2500),
+                )),
+            ),
+        ];
+
+        for instrument in instruments {
+            let display = instrument.to_string();
+            assert_eq!(display.parse::<Instrument>().unwrap(), instrument);
+
+            let market_id = MarketId::new(&ExchangeId::from("binance_spot"), &instrument);
+            let market = market_id.market().unwrap();
+            assert_eq!(market.exchange, ExchangeId::from("binance_spot"));
+            assert_eq!(market.instrument, instrument);
+            assert_eq!(market_id.0.parse::<MarketId>().unwrap(), market_id);
+        }
+
+        assert_eq!(
+            "FUTURE_2023-12-31-UTC".parse::<InstrumentKind>().unwrap(),
+            InstrumentKind::Future(FutureContract { expiry })
+        );
+        assert_eq!(
+            InstrumentKind::Future(FutureContract {
+                expiry: deribit_expiry
+            })
+            .to_string(),
+            "future_2023-12-29T08:00:00-UTC"
+        );
+        assert!("btc_usdt".parse::<Instrument>().is_err());
+        assert!("btc_usdt_swap".parse::<Instrument>().is_err());
+        assert!("btc_usdt_spot_usdt_btc".parse::<Instrument>().is_err());
+        assert!("option_call_asian_2023-12-31-UTC_1"
+            .parse::<InstrumentKind>()
+            .is_err());
+        assert!("binance".parse::<MarketId>().is_err());
+    }
+
+    #[test]
+    fn test_instrument_settlement() {
+        let linear = Instrument::new("btc", "usdt", InstrumentKind::Perpetual);
+        let inverse = Instrument::new("btc", "usd", InstrumentKind::Perpetual).with_settle("btc");
+
+        assert!(!linear.is_inverse());
+        assert_eq!(linear.settlement(), &Symbol::new("usdt"));
+        assert_eq!(linear.to_string(), "btc_usdt_perpetual");
+        assert!(inverse.is_inverse());
+        assert_eq!(inverse.to_string(), "btc_usd_perpetual_btc");
+
+        // Settling in the quote describes the same contract as no settlement Symbol
+        let quote_settled = linear.clone().with_settle("usdt");
+        assert_eq!(quote_settled, linear);
+        assert_eq!(quote_settled.settle, None);
+        assert_eq!(
+            "btc_usdt_perpetual_usdt".parse::<Instrument>().unwrap(),
+            linear
+        );
+        assert_eq!(
+            serde_json::from_str::<Instrument>(
+                r#"{"base":"btc","quote":"usdt","instrument_kind":"perpetual","settle":"usdt"}"#
+            )
+            .unwrap(),
+            linear
+        );
+        assert_eq!(
+            serde_json::to_string(&linear).unwrap(),
+            r#"{"base":"btc","quote":"usdt","instrument_kind":"perpetual"}"#
+        );
+    }
+
+    #[test]
+    fn test_option_contract_ser_ord() {
+        let expiry = Utc.timestamp_millis_opt(1703980800000).unwrap();
+        let option = |kind, strike| {
+            InstrumentKind::Option(OptionContract::new(
+                kind,
+                OptionExercise::European,
+                expiry,
+                strike,
+            ))
+        };
+
+        let call = option(PutCall::Call, dec!(50000.0));
+        assert_eq!(
+            serde_json::to_string(&call).unwrap(),
+            r#"{"option":{"kind":"call","exercise":"european","expiry":1703980800000,"strike":"50000"}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<InstrumentKind>(&serde_json::to_string(&call).unwrap()).unwrap(),
+            call
+        );
+
+        let mut chain = vec![
+            option(PutCall::Put, dec!(60000)),
+            option(PutCall::Put, dec!(50000)),
+            option(PutCall::Call, dec!(60000)),
+            call,
+        ];
+        chain.sort();
+        assert_eq!(
+            chain,
+            vec![
+                call,
+                option(PutCall::Put, dec!(50000)),
+                option(PutCall::Call, dec!(60000)),
+                option(PutCall::Put, dec!(60000)),
+            ]
+        );
+    }
 }
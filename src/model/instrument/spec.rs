@@ -0,0 +1,131 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+
+/// Trading rules of an [`Instrument`](super::Instrument), normalised from an exchange-info
+/// response.
+///
+/// eg/ Binance `PRICE_FILTER`, `LOT_SIZE` & `NOTIONAL` filters.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct InstrumentSpec {
+    /// Number of decimal places of a valid price.
+    pub price_precision: u32,
+    /// Number of decimal places of a valid quantity.
+    pub quantity_precision: u32,
+    /// Minimum price increment.
+    pub tick_size: Decimal,
+    /// Minimum quantity increment (ie/ lot size).
+    pub step_size: Decimal,
+    /// Minimum `price * quantity * contract_multiplier` of an order, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_notional: Option<Decimal>,
+    /// Maximum `price * quantity * contract_multiplier` of an order, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_notional: Option<Decimal>,
+    /// Quantity of the underlying represented by one contract. `1` for spot instruments.
+    #[serde(default = "default_contract_multiplier")]
+    pub contract_multiplier: Decimal,
+}
+
+impl InstrumentSpec {
+    /// Construct a new [`InstrumentSpec`] from the provided tick & step sizes, deriving the
+    /// price & quantity precision from them.
+    pub fn new(tick_size: Decimal, step_size: Decimal) -> Self {
+        Self {
+            price_precision: tick_size.normalize().scale(),
+            quantity_precision: step_size.normalize().scale(),
+            tick_size,
+            step_size,
+            min_notional: None,
+            max_notional: None,
+            contract_multiplier: default_contract_multiplier(),
+        }
+    }
+
+    /// Set the minimum & maximum order notional.
+    pub fn with_notional(self, min: Option<Decimal>, max: Option<Decimal>) -> Self {
+        Self {
+            min_notional: min,
+            max_notional: max,
+            ..self
+        }
+    }
+
+    /// Set the contract multiplier of a derivative.
+    pub fn with_contract_multiplier(self, contract_multiplier: Decimal) -> Self {
+        Self {
+            contract_multiplier,
+            ..self
+        }
+    }
+
+    /// Round the provided price down to a valid multiple of the tick size.
+    pub fn round_price(&self, price: Decimal) -> Decimal {
+        round_down_to(price, self.tick_size)
+            .round_dp_with_strategy(self.price_precision, RoundingStrategy::ToZero)
+    }
+
+    /// Round the provided quantity down to a valid multiple of the step size.
+    pub fn round_quantity(&self, quantity: Decimal) -> Decimal {
+        round_down_to(quantity, self.step_size)
+            .round_dp_with_strategy(self.quantity_precision, RoundingStrategy::ToZero)
+    }
+
+    /// Notional value of an order with the provided price & quantity.
+    pub fn notional(&self, price: Decimal, quantity: Decimal) -> Decimal {
+        price * quantity * self.contract_multiplier
+    }
+
+    /// Determine if an order with the provided price & quantity is within the notional limits.
+    pub fn is_notional_valid(&self, price: Decimal, quantity: Decimal) -> bool {
+        let notional = self.notional(price, quantity);
+        self.min_notional.is_none_or(|min| notional >= min)
+            && self.max_notional.is_none_or(|max| notional <= max)
+    }
+}
+
+fn default_contract_multiplier() -> Decimal {
+    Decimal::ONE
+}
+
+/// Round the provided value down to a multiple of the provided increment, if non-zero.
+fn round_down_to(value: Decimal, increment: Decimal) -> Decimal {
+    if increment.is_zero() {
+        value
+    } else {
+        (value / increment).floor() * increment
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_instrument_spec() {
+        let spec = serde_json::from_str::<InstrumentSpec>(
+            r#"{
+                "price_precision": 2,
+                "quantity_precision": 5,
+                "tick_size": "0.01",
+                "step_size": "0.00001",
+                "min_notional": "5"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            spec,
+            InstrumentSpec::new(dec!(0.01), dec!(0.00001)).with_notional(Some(dec!(5)), None)
+        );
+        assert_eq!(spec.round_price(dec!(43210.129)), dec!(43210.12));
+        assert_eq!(spec.round_quantity(dec!(0.123456)), dec!(0.12345));
+        assert!(spec.is_notional_valid(dec!(43210.12), dec!(0.001)));
+        assert!(!spec.is_notional_valid(dec!(43210.12), dec!(0.0001)));
+
+        let tick = InstrumentSpec::new(dec!(0.5), dec!(1)).with_contract_multiplier(dec!(100));
+        assert_eq!(tick.price_precision, 1);
+        assert_eq!(tick.round_price(dec!(100.7)), dec!(100.5));
+        assert_eq!(tick.notional(dec!(100), dec!(2)), dec!(20000));
+    }
+}
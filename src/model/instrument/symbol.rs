@@ -1,11 +1,43 @@
-use serde::{Deserialize, Deserializer, Serialize};
-use std::fmt::{Debug, Display, Formatter};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    borrow::{Borrow, Cow},
+    cmp::Ordering,
+    collections::{hash_map::DefaultHasher, HashMap},
+    fmt::{Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    sync::{Arc, OnceLock, RwLock, Weak},
+};
 
 /// Barter new type representing a currency symbol `String` identifier.
 ///
+/// Symbols are interned, such that equal live [`Symbol`]s share the same lowercase `Arc<str>`
+/// storage, cloning is a reference count increment, and comparisons are pointer-fast. The
+/// interner only holds weak references, so symbols that are no longer in use are freed & pruned.
+///
 /// eg/ "btc", "eth", "usdt", etc
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize)]
-pub struct Symbol(String);
+#[derive(Clone)]
+pub struct Symbol(Arc<str>);
+
+/// Number of independently locked interner shards, reducing lock contention between threads.
+const SHARDS: usize = 16;
+
+/// Shard of the process wide interner, mapping lowercase symbols to their shared storage.
+#[derive(Default)]
+struct Shard {
+    symbols: HashMap<Box<str>, Weak<str>>,
+    /// Number of symbols after which dead entries are next pruned.
+    prune_at: usize,
+}
+
+/// Process wide interner shard responsible for the provided lowercase symbol.
+fn shard(symbol: &str) -> &'static RwLock<Shard> {
+    static INTERNER: OnceLock<[RwLock<Shard>; SHARDS]> = OnceLock::new();
+
+    let mut hasher = DefaultHasher::new();
+    symbol.hash(&mut hasher);
+    &INTERNER.get_or_init(|| std::array::from_fn(|_| RwLock::default()))
+        [hasher.finish() as usize % SHARDS]
+}
 
 impl Debug for Symbol {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -25,30 +57,165 @@ impl AsRef<str> for Symbol {
     }
 }
 
+impl Borrow<str> for Symbol {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0 == other.0
+    }
+}
+
+impl Eq for Symbol {}
+
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if Arc::ptr_eq(&self.0, &other.0) {
+            Ordering::Equal
+        } else {
+            self.0.cmp(&other.0)
+        }
+    }
+}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl Serialize for Symbol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
 impl<'de> Deserialize<'de> for Symbol {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        String::deserialize(deserializer).map(Symbol::new)
+        <Cow<'de, str>>::deserialize(deserializer).map(|input| Symbol::intern(&input))
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(input: &str) -> Self {
+        Symbol::intern(input)
+    }
+}
+
+impl From<&String> for Symbol {
+    fn from(input: &String) -> Self {
+        Symbol::intern(input)
     }
 }
 
-impl<S> From<S> for Symbol
-where
-    S: Into<String>,
-{
-    fn from(input: S) -> Self {
-        Symbol::new(input)
+impl From<String> for Symbol {
+    fn from(input: String) -> Self {
+        Symbol::intern(&input)
+    }
+}
+
+impl From<Cow<'_, str>> for Symbol {
+    fn from(input: Cow<'_, str>) -> Self {
+        Symbol::intern(&input)
     }
 }
 
 impl Symbol {
-    /// Construct a new [`Symbol`] new type using the provided `Into<Symbol>` value.
+    /// Construct a new [`Symbol`] new type using the provided `AsRef<str>` value, only
+    /// allocating if an equal symbol is not currently interned.
     pub fn new<S>(input: S) -> Self
     where
-        S: Into<String>,
+        S: AsRef<str>,
     {
-        Self(input.into().to_lowercase())
+        Self::intern(input.as_ref())
+    }
+
+    /// Construct a new [`Symbol`] from the provided `&str`, only allocating if an equal symbol
+    /// is not currently interned.
+    pub fn intern(input: &str) -> Self {
+        let input = match input.chars().any(char::is_uppercase) {
+            true => Cow::Owned(input.to_lowercase()),
+            false => Cow::Borrowed(input),
+        };
+        let shard = shard(&input);
+
+        if let Some(symbol) = shard
+            .read()
+            .expect("Symbol interner lock poisoned")
+            .symbols
+            .get(input.as_ref())
+            .and_then(Weak::upgrade)
+        {
+            return Self(symbol);
+        }
+
+        let mut shard = shard.write().expect("Symbol interner lock poisoned");
+        if let Some(symbol) = shard.symbols.get(input.as_ref()).and_then(Weak::upgrade) {
+            return Self(symbol);
+        }
+
+        // Prune symbols that are no longer in use, amortised by doubling the prune threshold
+        if shard.symbols.len() >= shard.prune_at {
+            shard.symbols.retain(|_, symbol| symbol.strong_count() > 0);
+            shard.prune_at = (shard.symbols.len() * 2).max(64);
+        }
+
+        let symbol = Arc::<str>::from(input.as_ref());
+        shard
+            .symbols
+            .insert(Box::from(input.as_ref()), Arc::downgrade(&symbol));
+        Self(symbol)
+    }
+
+    /// Lowercase `str` representation of the [`Symbol`].
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_interning() {
+        let symbol = Symbol::new("BTC");
+        let interned = Symbol::intern("btc");
+        let deserialised = serde_json::from_str::<Symbol>(r#""Btc""#).unwrap();
+
+        assert_eq!(symbol, interned);
+        assert!(Arc::ptr_eq(&symbol.0, &interned.0));
+        assert!(Arc::ptr_eq(&symbol.0, &deserialised.0));
+        assert_eq!(symbol.as_str(), "btc");
+        assert_eq!(serde_json::to_string(&symbol).unwrap(), r#""btc""#);
+        assert!(Symbol::new("eth") < Symbol::new("usdt"));
+    }
+
+    #[test]
+    fn test_symbol_interner_frees_unused() {
+        let symbol = Symbol::new("unused_symbol");
+        let weak = Arc::downgrade(&symbol.0);
+        drop(symbol);
+
+        // Interner only holds a weak reference, so unused symbols are freed & re-interned
+        assert!(weak.upgrade().is_none());
+        let symbol = Symbol::from("UNUSED_SYMBOL");
+        assert_eq!(symbol.as_str(), "unused_symbol");
+        assert!(Arc::ptr_eq(&symbol.0, &Symbol::new("unused_symbol").0));
     }
 }
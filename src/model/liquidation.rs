@@ -0,0 +1,64 @@
+use crate::model::{instrument::Instrument, Side};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Normalised forced [`Liquidation`] of a derivatives position.
+///
+/// The [`Side`] is that of the liquidation order, such that a [`Side::Sell`] liquidates a long
+/// position & a [`Side::Buy`] liquidates a short position.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct Liquidation<InstrumentId = Instrument> {
+    pub instrument: InstrumentId,
+    pub side: Side,
+    pub price: Decimal,
+    pub quantity: Decimal,
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentId> Liquidation<InstrumentId> {
+    /// Notional value of the [`Liquidation`], in the quote (or contract) currency.
+    pub fn notional(&self) -> Decimal {
+        self.price * self.quantity
+    }
+
+    /// Side of the liquidated position, eg/ [`Side::Buy`] for a long position.
+    pub fn position_side(&self) -> Side {
+        self.side.invert()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::instrument::kind::InstrumentKind;
+    use chrono::TimeZone;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_liquidation() {
+        let liquidation = serde_json::from_str::<Liquidation>(
+            r#"{
+                "instrument": {"base": "btc", "quote": "usdt", "instrument_kind": "perpetual"},
+                "side": "SELL",
+                "price": "42000.5",
+                "quantity": "0.2",
+                "time": "2024-01-01T00:00:00Z"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            liquidation,
+            Liquidation {
+                instrument: Instrument::new("btc", "usdt", InstrumentKind::Perpetual),
+                side: Side::Sell,
+                price: dec!(42000.5),
+                quantity: dec!(0.2),
+                time: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            }
+        );
+        assert_eq!(liquidation.notional(), dec!(8400.1));
+        assert_eq!(liquidation.position_side(), Side::Buy);
+    }
+}
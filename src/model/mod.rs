@@ -1,8 +1,11 @@
 use crate::model::instrument::{kind::InstrumentKind, symbol::Symbol, Instrument};
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     borrow::Cow,
+    convert::Infallible,
     fmt::{Debug, Display, Formatter},
+    ops::{Deref, DerefMut},
+    str::FromStr,
 };
 
 /// [`Instrument`] related data structures.
@@ -10,17 +13,41 @@ use std::{
 /// eg/ `Instrument`, `InstrumentKind`, `OptionContract`, `Symbol`, etc.
 pub mod instrument;
 
-/// Represents a unique combination of an [`Exchange`] & an [`Instrument`].
+/// [`Asset`](asset::Asset) currency model of balances, deposits & withdrawals, distinct from a
+/// plain [`Symbol`].
+pub mod asset;
+
+/// Venue [`Notice`](notice::Notice)s such as delistings & maintenance banners.
+pub mod notice;
+
+/// Normalised [`OrderBook`](book::OrderBook) & [`Level`](book::Level) update application.
+pub mod book;
+
+/// Normalised OHLCV [`Candle`](candle::Candle) & [`TimeInterval`](candle::TimeInterval).
+pub mod candle;
+
+/// Normalised derivatives position [`Liquidation`](liquidation::Liquidation).
+pub mod liquidation;
+
+/// Normalised private execution domain models, eg/ [`Order`](execution::Order),
+/// [`Fill`](execution::Fill), [`Balance`](execution::Balance) & [`Position`](execution::Position).
+pub mod execution;
+
+/// Generic [`Event`](event::Event) envelope of normalised market data, identified by
+/// [`ExchangeId`] & [`Instrument`].
+pub mod event;
+
+/// Represents a unique combination of an [`ExchangeId`] & an [`Instrument`].
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct Market<InstrumentId = Instrument> {
-    pub exchange: Exchange,
+    pub exchange: ExchangeId,
     #[serde(flatten)]
     pub instrument: InstrumentId,
 }
 
 impl<E, I, InstrumentId> From<(E, I)> for Market<InstrumentId>
 where
-    E: Into<Exchange>,
+    E: Into<ExchangeId>,
     I: Into<InstrumentId>,
 {
     fn from((exchange, instrument): (E, I)) -> Self {
@@ -30,7 +57,7 @@ where
 
 impl<E, S> From<(E, S, S, InstrumentKind)> for Market<Instrument>
 where
-    E: Into<Exchange>,
+    E: Into<ExchangeId>,
     S: Into<Symbol>,
 {
     fn from((exchange, base, quote, instrument_kind): (E, S, S, InstrumentKind)) -> Self {
@@ -39,10 +66,10 @@ where
 }
 
 impl<InstrumentId> Market<InstrumentId> {
-    /// Constructs a new [`Market`] using the provided [`Exchange`] & [`Instrument`].
+    /// Constructs a new [`Market`] using the provided [`ExchangeId`] & [`Instrument`].
     pub fn new<E, I>(exchange: E, instrument: I) -> Self
     where
-        E: Into<Exchange>,
+        E: Into<ExchangeId>,
         I: Into<InstrumentId>,
     {
         Self {
@@ -53,7 +80,7 @@ impl<InstrumentId> Market<InstrumentId> {
 }
 
 /// Barter new type representing a unique `String` identifier for a [`Market`], where a [`Market`]
-/// represents an [`Instrument`] being traded on an [`Exchange`].
+/// represents an [`Instrument`] being traded on an [`ExchangeId`].
 ///
 /// eg/ binance_(btc_spot, future_perpetual)
 /// eg/ ftx_btc_usdt_future_perpetual
@@ -84,44 +111,230 @@ impl<InstrumentId: Display> From<&Market<InstrumentId>> for MarketId {
     }
 }
 
+/// Validates & normalises a [`MarketId`] by parsing it as a [`Market`].
+impl FromStr for MarketId {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Market::<Instrument>::from_str(input).map(|market| MarketId::from(&market))
+    }
+}
+
 impl MarketId {
     /// Construct a unique `String` [`MarketId`] identifier for a [`Market`], where a [`Market`]
-    /// represents an [`Instrument`] being traded on an [`Exchange`].
-    pub fn new(exchange: &Exchange, instrument: &Instrument) -> Self {
-        Self(
-            format!(
-                "{}_{}_{}_{}",
-                exchange, instrument.base, instrument.quote, instrument.kind
-            )
-            .to_lowercase(),
-        )
+    /// represents an [`Instrument`] being traded on an [`ExchangeId`].
+    pub fn new(exchange: &ExchangeId, instrument: &Instrument) -> Self {
+        Self(format!("{exchange}_{instrument}").to_lowercase())
+    }
+
+    /// Parse the [`Market`] this [`MarketId`] identifies.
+    pub fn market(&self) -> Result<Market<Instrument>, ParseError> {
+        Market::from_str(&self.0)
     }
 }
 
-/// Barter representation of an [`Exchange`]'s name.
+/// Parses the [`MarketId`] representation of a [`Market`], ie/ `exchange_instrument`.
 ///
-/// eg/ Exchange("binance_spot"), Exchange("bitfinex"), Exchange("gateio_spot"), etc.
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
-pub struct Exchange(Cow<'static, str>);
+/// Since [`ExchangeId`] names may contain `_` (eg/ "binance_spot"), the [`ExchangeId`] is the
+/// shortest `_` separated prefix followed by a valid [`Instrument`].
+impl FromStr for Market<Instrument> {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut first_error = None;
+
+        for (index, _) in input.match_indices('_') {
+            let (exchange, instrument) = (&input[..index], &input[index + 1..]);
+            if exchange.is_empty() {
+                continue;
+            }
+
+            match Instrument::from_str(instrument) {
+                Ok(instrument) => return Ok(Market::new(exchange.to_lowercase(), instrument)),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        let reason = first_error.map_or_else(|| "missing instrument".to_string(), |e| e.reason);
+        Err(ParseError::new("Market", input, reason))
+    }
+}
+
+/// Failed to parse an identifier from its `String` representation.
+#[derive(Clone, Eq, PartialEq, Debug, thiserror::Error)]
+#[error("failed to parse {entity} from '{input}': {reason}")]
+pub struct ParseError {
+    pub entity: &'static str,
+    pub input: String,
+    pub reason: String,
+}
 
-impl<E> From<E> for Exchange
+impl ParseError {
+    /// Construct a new [`ParseError`] for the provided entity & input.
+    pub fn new<Input, Reason>(entity: &'static str, input: Input, reason: Reason) -> Self
+    where
+        Input: Into<String>,
+        Reason: Into<String>,
+    {
+        Self {
+            entity,
+            input: input.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+/// Barter representation of an exchange's name, retained as an alias of [`ExchangeId`] for
+/// compatibility.
+///
+/// eg/ ExchangeId::from("binance_spot") == ExchangeId::BinanceSpot
+pub type Exchange = ExchangeId;
+
+/// Unique identifier of a major venue, with an [`Other`](ExchangeId::Other) fallback for venues
+/// without a dedicated variant.
+///
+/// Round-trips via [`FromStr`], [`Display`] & serde using [`as_str`](ExchangeId::as_str), with
+/// `Other` retaining the original case of the input. Prefer constructing via [`FromStr`],
+/// `From<&str>` or serde, which never represent known venues by `Other`. Equality, ordering &
+/// hashing are by case-insensitive [`as_str`](ExchangeId::as_str), such that a directly
+/// constructed `Other("OKX")` is equal to [`ExchangeId::Okx`].
+///
+/// eg/ `"binance_spot".parse::<ExchangeId>()` == `Ok(ExchangeId::BinanceSpot)`
+#[derive(Clone, Debug)]
+pub enum ExchangeId {
+    BinanceSpot,
+    BinanceFuturesUsd,
+    Bitfinex,
+    Bitmex,
+    Bybit,
+    Coinbase,
+    Deribit,
+    GateioSpot,
+    Kraken,
+    Kucoin,
+    Okx,
+    Other(Cow<'static, str>),
+}
+
+impl ExchangeId {
+    /// Every [`ExchangeId`] with a dedicated variant.
+    pub const ALL: [Self; 11] = [
+        Self::BinanceSpot,
+        Self::BinanceFuturesUsd,
+        Self::Bitfinex,
+        Self::Bitmex,
+        Self::Bybit,
+        Self::Coinbase,
+        Self::Deribit,
+        Self::GateioSpot,
+        Self::Kraken,
+        Self::Kucoin,
+        Self::Okx,
+    ];
+
+    /// `str` representation of the [`ExchangeId`]. Lowercase for venues with a dedicated variant,
+    /// and the original input for [`Other`](ExchangeId::Other).
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::BinanceSpot => "binance_spot",
+            Self::BinanceFuturesUsd => "binance_futures_usd",
+            Self::Bitfinex => "bitfinex",
+            Self::Bitmex => "bitmex",
+            Self::Bybit => "bybit",
+            Self::Coinbase => "coinbase",
+            Self::Deribit => "deribit",
+            Self::GateioSpot => "gateio_spot",
+            Self::Kraken => "kraken",
+            Self::Kucoin => "kucoin",
+            Self::Okx => "okx",
+            Self::Other(exchange) => exchange,
+        }
+    }
+}
+
+impl FromStr for ExchangeId {
+    type Err = Infallible;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Ok(Self::ALL
+            .into_iter()
+            .find(|exchange| exchange.as_str().eq_ignore_ascii_case(input))
+            .unwrap_or_else(|| Self::Other(Cow::Owned(input.to_owned()))))
+    }
+}
+
+impl<E> From<E> for ExchangeId
 where
     E: Into<Cow<'static, str>>,
 {
     fn from(exchange: E) -> Self {
-        Exchange(exchange.into())
+        let exchange = exchange.into();
+        let Ok(exchange) = ExchangeId::from_str(&exchange);
+        exchange
     }
 }
 
-impl Debug for Exchange {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+impl PartialEq for ExchangeId {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str().eq_ignore_ascii_case(other.as_str())
+    }
+}
+
+impl Eq for ExchangeId {}
+
+impl PartialOrd for ExchangeId {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ExchangeId {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let lowercase = |exchange: &Self| {
+            exchange
+                .as_str()
+                .bytes()
+                .map(|byte| byte.to_ascii_lowercase())
+                .collect::<Vec<_>>()
+        };
+        lowercase(self).cmp(&lowercase(other))
+    }
+}
+
+impl std::hash::Hash for ExchangeId {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str()
+            .bytes()
+            .for_each(|byte| state.write_u8(byte.to_ascii_lowercase()));
+        state.write_u8(0xff);
     }
 }
 
-impl Display for Exchange {
+impl Display for ExchangeId {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for ExchangeId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ExchangeId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let input = <Cow<'de, str>>::deserialize(deserializer)?;
+        let Ok(exchange) = ExchangeId::from_str(&input);
+        Ok(exchange)
     }
 }
 
@@ -166,6 +379,50 @@ where
     }
 }
 
+impl SubscriptionId {
+    /// Construct a `{channel}@{market}` [`SubscriptionId`].
+    ///
+    /// eg/ `SubscriptionId::channel_at_market("trade", "btcusdt")` => "trade@btcusdt"
+    pub fn channel_at_market<Channel, MarketName>(channel: Channel, market: MarketName) -> Self
+    where
+        Channel: Display,
+        MarketName: Display,
+    {
+        Self(format!("{channel}@{market}"))
+    }
+
+    /// Construct a `{channel}|{market}` [`SubscriptionId`].
+    ///
+    /// eg/ `SubscriptionId::channel_pipe_market("trades", "XBT/USD")` => "trades|XBT/USD"
+    pub fn channel_pipe_market<Channel, MarketName>(channel: Channel, market: MarketName) -> Self
+    where
+        Channel: Display,
+        MarketName: Display,
+    {
+        Self(format!("{channel}|{market}"))
+    }
+
+    /// Construct a `{market}:{channel}` [`SubscriptionId`].
+    ///
+    /// eg/ `SubscriptionId::market_colon_channel("ticker", "BTC-USD")` => "BTC-USD:ticker"
+    pub fn market_colon_channel<Channel, MarketName>(channel: Channel, market: MarketName) -> Self
+    where
+        Channel: Display,
+        MarketName: Display,
+    {
+        Self(format!("{market}:{channel}"))
+    }
+}
+
+/// Identifies the [`SubscriptionId`] an exchange message belongs to, such that a
+/// [`Transformer`](crate::Transformer) can map it to the originating subscription.
+///
+/// eg/ A `BinanceTrade` message is identified by `SubscriptionId::channel_at_market` of its
+/// lowercase symbol, mirroring the stream name it was subscribed with.
+pub trait SubscriptionIdentifier {
+    fn subscription_id(&self) -> SubscriptionId;
+}
+
 /// [`Side`] of a trade or position - Buy or Sell.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub enum Side {
@@ -175,6 +432,65 @@ pub enum Side {
     Sell,
 }
 
+impl Side {
+    /// Construct the aggressor (taker) [`Side`] of a public trade from the common exchange
+    /// `buyer_is_maker` flag (eg/ Binance `m`), since a buyer that is the maker means the trade
+    /// was initiated by a seller.
+    pub fn from_buyer_is_maker(buyer_is_maker: bool) -> Self {
+        if buyer_is_maker {
+            Self::Sell
+        } else {
+            Self::Buy
+        }
+    }
+
+    /// Opposite [`Side`], eg/ the side of the resting order an aggressor traded against.
+    pub fn invert(self) -> Self {
+        match self {
+            Self::Buy => Self::Sell,
+            Self::Sell => Self::Buy,
+        }
+    }
+
+    /// Determine if the [`Side`] is [`Side::Buy`].
+    pub fn is_buy(self) -> bool {
+        matches!(self, Self::Buy)
+    }
+
+    /// Determine if the [`Side`] is [`Side::Sell`].
+    pub fn is_sell(self) -> bool {
+        matches!(self, Self::Sell)
+    }
+}
+
+/// Signed direction of the [`Side`], `+1` for [`Side::Buy`] & `-1` for [`Side::Sell`], for
+/// signing quantities & position deltas.
+impl From<Side> for i8 {
+    fn from(side: Side) -> Self {
+        match side {
+            Side::Buy => 1,
+            Side::Sell => -1,
+        }
+    }
+}
+
+/// Parses `+1` as [`Side::Buy`] & `-1` as [`Side::Sell`].
+impl TryFrom<i8> for Side {
+    type Error = ParseError;
+
+    fn try_from(sign: i8) -> Result<Self, Self::Error> {
+        match sign {
+            1 => Ok(Side::Buy),
+            -1 => Ok(Side::Sell),
+            other => Err(ParseError::new(
+                "Side",
+                other.to_string(),
+                "expected +1 or -1",
+            )),
+        }
+    }
+}
+
 impl Display for Side {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -188,6 +504,174 @@ impl Display for Side {
     }
 }
 
+/// [`Liquidity`] role of a trade or execution - Maker (resting order) or Taker (aggressor).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub enum Liquidity {
+    #[serde(alias = "maker", alias = "MAKER", alias = "m", alias = "M")]
+    Maker,
+    #[serde(alias = "taker", alias = "TAKER", alias = "t", alias = "T")]
+    Taker,
+}
+
+impl Liquidity {
+    /// Construct a [`Liquidity`] from the common exchange `is_maker` flag.
+    pub fn from_is_maker(is_maker: bool) -> Self {
+        if is_maker {
+            Self::Maker
+        } else {
+            Self::Taker
+        }
+    }
+}
+
+impl Display for Liquidity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Liquidity::Maker => "maker",
+                Liquidity::Taker => "taker",
+            }
+        )
+    }
+}
+
+/// [`Fee`] charged by the venue for a trade or execution.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Fee {
+    pub amount: f64,
+    /// Asset the fee was charged in, if provided by the venue.
+    pub asset: Option<Symbol>,
+    /// Venue fee tier the fee was charged at, if provided by the venue. eg/ "VIP1"
+    pub tier: Option<String>,
+}
+
+/// Normalised trade or execution output enriched with the optional [`Liquidity`] role & [`Fee`]
+/// populated when the venue provides them, as required by downstream PnL attribution.
+///
+/// eg/ `Enriched<PublicTrade>`
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Enriched<T> {
+    #[serde(flatten)]
+    pub event: T,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liquidity: Option<Liquidity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee: Option<Fee>,
+}
+
+impl<T> From<T> for Enriched<T> {
+    fn from(event: T) -> Self {
+        Self {
+            event,
+            liquidity: None,
+            fee: None,
+        }
+    }
+}
+
+impl<T> Enriched<T> {
+    /// Set the [`Liquidity`] role.
+    pub fn with_liquidity(self, liquidity: Liquidity) -> Self {
+        Self {
+            liquidity: Some(liquidity),
+            ..self
+        }
+    }
+
+    /// Set the [`Fee`].
+    pub fn with_fee(self, fee: Fee) -> Self {
+        Self {
+            fee: Some(fee),
+            ..self
+        }
+    }
+
+    /// Map the enriched event using the provided function, keeping the enrichment.
+    pub fn map<F, U>(self, f: F) -> Enriched<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Enriched {
+            event: f(self.event),
+            liquidity: self.liquidity,
+            fee: self.fee,
+        }
+    }
+}
+
+/// Generic pairing of a value with its key (eg/ [`SubscriptionId`], [`Instrument`], or
+/// [`ExchangeId`]).
+///
+/// Dereferences to the value. eg/ `Keyed<SubscriptionId, PublicTrade>`
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct Keyed<Key, Value> {
+    pub key: Key,
+    pub value: Value,
+}
+
+impl<Key, Value> Deref for Keyed<Key, Value> {
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<Key, Value> DerefMut for Keyed<Key, Value> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<Key, Value> From<(Key, Value)> for Keyed<Key, Value> {
+    fn from((key, value): (Key, Value)) -> Self {
+        Self::new(key, value)
+    }
+}
+
+impl<Key, Value> Keyed<Key, Value> {
+    /// Construct a new [`Keyed`] pairing the provided key & value.
+    pub fn new(key: Key, value: Value) -> Self {
+        Self { key, value }
+    }
+
+    /// Map the value using the provided function, keeping the key.
+    pub fn map<F, U>(self, f: F) -> Keyed<Key, U>
+    where
+        F: FnOnce(Value) -> U,
+    {
+        Keyed {
+            key: self.key,
+            value: f(self.value),
+        }
+    }
+
+    /// Replace the key with the provided key, keeping the value.
+    pub fn with_key<K>(self, key: K) -> Keyed<K, Value> {
+        Keyed {
+            key,
+            value: self.value,
+        }
+    }
+
+    /// Borrow the key & value.
+    pub fn as_ref(&self) -> Keyed<&Key, &Value> {
+        Keyed {
+            key: &self.key,
+            value: &self.value,
+        }
+    }
+
+    /// Split into the key & value.
+    pub fn into_parts(self) -> (Key, Value) {
+        (self.key, self.value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,7 +690,7 @@ mod tests {
                 // TC0: Valid Binance btc_usd Spot Market
                 input: r##"{ "exchange": "binance", "base": "btc", "quote": "usd", "instrument_kind": "spot" }"##,
                 expected: Ok(Market {
-                    exchange: Exchange::from("binance"),
+                    exchange: ExchangeId::from("binance"),
                     instrument: Instrument::from(("btc", "usd", InstrumentKind::Spot)),
                 }),
             },
@@ -214,7 +698,7 @@ mod tests {
                 // TC1: Valid Ftx btc_usd FuturePerpetual Market
                 input: r##"{ "exchange": "ftx_old", "base": "btc", "quote": "usd", "instrument_kind": "perpetual" }"##,
                 expected: Ok(Market {
-                    exchange: Exchange::from("ftx_old"),
+                    exchange: ExchangeId::from("ftx_old"),
                     instrument: Instrument::from(("btc", "usd", InstrumentKind::Perpetual)),
                 }),
             },
@@ -242,4 +726,127 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_exchange_id_round_trip() {
+        for exchange in ExchangeId::ALL {
+            assert_eq!(exchange.as_str().parse::<ExchangeId>().unwrap(), exchange);
+            assert_eq!(
+                serde_json::from_str::<ExchangeId>(&serde_json::to_string(&exchange).unwrap())
+                    .unwrap(),
+                exchange
+            );
+        }
+
+        assert_eq!(
+            "BINANCE_SPOT".parse::<ExchangeId>().unwrap(),
+            ExchangeId::BinanceSpot
+        );
+        assert_eq!(ExchangeId::from("Bitfinex"), ExchangeId::Bitfinex);
+
+        // Unknown venues retain their original case, but compare case-insensitively
+        let other = "HyperLiquid".parse::<ExchangeId>().unwrap();
+        assert_eq!(other, ExchangeId::Other(Cow::Borrowed("hyperliquid")));
+        assert_eq!(other.to_string(), "HyperLiquid");
+        assert_eq!(
+            other.to_string().parse::<ExchangeId>().unwrap().as_str(),
+            "HyperLiquid"
+        );
+
+        let json = serde_json::to_string(&other).unwrap();
+        assert_eq!(json, r#""HyperLiquid""#);
+        let deserialised = serde_json::from_str::<ExchangeId>(&json).unwrap();
+        assert_eq!(deserialised.as_str(), "HyperLiquid");
+
+        // Directly constructed Other variants of known venues are equivalent to the venue
+        let other = ExchangeId::Other(Cow::Borrowed("OKX"));
+        assert_eq!(other, ExchangeId::Okx);
+        assert_eq!(other.cmp(&ExchangeId::Okx), std::cmp::Ordering::Equal);
+        assert!(std::collections::HashSet::from([ExchangeId::Okx]).contains(&other));
+    }
+
+    #[test]
+    fn test_side() {
+        assert_eq!(Side::from_buyer_is_maker(true), Side::Sell);
+        assert_eq!(Side::from_buyer_is_maker(false), Side::Buy);
+        assert_eq!(Side::Buy.invert(), Side::Sell);
+        assert!(Side::Buy.is_buy() && Side::Sell.is_sell());
+
+        for side in [Side::Buy, Side::Sell] {
+            assert_eq!(Side::try_from(i8::from(side)).unwrap(), side);
+        }
+        assert_eq!(i8::from(Side::Sell), -1);
+        assert!(Side::try_from(0).is_err());
+    }
+
+    #[test]
+    fn test_subscription_identifier() {
+        struct BinanceTrade {
+            symbol: &'static str,
+        }
+
+        impl SubscriptionIdentifier for BinanceTrade {
+            fn subscription_id(&self) -> SubscriptionId {
+                SubscriptionId::channel_at_market("trade", self.symbol.to_lowercase())
+            }
+        }
+
+        assert_eq!(
+            BinanceTrade { symbol: "BTCUSDT" }.subscription_id(),
+            SubscriptionId::from("trade@btcusdt")
+        );
+        assert_eq!(
+            SubscriptionId::channel_pipe_market("trades", "XBT/USD"),
+            SubscriptionId::from("trades|XBT/USD")
+        );
+        assert_eq!(
+            SubscriptionId::market_colon_channel("ticker", "BTC-USD"),
+            SubscriptionId::from("BTC-USD:ticker")
+        );
+    }
+
+    #[test]
+    fn test_keyed() {
+        let keyed = Keyed::new(SubscriptionId::from("btcusdt@trade"), 1.0_f64)
+            .map(|price| price * 2.0)
+            .with_key(ExchangeId::from("binance_spot"));
+
+        assert_eq!(*keyed, 2.0);
+        assert_eq!(keyed.key, ExchangeId::from("binance_spot"));
+        assert_eq!(
+            serde_json::to_string(&keyed).unwrap(),
+            r#"{"key":"binance_spot","value":2.0}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Keyed<ExchangeId, f64>>(r#"{"key":"binance_spot","value":2.0}"#)
+                .unwrap(),
+            keyed
+        );
+    }
+
+    #[test]
+    fn test_de_enriched() {
+        #[derive(Debug, PartialEq, Deserialize, Serialize)]
+        struct Trade {
+            price: f64,
+        }
+
+        let actual = serde_json::from_str::<Enriched<Trade>>(
+            r#"{"price":1.0,"liquidity":"M","fee":{"amount":0.1,"asset":"usdt","tier":null}}"#,
+        )
+        .unwrap();
+        let expected = Enriched::from(Trade { price: 1.0 })
+            .with_liquidity(Liquidity::from_is_maker(true))
+            .with_fee(Fee {
+                amount: 0.1,
+                asset: Some(Symbol::from("usdt")),
+                tier: None,
+            });
+        assert_eq!(actual, expected);
+
+        // Enrichment is optional
+        let actual = serde_json::from_str::<Enriched<Trade>>(r#"{"price":2.0}"#).unwrap();
+        assert_eq!(actual, Enriched::from(Trade { price: 2.0 }));
+        assert_eq!(serde_json::to_string(&actual).unwrap(), r#"{"price":2.0}"#);
+    }
 }
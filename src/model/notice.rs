@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Classification of a venue [`Notice`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoticeKind {
+    /// Instrument or asset delisting.
+    Delisting,
+    /// Scheduled or ongoing venue maintenance.
+    Maintenance,
+    /// Any other announcement or banner.
+    Announcement,
+}
+
+impl NoticeKind {
+    /// Classify a notice message using common delisting & maintenance keywords, defaulting to
+    /// [`NoticeKind::Announcement`].
+    pub fn classify(message: &str) -> Self {
+        let message = message.to_lowercase();
+        if ["delist", "removal of", "will be removed"]
+            .iter()
+            .any(|pattern| message.contains(pattern))
+        {
+            NoticeKind::Delisting
+        } else if ["maintenance", "upgrade", "downtime", "suspend"]
+            .iter()
+            .any(|pattern| message.contains(pattern))
+        {
+            NoticeKind::Maintenance
+        } else {
+            NoticeKind::Announcement
+        }
+    }
+}
+
+impl Display for NoticeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                NoticeKind::Delisting => "delisting",
+                NoticeKind::Maintenance => "maintenance",
+                NoticeKind::Announcement => "announcement",
+            }
+        )
+    }
+}
+
+/// Venue notice or announcement (eg/ delisting, maintenance banner) sent over the socket,
+/// surfaced to operators as early warning of disruptive changes.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Notice {
+    pub kind: NoticeKind,
+    pub message: String,
+    /// Time the notice was issued by the venue, if provided.
+    pub time: Option<DateTime<Utc>>,
+}
+
+impl Notice {
+    /// Construct a new [`Notice`], classifying its [`NoticeKind`] from the message.
+    pub fn new<S>(message: S) -> Self
+    where
+        S: Into<String>,
+    {
+        let message = message.into();
+        Self {
+            kind: NoticeKind::classify(&message),
+            message,
+            time: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notice_kind_classify() {
+        struct TestCase {
+            input: &'static str,
+            expected: NoticeKind,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: delisting
+                input: "BTC-PERP will be delisted at 08:00 UTC",
+                expected: NoticeKind::Delisting,
+            },
+            TestCase {
+                // TC1: maintenance
+                input: "Scheduled system Maintenance in 30 minutes",
+                expected: NoticeKind::Maintenance,
+            },
+            TestCase {
+                // TC2: generic announcement
+                input: "New listing: SOL/USDT",
+                expected: NoticeKind::Announcement,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            assert_eq!(
+                NoticeKind::classify(test.input),
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+}
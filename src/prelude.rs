@@ -0,0 +1 @@
+pub use crate::v1::*;
@@ -0,0 +1,153 @@
+use crate::{error::SocketError, protocol::http::HttpParser};
+use reqwest::StatusCode;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Common `{"code": -1121, "msg": "Invalid symbol."}` API error envelope.
+///
+/// Accepts string codes (eg/ `"51000"`) & a `message` field in place of `msg`.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CodeMsg {
+    #[serde(deserialize_with = "de_code")]
+    pub code: i64,
+    #[serde(alias = "message")]
+    pub msg: String,
+}
+
+/// [`HttpParser::OutputError`] of a [`CodeMsgParser`].
+#[derive(Debug, Error)]
+pub enum CodeMsgError<ExchangeError> {
+    /// API error with a code mapped to an `ExchangeError`.
+    #[error("HTTP response (status={status}) error {code}: {msg}")]
+    Exchange {
+        status: StatusCode,
+        code: i64,
+        msg: String,
+        error: ExchangeError,
+    },
+
+    /// API error with an unmapped code, or any other [`SocketError`].
+    #[error(transparent)]
+    Socket(#[from] SocketError),
+}
+
+/// Generic [`HttpParser`] for APIs that return [`CodeMsg`] error envelopes, mapping error codes
+/// to an integration specific `ExchangeError` via a user supplied table.
+///
+/// API errors with unmapped codes are surfaced as a [`SocketError::HttpResponse`].
+///
+/// eg/ `CodeMsgParser::new([(-1121, BinanceError::InvalidSymbol), (-2010, BinanceError::NewOrder)])`
+#[derive(Debug, Clone)]
+pub struct CodeMsgParser<ExchangeError> {
+    pub errors: HashMap<i64, ExchangeError>,
+}
+
+impl<ExchangeError> Default for CodeMsgParser<ExchangeError> {
+    fn default() -> Self {
+        Self {
+            errors: HashMap::new(),
+        }
+    }
+}
+
+impl<ExchangeError> CodeMsgParser<ExchangeError> {
+    /// Construct a new [`CodeMsgParser`] using the provided code to `ExchangeError` table.
+    pub fn new<Errors>(errors: Errors) -> Self
+    where
+        Errors: IntoIterator<Item = (i64, ExchangeError)>,
+    {
+        Self {
+            errors: errors.into_iter().collect(),
+        }
+    }
+
+    /// Map the provided API error code to the provided `ExchangeError`.
+    pub fn with_code(mut self, code: i64, error: ExchangeError) -> Self {
+        self.errors.insert(code, error);
+        self
+    }
+}
+
+impl<ExchangeError> HttpParser for CodeMsgParser<ExchangeError>
+where
+    ExchangeError: Clone,
+{
+    type ApiError = CodeMsg;
+    type OutputError = CodeMsgError<ExchangeError>;
+
+    fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+        match self.errors.get(&error.code) {
+            Some(exchange_error) => CodeMsgError::Exchange {
+                status,
+                code: error.code,
+                msg: error.msg,
+                error: exchange_error.clone(),
+            },
+            None => CodeMsgError::Socket(SocketError::HttpResponse(
+                status,
+                format!("{}: {}", error.code, error.msg),
+            )),
+        }
+    }
+}
+
+/// Deserialise an API error code from either a number or a numeric string.
+fn de_code<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Code {
+        Number(i64),
+        String(String),
+    }
+
+    match Code::deserialize(deserializer)? {
+        Code::Number(code) => Ok(code),
+        Code::String(code) => code.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestError {
+        InvalidSymbol,
+    }
+
+    #[test]
+    fn test_code_msg_parser() {
+        let parser = CodeMsgParser::new([(-1121, TestError::InvalidSymbol)]);
+
+        let error = parser
+            .parse::<Vec<u64>>(
+                StatusCode::BAD_REQUEST,
+                br#"{"code":-1121,"msg":"Invalid symbol."}"#,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            CodeMsgError::Exchange {
+                code: -1121,
+                error: TestError::InvalidSymbol,
+                ..
+            }
+        ));
+
+        let error = parser
+            .parse::<Vec<u64>>(
+                StatusCode::BAD_REQUEST,
+                br#"{"code":"51000","message":"Parameter error"}"#,
+            )
+            .unwrap_err();
+        assert!(matches!(
+            error,
+            CodeMsgError::Socket(SocketError::HttpResponse(StatusCode::BAD_REQUEST, ref msg))
+                if msg == "51000: Parameter error"
+        ));
+    }
+}
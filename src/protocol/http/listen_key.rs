@@ -0,0 +1,269 @@
+use crate::{
+    error::SocketError,
+    protocol::http::{
+        rest::{client::RestClient, retry::RetryPolicy, RestRequest},
+        BuildStrategy, HttpParser,
+    },
+};
+use futures::future::AbortHandle;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, sync::Arc, time::Duration};
+use tokio::sync::{mpsc, watch};
+use tracing::{debug, warn};
+
+/// Default interval at which a listen key is kept alive, well within Binance's 60 minute expiry.
+pub const DEFAULT_LISTEN_KEY_KEEP_ALIVE: Duration = Duration::from_secs(30 * 60);
+
+/// Maximum backoff between attempts to re-create an expired listen key.
+pub const MAX_LISTEN_KEY_RECREATE_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Response of an exchange's create listen key endpoint.
+pub trait ListenKey {
+    fn listen_key(self) -> String;
+}
+
+/// Binance style create listen key response. eg/ `{"listenKey": "pqia91ma19a5s61cv6a81va65sd"}`
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListenKeyResponse {
+    pub listen_key: String,
+}
+
+impl ListenKey for ListenKeyResponse {
+    fn listen_key(self) -> String {
+        self.listen_key
+    }
+}
+
+/// Manages the listen key of a user data stream, which must be created over REST & periodically
+/// kept alive (eg/ Binance `PUT /api/v3/userDataStream` every 30 minutes).
+///
+/// Owns the keep-alive task (spawned & timed using the [`RestClient::runtime`]), which is
+/// aborted when the [`ListenKeyManager`] is dropped. If a keep-alive fails the listen key is
+/// assumed expired, so a new listen key is created & published, and a
+/// [`SocketError::ListenKeyExpired`] is surfaced such that the user data stream can be
+/// re-subscribed. Failed re-creates are retried with exponential backoff, bounded by the
+/// keep-alive interval & [`MAX_LISTEN_KEY_RECREATE_BACKOFF`].
+#[derive(Debug)]
+pub struct ListenKeyManager {
+    key: watch::Receiver<String>,
+    errors: mpsc::UnboundedReceiver<SocketError>,
+    task: AbortHandle,
+}
+
+impl ListenKeyManager {
+    /// Create a listen key using the provided [`RestClient`] & create request generator, then
+    /// spawn a task that keeps it alive at the provided interval.
+    pub async fn start<Strategy, Parser, Create, FnCreate, KeepAlive, FnKeepAlive>(
+        client: Arc<RestClient<Strategy, Parser>>,
+        create: FnCreate,
+        keep_alive: FnKeepAlive,
+        interval: Duration,
+    ) -> Result<Self, Parser::OutputError>
+    where
+        Strategy: BuildStrategy + Send + Sync + 'static,
+        Parser: HttpParser + Send + Sync + 'static,
+        Parser::OutputError: Debug + Send,
+        Create: RestRequest + Send,
+        Create::Response: ListenKey,
+        FnCreate: Fn() -> Create + Send + 'static,
+        KeepAlive: RestRequest + Send,
+        FnKeepAlive: Fn(&str) -> KeepAlive + Send + 'static,
+    {
+        let (response, _) = client.execute(create()).await?;
+        let (key_tx, key_rx) = watch::channel(response.listen_key());
+        let (error_tx, error_rx) = mpsc::unbounded_channel();
+
+        let max_backoff = interval.min(MAX_LISTEN_KEY_RECREATE_BACKOFF);
+        let recreate = RetryPolicy {
+            initial_backoff: Duration::from_secs(1).min(max_backoff),
+            max_backoff,
+            ..RetryPolicy::default()
+        };
+
+        let runtime = Arc::clone(&client.runtime);
+        let task = runtime.spawn_abortable(Box::pin(async move {
+            loop {
+                client.runtime.sleep(interval).await;
+
+                let current = key_tx.borrow().clone();
+                let Err(error) = client.execute(keep_alive(&current)).await else {
+                    debug!("ListenKeyManager kept listen key alive");
+                    continue;
+                };
+
+                warn!(?error, "ListenKeyManager failed to keep listen key alive");
+                let _ = error_tx.send(SocketError::ListenKeyExpired(format!("{error:?}")));
+
+                // Listen key is assumed expired, so create a new one, retrying with backoff
+                let mut retry = 0;
+                loop {
+                    match client.execute(create()).await {
+                        Ok((response, _)) => {
+                            key_tx.send_replace(response.listen_key());
+                            break;
+                        }
+                        Err(error) => {
+                            warn!(
+                                ?error,
+                                retry, "ListenKeyManager failed to create a new listen key"
+                            );
+                            if retry == 0 {
+                                let _ = error_tx
+                                    .send(SocketError::ListenKeyExpired(format!("{error:?}")));
+                            }
+                        }
+                    }
+
+                    retry += 1;
+                    client.runtime.sleep(recreate.backoff(retry)).await;
+                }
+            }
+        }));
+
+        Ok(Self {
+            key: key_rx,
+            errors: error_rx,
+            task,
+        })
+    }
+
+    /// Current listen key.
+    pub fn key(&self) -> String {
+        self.key.borrow().clone()
+    }
+
+    /// Subscribe to listen key changes, notified when an expired listen key is replaced.
+    pub fn subscribe(&self) -> watch::Receiver<String> {
+        self.key.clone()
+    }
+
+    /// Wait for the next [`SocketError::ListenKeyExpired`] surfaced by the keep-alive task.
+    pub async fn next_error(&mut self) -> Option<SocketError> {
+        self.errors.recv().await
+    }
+}
+
+impl Drop for ListenKeyManager {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        protocol::http::public::PublicNoHeaders,
+        test_util::{http_response, MockServer, TestParser},
+    };
+    use std::borrow::Cow;
+
+    struct CreateListenKey;
+
+    impl RestRequest for CreateListenKey {
+        type Response = ListenKeyResponse;
+        type QueryParams = ();
+        type Body = ();
+
+        fn path(&self) -> Cow<'static, str> {
+            Cow::Borrowed("/userDataStream")
+        }
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::POST
+        }
+    }
+
+    struct KeepAliveListenKey(String);
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct KeepAliveResponse {}
+
+    impl RestRequest for KeepAliveListenKey {
+        type Response = KeepAliveResponse;
+        type QueryParams = ();
+        type Body = ();
+
+        fn path(&self) -> Cow<'static, str> {
+            Cow::Owned(format!("/userDataStream?listenKey={}", self.0))
+        }
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::PUT
+        }
+    }
+
+    #[tokio::test]
+    async fn test_listen_key_manager_replaces_expired_key() {
+        let server = MockServer::responses([
+            http_response("200 OK", &[], r#"{"listenKey":"first"}"#),
+            http_response("400 Bad Request", &[], r#"{"code":-1125}"#),
+            http_response("200 OK", &[], r#"{"listenKey":"second"}"#),
+        ]);
+
+        let client = Arc::new(RestClient::new(
+            server.base_url.clone(),
+            PublicNoHeaders,
+            TestParser,
+        ));
+        let mut manager = ListenKeyManager::start(
+            client,
+            || CreateListenKey,
+            |key| KeepAliveListenKey(key.to_string()),
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+        assert_eq!(manager.key(), "first");
+
+        let mut keys = manager.subscribe();
+        assert!(matches!(
+            manager.next_error().await,
+            Some(SocketError::ListenKeyExpired(_))
+        ));
+        keys.changed().await.unwrap();
+        assert_eq!(manager.key(), "second");
+    }
+
+    #[tokio::test]
+    async fn test_listen_key_manager_retries_failed_create() {
+        let server = MockServer::responses([
+            http_response("200 OK", &[], r#"{"listenKey":"first"}"#),
+            http_response("400 Bad Request", &[], r#"{"code":-1125}"#),
+            http_response("500 Internal Server Error", &[], r#"{"code":-1000}"#),
+            http_response("500 Internal Server Error", &[], r#"{"code":-1000}"#),
+            http_response("200 OK", &[], r#"{"listenKey":"second"}"#),
+        ]);
+
+        let client = Arc::new(RestClient::new(
+            server.base_url.clone(),
+            PublicNoHeaders,
+            TestParser,
+        ));
+        let mut manager = ListenKeyManager::start(
+            client,
+            || CreateListenKey,
+            |key| KeepAliveListenKey(key.to_string()),
+            Duration::from_millis(10),
+        )
+        .await
+        .unwrap();
+
+        // Keep-alive & first failed create are surfaced, then create is retried until success
+        let mut keys = manager.subscribe();
+        keys.changed().await.unwrap();
+        assert_eq!(manager.key(), "second");
+        for _ in 0..2 {
+            assert!(matches!(
+                manager.next_error().await,
+                Some(SocketError::ListenKeyExpired(_))
+            ));
+        }
+
+        for path in ["POST", "PUT", "POST", "POST", "POST"] {
+            assert!(server.next_request().starts_with(path));
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use self::rest::RestRequest;
 use crate::error::SocketError;
 use reqwest::StatusCode;
-use serde::de::DeserializeOwned;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tracing::error;
 
 /// Defines an abstract [`RestRequest`] that can be executed by a fully
@@ -16,6 +16,18 @@ pub mod private;
 /// [`RestRequest`] with no headers.
 pub mod public;
 
+/// Plain text & CSV deserialisation used by the [`ResponseFormat::Text`] &
+/// [`ResponseFormat::Csv`] formats.
+pub mod plain;
+
+/// Generic [`CodeMsgParser`](code_msg::CodeMsgParser) for APIs returning `{"code", "msg"}` error
+/// envelopes.
+pub mod code_msg;
+
+/// [`ListenKeyManager`](listen_key::ListenKeyManager) that creates & keeps alive the listen key of
+/// a user data stream.
+pub mod listen_key;
+
 /// [`RestRequest`] build strategy for the API being interacted with.
 ///
 /// An API that requires authenticated [`RestRequest`]s will likely utilise the configurable
@@ -36,6 +48,48 @@ pub trait BuildStrategy {
     ) -> Result<reqwest::Request, SocketError>
     where
         Request: RestRequest;
+
+    /// Inject the [`RestRequest::idempotency_key`] (eg/ client order id) into the request in the
+    /// consistent place required by the API, before [`build`](Self::build) signs it.
+    ///
+    /// Defaults to not injecting the key. See [`IdempotencyPlacement`] for common placements.
+    ///
+    /// Implementations that inject the key must also override
+    /// [`injects_idempotency_key`](Self::injects_idempotency_key).
+    fn inject_idempotency_key(
+        &self,
+        builder: reqwest::RequestBuilder,
+        _key: &str,
+    ) -> reqwest::RequestBuilder {
+        builder
+    }
+
+    /// Determine if [`inject_idempotency_key`](Self::inject_idempotency_key) sends the key to
+    /// the server, such that non-idempotent requests carrying one are safe to retry.
+    ///
+    /// Defaults to `false`, matching the default of not injecting the key.
+    fn injects_idempotency_key(&self) -> bool {
+        false
+    }
+}
+
+/// Common placements of an idempotency key (eg/ client order id, request id) within a request.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum IdempotencyPlacement {
+    /// Header with the provided name. eg/ `X-Request-Id`.
+    Header(&'static str),
+    /// Query parameter with the provided name. eg/ `newClientOrderId`.
+    Query(&'static str),
+}
+
+impl IdempotencyPlacement {
+    /// Inject the provided idempotency key into the request.
+    pub fn inject(self, builder: reqwest::RequestBuilder, key: &str) -> reqwest::RequestBuilder {
+        match self {
+            Self::Header(name) => builder.header(name, key),
+            Self::Query(name) => builder.query(&[(name, key)]),
+        }
+    }
 }
 
 /// Utilised by a [`RestClient`](rest::client::RestClient) to deserialise
@@ -81,7 +135,255 @@ pub trait HttpParser {
         }))
     }
 
+    /// Attempt to parse a [`StatusCode`] & bytes payload encoded in the provided
+    /// [`ResponseFormat`] into a deserialisable `Response`.
+    ///
+    /// [`ResponseFormat::Json`] payloads are delegated to [`parse`](Self::parse), such that
+    /// existing custom parsing logic is respected.
+    fn parse_format<Response>(
+        &self,
+        status: StatusCode,
+        payload: &[u8],
+        format: ResponseFormat,
+    ) -> Result<Response, Self::OutputError>
+    where
+        Response: DeserializeOwned,
+    {
+        if format == ResponseFormat::Json {
+            return self.parse(status, payload);
+        }
+
+        // Plain text & CSV formats can deserialise most error bodies, so unsuccessful responses
+        // are first parsed as an API Error
+        if !status.is_success() {
+            if let Ok(api_error) = format.deserialise_api_error::<Self::ApiError>(payload) {
+                return Err(self.parse_api_error(status, api_error));
+            }
+        }
+
+        // Attempt to deserialise reqwest::Response bytes into Ok(Response)
+        let parse_ok_error = match format.deserialise::<Response>(payload) {
+            Ok(response) => return Ok(response),
+            Err(error) => error,
+        };
+
+        // Attempt to deserialise API Error if Ok(Response) deserialisation failed
+        let parse_api_error_error = match format.deserialise_api_error::<Self::ApiError>(payload) {
+            Ok(api_error) => return Err(self.parse_api_error(status, api_error)),
+            Err(error) => error,
+        };
+
+        // Log errors if failed to deserialise reqwest::Response into Response or API Self::Error
+        error!(
+            status_code = ?status,
+            ?format,
+            ?parse_ok_error,
+            ?parse_api_error_error,
+            response_body = %String::from_utf8_lossy(payload),
+            "error deserializing HTTP response"
+        );
+
+        Err(Self::OutputError::from(parse_ok_error))
+    }
+
     /// If [`parse`](Self::parse) fails to deserialise the `Ok(Response)`, this function parses
     /// to parse the API [`Self::ApiError`] associated with the response.
     fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError;
 }
+
+/// Encoding of a [`RestRequest::Response`] payload.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum ResponseFormat {
+    #[default]
+    Json,
+    /// Used by some legacy & institutional endpoints. Requires the `xml` feature to deserialise.
+    Xml,
+    /// Plain text deserialised into a `String`, number, bool or unit enum variant.
+    Text,
+    /// CSV with a header row, deserialised as a sequence of records. eg/ Exports.
+    Csv,
+}
+
+impl ResponseFormat {
+    /// Deserialise the provided payload bytes, encoded in this [`ResponseFormat`].
+    pub fn deserialise<T>(&self, payload: &[u8]) -> Result<T, SocketError>
+    where
+        T: DeserializeOwned,
+    {
+        match self {
+            ResponseFormat::Json => {
+                serde_json::from_slice(payload).map_err(|error| SocketError::DeserialiseBinary {
+                    error,
+                    payload: payload.to_vec(),
+                })
+            }
+            #[cfg(feature = "xml")]
+            ResponseFormat::Xml => {
+                let payload = String::from_utf8_lossy(payload);
+                quick_xml::de::from_str(&payload).map_err(|error| SocketError::DeserialiseXml {
+                    error: error.to_string(),
+                    payload: payload.into_owned(),
+                })
+            }
+            #[cfg(not(feature = "xml"))]
+            ResponseFormat::Xml => Err(SocketError::DeserialiseXml {
+                error: "the xml feature is disabled".to_string(),
+                payload: String::from_utf8_lossy(payload).into_owned(),
+            }),
+            ResponseFormat::Text => {
+                let payload = String::from_utf8_lossy(payload);
+                plain::from_text(&payload).map_err(|error| SocketError::DeserialisePlain {
+                    error,
+                    payload: payload.into_owned(),
+                })
+            }
+            ResponseFormat::Csv => {
+                let payload = String::from_utf8_lossy(payload);
+                plain::from_csv(&payload).map_err(|error| SocketError::DeserialisePlain {
+                    error,
+                    payload: payload.into_owned(),
+                })
+            }
+        }
+    }
+
+    /// Deserialise the provided API error payload bytes, encoded in this [`ResponseFormat`] or
+    /// in JSON, since many venues return JSON errors regardless of the response format.
+    pub fn deserialise_api_error<T>(&self, payload: &[u8]) -> Result<T, SocketError>
+    where
+        T: DeserializeOwned,
+    {
+        self.deserialise(payload).or_else(|error| match self {
+            ResponseFormat::Json => Err(error),
+            _ => ResponseFormat::Json.deserialise(payload).map_err(|_| error),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Balance {
+        asset: String,
+        free: f64,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct ApiError {
+        message: String,
+    }
+
+    struct TestParser;
+
+    impl HttpParser for TestParser {
+        type ApiError = ApiError;
+        type OutputError = SocketError;
+
+        fn parse_api_error(&self, status: StatusCode, error: Self::ApiError) -> Self::OutputError {
+            SocketError::HttpResponse(status, error.message)
+        }
+    }
+
+    #[test]
+    fn test_parse_format_text_csv() {
+        let actual = TestParser.parse_format::<f64>(StatusCode::OK, b"1.5\n", ResponseFormat::Text);
+        assert_eq!(actual.unwrap(), 1.5);
+
+        let actual = TestParser.parse_format::<Vec<Balance>>(
+            StatusCode::OK,
+            b"asset,free\nBTC,1.5\nETH,2\n",
+            ResponseFormat::Csv,
+        );
+        assert_eq!(
+            actual.unwrap(),
+            vec![
+                Balance {
+                    asset: "BTC".to_string(),
+                    free: 1.5
+                },
+                Balance {
+                    asset: "ETH".to_string(),
+                    free: 2.0
+                }
+            ]
+        );
+
+        // JSON API errors are parsed regardless of the ResponseFormat
+        let actual = TestParser.parse_format::<String>(
+            StatusCode::BAD_REQUEST,
+            br#"{"message":"invalid asset"}"#,
+            ResponseFormat::Text,
+        );
+        assert!(matches!(
+            actual,
+            Err(SocketError::HttpResponse(StatusCode::BAD_REQUEST, message)) if message == "invalid asset"
+        ));
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn test_parse_format_xml() {
+        let actual = TestParser.parse_format::<Balance>(
+            StatusCode::OK,
+            b"<balance><asset>BTC</asset><free>1.5</free></balance>",
+            ResponseFormat::Xml,
+        );
+        assert_eq!(
+            actual.unwrap(),
+            Balance {
+                asset: "BTC".to_string(),
+                free: 1.5
+            }
+        );
+
+        let actual = TestParser.parse_format::<Balance>(
+            StatusCode::BAD_REQUEST,
+            b"<error><message>invalid asset</message></error>",
+            ResponseFormat::Xml,
+        );
+        assert!(matches!(
+            actual,
+            Err(SocketError::HttpResponse(StatusCode::BAD_REQUEST, message)) if message == "invalid asset"
+        ));
+
+        let actual =
+            TestParser.parse_format::<Balance>(StatusCode::OK, b"not xml", ResponseFormat::Xml);
+        assert!(matches!(actual, Err(SocketError::DeserialiseXml { .. })));
+    }
+
+    #[cfg(not(feature = "xml"))]
+    #[test]
+    fn test_parse_format_xml_disabled() {
+        let actual = TestParser.parse_format::<Balance>(
+            StatusCode::OK,
+            b"<balance><asset>BTC</asset><free>1.5</free></balance>",
+            ResponseFormat::Xml,
+        );
+        assert!(matches!(actual, Err(SocketError::DeserialiseXml { .. })));
+    }
+
+    #[test]
+    fn test_idempotency_placement() {
+        let builder = reqwest::Client::new().post("https://api.x.com/order?symbol=BTCUSDT");
+        let request = IdempotencyPlacement::Query("newClientOrderId")
+            .inject(builder, "order-1")
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.x.com/order?symbol=BTCUSDT&newClientOrderId=order-1"
+        );
+
+        let builder = reqwest::Client::new().post("https://api.x.com/order");
+        let request = IdempotencyPlacement::Header("x-request-id")
+            .inject(builder, "order-1")
+            .build()
+            .unwrap();
+        assert_eq!(request.headers()["x-request-id"], "order-1");
+    }
+}
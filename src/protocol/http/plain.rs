@@ -0,0 +1,275 @@
+use serde::{
+    de::{
+        value::{Error, MapDeserializer, SeqDeserializer},
+        IntoDeserializer, Visitor,
+    },
+    forward_to_deserialize_any, Deserializer,
+};
+
+/// Deserialise a plain text payload (eg/ `"pong"`, `"42"`) into a `String`, number, bool or unit
+/// enum variant. Surrounding whitespace is ignored.
+pub fn from_text<'de, T>(text: &'de str) -> Result<T, Error>
+where
+    T: serde::Deserialize<'de>,
+{
+    T::deserialize(Field(text.trim()))
+}
+
+/// Deserialise a CSV payload with a header row into a sequence of records (eg/ `Vec<Candle>`),
+/// where each record is deserialised as a map of header name to field.
+///
+/// Fields are parsed according to the target type, so numeric looking ids may still be
+/// deserialised as `String`s. Empty fields deserialise as `None` for `Option` fields. Records
+/// with a different number of fields to the header row are rejected.
+pub fn from_csv<T>(csv: &str) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut reader = csv::Reader::from_reader(csv.as_bytes());
+    let headers = reader.headers().map_err(csv_error)?.clone();
+
+    let records = reader
+        .records()
+        .map(|record| {
+            record.map(|fields| Record {
+                headers: &headers,
+                fields,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(csv_error)?;
+
+    T::deserialize(SeqDeserializer::<_, Error>::new(records.into_iter()))
+}
+
+fn csv_error(error: csv::Error) -> Error {
+    <Error as serde::de::Error>::custom(error)
+}
+
+/// CSV record, deserialised as a map of header name to [`Field`].
+struct Record<'a> {
+    headers: &'a csv::StringRecord,
+    fields: csv::StringRecord,
+}
+
+impl<'de, 'a> IntoDeserializer<'de, Error> for Record<'a> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de, 'a> Deserializer<'de> for Record<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let entries = self
+            .headers
+            .iter()
+            .zip(self.fields.iter())
+            .map(|(header, field)| (header, OwnedField(field.to_string())));
+        visitor.visit_map(MapDeserializer::new(entries))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Owned CSV field, deserialised via a [`Field`].
+struct OwnedField(String);
+
+impl<'de> IntoDeserializer<'de, Error> for OwnedField {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+macro_rules! forward_to_field {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                Field(&self.0).$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for OwnedField {
+    type Error = Error;
+
+    forward_to_field! {
+        deserialize_any deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32
+        deserialize_i64 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_f32 deserialize_f64 deserialize_option deserialize_unit
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Field(&self.0).deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// Plain text field that is parsed according to the type being deserialised.
+struct Field<'a>(&'a str);
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident),*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: Visitor<'de>,
+            {
+                let value = self.0.trim().parse().map_err(|error| {
+                    <Error as serde::de::Error>::custom(format!("{error} for field: {}", self.0))
+                })?;
+                visitor.$visit(value)
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for Field<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_str(self.0)
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        IntoDeserializer::<Error>::into_deserializer(self.0)
+            .deserialize_enum(name, variants, visitor)
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum Side {
+        Buy,
+        Sell,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Fill {
+        id: String,
+        side: Side,
+        price: f64,
+        fee: Option<f64>,
+        note: String,
+    }
+
+    #[test]
+    fn test_from_text() {
+        assert_eq!(from_text::<String>("pong\n").unwrap(), "pong");
+        assert_eq!(from_text::<u64>(" 42 ").unwrap(), 42);
+        assert_eq!(from_text::<Side>("Sell").unwrap(), Side::Sell);
+        assert!(from_text::<u64>("pong").is_err());
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let csv = "id,side,price,fee,note\r\n\
+                   0001,Buy,100.5,0.1,plain\r\n\
+                   0002,Sell,101,,\"quoted, with \"\"escapes\"\"\"\r\n";
+
+        let actual = from_csv::<Vec<Fill>>(csv).unwrap();
+        let expected = vec![
+            Fill {
+                id: "0001".to_string(),
+                side: Side::Buy,
+                price: 100.5,
+                fee: Some(0.1),
+                note: "plain".to_string(),
+            },
+            Fill {
+                id: "0002".to_string(),
+                side: Side::Sell,
+                price: 101.0,
+                fee: None,
+                note: "quoted, with \"escapes\"".to_string(),
+            },
+        ];
+        assert_eq!(actual, expected);
+
+        assert!(from_csv::<Vec<Fill>>("id,side,price,fee,note\n1,Buy,abc,,x\n").is_err());
+
+        // Records with a missing or extra field are rejected rather than truncated
+        assert!(from_csv::<Vec<Fill>>("id,side,price,fee,note\n1,Buy,100\n").is_err());
+        assert!(from_csv::<Vec<Fill>>("id,side,price,fee,note\n1,Buy,100,,x,y\n").is_err());
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    error::SocketError,
+    protocol::http::{
+        rest::{path::PathTemplate, rate_limit::RequestPriority, BodyKind, RestRequest},
+        BuildStrategy, ResponseFormat,
+    },
+};
+use reqwest::header::HeaderMap;
+use std::{borrow::Cow, collections::HashMap, time::Duration};
+
+/// [`BuildStrategy`] that signs each [`RestRequest`] with the credentials of the account
+/// identified by its [`RestRequest::account_id`], such that one
+/// [`RestClient`](super::super::rest::client::RestClient) can serve many sub-accounts.
+///
+/// Requests without an account id are built using the default account strategy.
+///
+/// eg/ `MultiAccount::new(main_signer).with_account("sub-1", sub_signer)`
+#[derive(Debug, Clone)]
+pub struct MultiAccount<Strategy> {
+    pub default: Strategy,
+    pub accounts: HashMap<String, Strategy>,
+}
+
+impl<Strategy> MultiAccount<Strategy> {
+    /// Construct a new [`MultiAccount`] using the provided default account strategy.
+    pub fn new(default: Strategy) -> Self {
+        Self {
+            default,
+            accounts: HashMap::new(),
+        }
+    }
+
+    /// Build requests with the provided account id using the provided strategy (eg/ a
+    /// [`RequestSigner`](super::RequestSigner) holding the sub-account credentials).
+    pub fn with_account<S>(mut self, account_id: S, strategy: Strategy) -> Self
+    where
+        S: Into<String>,
+    {
+        self.accounts.insert(account_id.into(), strategy);
+        self
+    }
+}
+
+impl<Strategy> BuildStrategy for MultiAccount<Strategy>
+where
+    Strategy: BuildStrategy,
+{
+    fn build<Request>(
+        &self,
+        request: Request,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        let strategy = match request.account_id() {
+            Some(account_id) => {
+                self.accounts
+                    .get(&account_id)
+                    .ok_or_else(|| SocketError::Unsupported {
+                        entity: "MultiAccount",
+                        item: format!("account {account_id}"),
+                    })?
+            }
+            None => &self.default,
+        };
+
+        strategy.build(request, builder)
+    }
+
+    fn inject_idempotency_key(
+        &self,
+        builder: reqwest::RequestBuilder,
+        key: &str,
+    ) -> reqwest::RequestBuilder {
+        // Idempotency key placement is API wide, so is identical for every account
+        self.default.inject_idempotency_key(builder, key)
+    }
+
+    fn injects_idempotency_key(&self) -> bool {
+        self.default.injects_idempotency_key()
+    }
+}
+
+/// [`RestRequest`] wrapper that executes the inner request as the provided account, via a
+/// [`MultiAccount`] strategy.
+///
+/// See [`RestClient::execute_as`](super::super::rest::client::RestClient::execute_as).
+#[derive(Debug, Clone)]
+pub struct AsAccount<Request> {
+    pub account_id: String,
+    pub request: Request,
+}
+
+impl<Request> AsAccount<Request> {
+    /// Construct a new [`AsAccount`] executing the provided request as the provided account.
+    pub fn new<S>(account_id: S, request: Request) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            account_id: account_id.into(),
+            request,
+        }
+    }
+}
+
+impl<Request> RestRequest for AsAccount<Request>
+where
+    Request: RestRequest,
+{
+    type Response = Request::Response;
+    type QueryParams = Request::QueryParams;
+    type Body = Request::Body;
+
+    fn path(&self) -> Cow<'static, str> {
+        self.request.path()
+    }
+
+    fn path_template(&self) -> Option<PathTemplate> {
+        self.request.path_template()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        self.request.method()
+    }
+
+    fn body_kind() -> BodyKind {
+        Request::body_kind()
+    }
+
+    fn response_format() -> ResponseFormat {
+        Request::response_format()
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        self.request.query_params()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        self.request.body()
+    }
+
+    fn headers(&self) -> HeaderMap {
+        self.request.headers()
+    }
+
+    fn rate_limit_key(&self) -> Option<&'static str> {
+        self.request.rate_limit_key()
+    }
+
+    fn weight(&self) -> u32 {
+        self.request.weight()
+    }
+
+    fn priority(&self) -> RequestPriority {
+        self.request.priority()
+    }
+
+    fn idempotency_key(&self) -> Option<String> {
+        self.request.idempotency_key()
+    }
+
+    fn account_id(&self) -> Option<String> {
+        Some(self.account_id.clone())
+    }
+
+    fn cache_ttl(&self) -> Option<Duration> {
+        self.request.cache_ttl()
+    }
+
+    fn recv_window(&self) -> Option<Duration> {
+        self.request.recv_window()
+    }
+
+    fn timeout() -> Duration {
+        Request::timeout()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        protocol::http::private::{encoder::HexEncoder, RequestSigner},
+        test_util::{Account, PayloadSigner},
+    };
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    #[test]
+    fn test_multi_account_build() {
+        let signer = |api_key| {
+            RequestSigner::new(
+                PayloadSigner(api_key),
+                Hmac::<Sha256>::new_from_slice(api_key.as_bytes()).unwrap(),
+                HexEncoder,
+            )
+        };
+        let strategy = MultiAccount::new(signer("main")).with_account("sub-1", signer("sub"));
+        let client = reqwest::Client::new();
+        let builder = || client.get("https://api.x.com/account");
+
+        let main = strategy.build(Account, builder()).unwrap();
+        assert_eq!(main.headers()["x-payload"], "main");
+
+        let sub = strategy
+            .build(AsAccount::new("sub-1", Account), builder())
+            .unwrap();
+        assert_eq!(sub.headers()["x-payload"], "sub");
+
+        assert!(matches!(
+            strategy.build(AsAccount::new("sub-2", Account), builder()),
+            Err(SocketError::Unsupported { .. })
+        ));
+    }
+}
@@ -0,0 +1,80 @@
+use super::Signer;
+use crate::error::SocketError;
+use hmac::{
+    digest::{typenum::U0, FixedOutput, MacMarker, Output, OutputSizeUser, Update},
+    Mac,
+};
+
+/// Algorithm used by a [`RequestSigner`](super::RequestSigner) to generate the signature of the
+/// bytes provided by [`Signer::add_bytes_to_sign`].
+///
+/// Implemented for every hashable [`Mac`] (eg/ `Hmac<Sha256>`), and for asymmetric signing keys
+/// (eg/ [`Ed25519Key`](super::ed25519::Ed25519Key)), which sign the bytes collected by a
+/// [`SigningBytes`] buffer.
+pub trait SignatureAlgorithm {
+    /// Signature bytes, encoded by the [`Encoder`](super::encoder::Encoder).
+    type Signature: AsRef<[u8]>;
+
+    /// Generate the signature of the bytes added by [`Signer::add_bytes_to_sign`] for the
+    /// provided [`Signer::Config`].
+    fn sign<Sig>(&self, config: &Sig::Config<'_>) -> Result<Self::Signature, SocketError>
+    where
+        Sig: Signer;
+
+    /// Generate the signature of the provided bytes (eg/ a WebSocket login payload).
+    fn sign_bytes(&self, bytes: &[u8]) -> Result<Self::Signature, SocketError>;
+}
+
+impl<M> SignatureAlgorithm for M
+where
+    M: Mac + Clone,
+{
+    type Signature = Output<M>;
+
+    fn sign<Sig>(&self, config: &Sig::Config<'_>) -> Result<Self::Signature, SocketError>
+    where
+        Sig: Signer,
+    {
+        let mut mac = self.clone();
+        Sig::add_bytes_to_sign(&mut mac, config);
+        Ok(mac.finalize().into_bytes())
+    }
+
+    fn sign_bytes(&self, bytes: &[u8]) -> Result<Self::Signature, SocketError> {
+        Ok(self.clone().chain_update(bytes).finalize().into_bytes())
+    }
+}
+
+/// [`Mac`] compatible buffer that collects the bytes added by [`Signer::add_bytes_to_sign`],
+/// such that asymmetric [`SignatureAlgorithm`]s can sign them.
+#[derive(Debug, Clone, Default)]
+pub struct SigningBytes(pub Vec<u8>);
+
+impl SigningBytes {
+    /// Collect the bytes added by [`Signer::add_bytes_to_sign`] for the provided
+    /// [`Signer::Config`].
+    pub fn collect<Sig>(config: &Sig::Config<'_>) -> Self
+    where
+        Sig: Signer,
+    {
+        let mut bytes = Self::default();
+        Sig::add_bytes_to_sign(&mut bytes, config);
+        bytes
+    }
+}
+
+impl Update for SigningBytes {
+    fn update(&mut self, data: &[u8]) {
+        self.0.extend_from_slice(data);
+    }
+}
+
+impl OutputSizeUser for SigningBytes {
+    type OutputSize = U0;
+}
+
+impl FixedOutput for SigningBytes {
+    fn finalize_into(self, _: &mut Output<Self>) {}
+}
+
+impl MacMarker for SigningBytes {}
@@ -0,0 +1,197 @@
+use crate::error::SocketError;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+use url::form_urlencoded;
+
+/// Hex encoded SHA256 hash of an empty body.
+pub const EMPTY_SHA256: &str = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// Every character except the RFC 3986 unreserved characters `A-Z a-z 0-9 - _ . ~`.
+const RFC3986_RESERVED: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// Url encoding rules applied to the query string (or body) that is signed.
+///
+/// Subtle differences between the encoding that is signed & the encoding that is sent are the
+/// most common cause of "signature invalid" errors, so signers should encode both via the same
+/// [`UrlEncoding`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum UrlEncoding {
+    /// `application/x-www-form-urlencoded` rules, as used by `reqwest` & `serde_urlencoded`.
+    /// Spaces are encoded as `+`, and `*` is left unencoded.
+    ///
+    /// eg/ Binance
+    #[default]
+    Form,
+
+    /// RFC 3986 rules, encoding everything except the unreserved characters.
+    /// Spaces are encoded as `%20`.
+    ///
+    /// eg/ AWS SigV4, Kraken
+    Rfc3986,
+}
+
+impl UrlEncoding {
+    /// Encode the provided query key or value.
+    pub fn encode<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::Form => Cow::Owned(form_urlencoded::byte_serialize(value.as_bytes()).collect()),
+            Self::Rfc3986 => Cow::from(utf8_percent_encode(value, RFC3986_RESERVED)),
+        }
+    }
+}
+
+/// Ordering of the key value pairs within a canonical query string.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum QuerySort {
+    /// Stable sort by encoded key, such that repeated keys retain their original order.
+    ///
+    /// eg/ `b=2&b=1&a=1` => `a=1&b=2&b=1`
+    #[default]
+    Key,
+
+    /// Sort by encoded key, then by encoded value.
+    ///
+    /// eg/ AWS SigV4: `b=2&b=1&a=1` => `a=1&b=1&b=2`
+    KeyValue,
+}
+
+/// Generate the canonical query string of the provided key value pairs, encoded using the
+/// provided [`UrlEncoding`] & sorted according to the provided [`QuerySort`].
+///
+/// eg/ `[("symbol", "BTC USDT"), ("limit", "5")]` => `limit=5&symbol=BTC+USDT`
+pub fn canonical_query<Pairs, Key, Value>(
+    pairs: Pairs,
+    encoding: UrlEncoding,
+    sort: QuerySort,
+) -> String
+where
+    Pairs: IntoIterator<Item = (Key, Value)>,
+    Key: AsRef<str>,
+    Value: AsRef<str>,
+{
+    let mut pairs = pairs
+        .into_iter()
+        .map(|(key, value)| {
+            (
+                encoding.encode(key.as_ref()).into_owned(),
+                encoding.encode(value.as_ref()).into_owned(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    match sort {
+        QuerySort::Key => pairs.sort_by(|(a, _), (b, _)| a.cmp(b)),
+        QuerySort::KeyValue => pairs.sort(),
+    }
+
+    pairs
+        .into_iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Generate the canonical query string of the provided serialisable query parameters (eg/
+/// [`RestRequest::QueryParams`](super::super::rest::RestRequest::QueryParams)).
+///
+/// See [`canonical_query`].
+pub fn canonical_query_params<Params>(
+    params: &Params,
+    encoding: UrlEncoding,
+    sort: QuerySort,
+) -> Result<String, SocketError>
+where
+    Params: Serialize,
+{
+    let query = serde_urlencoded::to_string(params)?;
+    Ok(canonical_query(
+        form_urlencoded::parse(query.as_bytes()),
+        encoding,
+        sort,
+    ))
+}
+
+/// Generate the canonical query string of the provided [`reqwest::Url`].
+///
+/// See [`canonical_query`].
+pub fn canonical_url_query(url: &reqwest::Url, encoding: UrlEncoding, sort: QuerySort) -> String {
+    canonical_query(url.query_pairs(), encoding, sort)
+}
+
+/// Hex encoded SHA256 hash of the provided body, as signed by APIs that sign a body digest
+/// rather than the body itself.
+///
+/// eg/ `sha256_hex(b"")` == [`EMPTY_SHA256`]
+pub fn sha256_hex<Body>(body: Body) -> String
+where
+    Body: AsRef<[u8]>,
+{
+    hex::encode(Sha256::digest(body.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_query() {
+        let pairs = [
+            ("symbol", "BTC USDT*"),
+            ("limit", "5"),
+            ("b", "2"),
+            ("b", "1"),
+        ];
+
+        assert_eq!(
+            canonical_query(pairs, UrlEncoding::Form, QuerySort::Key),
+            "b=2&b=1&limit=5&symbol=BTC+USDT*"
+        );
+        assert_eq!(
+            canonical_query(pairs, UrlEncoding::Rfc3986, QuerySort::Key),
+            "b=2&b=1&limit=5&symbol=BTC%20USDT%2A"
+        );
+        assert_eq!(
+            canonical_query(pairs, UrlEncoding::Rfc3986, QuerySort::KeyValue),
+            "b=1&b=2&limit=5&symbol=BTC%20USDT%2A"
+        );
+
+        #[derive(Serialize)]
+        struct Params {
+            symbol: &'static str,
+            limit: u32,
+        }
+        assert_eq!(
+            canonical_query_params(
+                &Params {
+                    symbol: "BTC/USDT",
+                    limit: 5
+                },
+                UrlEncoding::Rfc3986,
+                QuerySort::Key,
+            )
+            .unwrap(),
+            "limit=5&symbol=BTC%2FUSDT"
+        );
+
+        let url = reqwest::Url::parse("https://api.x.com/depth?symbol=BTCUSDT&limit=5").unwrap();
+        assert_eq!(
+            canonical_url_query(&url, UrlEncoding::Form, QuerySort::Key),
+            "limit=5&symbol=BTCUSDT"
+        );
+    }
+
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(sha256_hex(b""), EMPTY_SHA256);
+        assert_eq!(
+            sha256_hex("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use super::secret::Secret;
+use crate::error::SocketError;
+use hmac::{digest::KeyInit, Mac};
+
+/// API key, secret & optional passphrase used to sign private requests.
+///
+/// Signers of APIs that require a passphrase (eg/ OKX, Coinbase) hold a [`Credentials`] & borrow
+/// it in their [`Signer::Config`](super::Signer::Config), injecting the headers via
+/// [`PassphraseHeaders`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Credentials {
+    pub api_key: String,
+    pub secret: Secret,
+    pub passphrase: Option<Secret>,
+}
+
+impl Credentials {
+    /// Construct new [`Credentials`] from the provided API key & secret, without a passphrase.
+    pub fn new<Key, S>(api_key: Key, secret: S) -> Self
+    where
+        Key: Into<String>,
+        S: Into<Secret>,
+    {
+        Self {
+            api_key: api_key.into(),
+            secret: secret.into(),
+            passphrase: None,
+        }
+    }
+
+    /// Add the provided passphrase, as chosen when the API key was created.
+    pub fn with_passphrase<S>(self, passphrase: S) -> Self
+    where
+        S: Into<Secret>,
+    {
+        Self {
+            passphrase: Some(passphrase.into()),
+            ..self
+        }
+    }
+
+    /// Initialise a [`Mac`] keyed with the secret, for use by a
+    /// [`RequestSigner`](super::RequestSigner).
+    ///
+    /// eg/ `credentials.mac::<Hmac<Sha256>>()?`
+    pub fn mac<M>(&self) -> Result<M, SocketError>
+    where
+        M: Mac + KeyInit,
+    {
+        <M as Mac>::new_from_slice(self.secret.expose().as_bytes())
+            .map_err(|error| SocketError::SigningKey(error.to_string()))
+    }
+}
+
+/// Header names used to inject the [`Credentials`], timestamp & signature of a signed request,
+/// for APIs using the common key + passphrase + timestamp + signature header scheme.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct PassphraseHeaders {
+    pub api_key: &'static str,
+    pub passphrase: &'static str,
+    pub timestamp: &'static str,
+    pub signature: &'static str,
+}
+
+impl PassphraseHeaders {
+    /// OKX `OK-ACCESS-*` headers.
+    pub const OKX: Self = Self {
+        api_key: "OK-ACCESS-KEY",
+        passphrase: "OK-ACCESS-PASSPHRASE",
+        timestamp: "OK-ACCESS-TIMESTAMP",
+        signature: "OK-ACCESS-SIGN",
+    };
+
+    /// Coinbase Exchange `CB-ACCESS-*` headers.
+    pub const COINBASE: Self = Self {
+        api_key: "CB-ACCESS-KEY",
+        passphrase: "CB-ACCESS-PASSPHRASE",
+        timestamp: "CB-ACCESS-TIMESTAMP",
+        signature: "CB-ACCESS-SIGN",
+    };
+
+    /// Inject the [`Credentials`] api key & passphrase (if any), timestamp & signature headers.
+    pub fn inject(
+        &self,
+        builder: reqwest::RequestBuilder,
+        credentials: &Credentials,
+        timestamp: &str,
+        signature: &str,
+    ) -> reqwest::RequestBuilder {
+        let builder = builder
+            .header(self.api_key, &credentials.api_key)
+            .header(self.timestamp, timestamp)
+            .header(self.signature, signature);
+
+        match &credentials.passphrase {
+            Some(passphrase) => builder.header(self.passphrase, passphrase.expose()),
+            None => builder,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hmac::Hmac;
+    use sha2::Sha256;
+
+    #[test]
+    fn test_passphrase_headers_inject() {
+        let credentials = Credentials::new("key", "secret").with_passphrase("passphrase");
+        assert!(credentials.mac::<Hmac<Sha256>>().is_ok());
+
+        let request = PassphraseHeaders::OKX
+            .inject(
+                reqwest::Client::new().get("https://www.okx.com/api/v5/account/balance"),
+                &credentials,
+                "2020-12-08T09:08:57.715Z",
+                "signature",
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(request.headers()["OK-ACCESS-KEY"], "key");
+        assert_eq!(request.headers()["OK-ACCESS-PASSPHRASE"], "passphrase");
+        assert_eq!(
+            request.headers()["OK-ACCESS-TIMESTAMP"],
+            "2020-12-08T09:08:57.715Z"
+        );
+        assert_eq!(request.headers()["OK-ACCESS-SIGN"], "signature");
+        assert_eq!(format!("{credentials:?}").matches("***").count(), 2);
+    }
+}
@@ -33,3 +33,72 @@ impl Encoder for Base64Encoder {
         base64::engine::general_purpose::STANDARD.encode(data)
     }
 }
+
+/// Encodes bytes data as a URL-safe base64 `String` (`-` & `_` in place of `+` & `/`), with
+/// padding.
+#[derive(Debug, Copy, Clone)]
+pub struct Base64UrlEncoder;
+
+impl Encoder for Base64UrlEncoder {
+    fn encode<Bytes>(&self, data: Bytes) -> String
+    where
+        Bytes: AsRef<[u8]>,
+    {
+        base64::engine::general_purpose::URL_SAFE.encode(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hmac::{Hmac, Mac};
+    use sha2::{Digest, Sha256, Sha512};
+
+    #[test]
+    fn test_encoders_rfc_4231_vector() {
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"Jefe").unwrap();
+        mac.update(b"what do ya want for nothing?");
+        let signature = mac.finalize().into_bytes();
+
+        assert_eq!(
+            HexEncoder.encode(signature),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert_eq!(
+            Base64Encoder.encode(signature),
+            "W9zBRr9gdU5qBCQmCJV1x1oAPwidJzmDnexYuWTsOEM="
+        );
+        assert_eq!(
+            Base64UrlEncoder.encode(signature),
+            "W9zBRr9gdU5qBCQmCJV1x1oAPwidJzmDnexYuWTsOEM="
+        );
+    }
+
+    #[test]
+    fn test_encoders_kraken_vector() {
+        // Kraken REST API authentication documentation example
+        let secret = base64::engine::general_purpose::STANDARD
+            .decode(
+                "kQH5HW/8p1uGOVjbgWA7FunAmGO8lsSUXNsu3eow76sz84Q18fWxnyRzBHCd3pd5nE9qa99HAZtuZuj6F1huXg==",
+            )
+            .unwrap();
+        let (nonce, post_data) = (
+            "1616492376594",
+            "nonce=1616492376594&ordertype=limit&pair=XBTUSD&price=37500&type=buy&volume=1.25",
+        );
+
+        let mut mac = Hmac::<Sha512>::new_from_slice(&secret).unwrap();
+        mac.update(b"/0/private/AddOrder");
+        mac.update(&Sha256::digest(format!("{nonce}{post_data}")));
+        let signature = mac.finalize().into_bytes();
+
+        assert_eq!(
+            Base64Encoder.encode(signature),
+            "4/dpxb3iT4tp/ZCVEwSnEsLxx0bqyhLpdfOpc6fn7OR8+UClSV5n9E6aSS8MPtnRfp32bAb0nmbRn6H8ndwLUQ=="
+        );
+        assert_eq!(
+            Base64UrlEncoder.encode(signature),
+            "4_dpxb3iT4tp_ZCVEwSnEsLxx0bqyhLpdfOpc6fn7OR8-UClSV5n9E6aSS8MPtnRfp32bAb0nmbRn6H8ndwLUQ=="
+        );
+    }
+}
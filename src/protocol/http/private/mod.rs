@@ -1,4 +1,4 @@
-use self::encoder::Encoder;
+use self::{algorithm::SignatureAlgorithm, encoder::Encoder, placement::SignaturePlacement};
 use super::{rest::RestRequest, BuildStrategy};
 use crate::error::SocketError;
 use hmac::Mac;
@@ -6,6 +6,59 @@ use hmac::Mac;
 /// Implementations for encoding signatures generated by a [`RequestSigner`].
 pub mod encoder;
 
+/// [`SignatureAlgorithm`](algorithm::SignatureAlgorithm)s used by a [`RequestSigner`] to sign
+/// requests, implemented for every hashable [`Mac`].
+pub mod algorithm;
+
+/// [`Ed25519Key`](ed25519::Ed25519Key) asymmetric [`SignatureAlgorithm`].
+#[cfg(feature = "ed25519")]
+pub mod ed25519;
+
+/// [`RsaKey`](rsa::RsaKey) RSA-SHA256 asymmetric [`SignatureAlgorithm`].
+#[cfg(feature = "rsa")]
+pub mod rsa;
+
+/// [`SignaturePlacement`](placement::SignaturePlacement) of the encoded signature within a signed
+/// request (header, query parameter or body field).
+pub mod placement;
+
+/// [`TimeSync`](time_sync::TimeSync) service tracking the server clock offset, used to generate
+/// valid signed request timestamps.
+pub mod time_sync;
+
+/// [`MultiAccount`](account::MultiAccount) strategy signing each request with the credentials of
+/// its account, for multi-account setups.
+pub mod account;
+
+/// [`Rotating`](rotating::Rotating) hot swappable credentials, for rotating API keys at runtime.
+pub mod rotating;
+
+/// [`Secret`](secret::Secret) API secrets & passphrases, zeroised on drop & redacted in logs.
+pub mod secret;
+
+/// [`Credentials`](credentials::Credentials) API key, secret & passphrase, with the
+/// [`PassphraseHeaders`](credentials::PassphraseHeaders) used to inject them (eg/ OKX, Coinbase).
+pub mod credentials;
+
+/// Canonicalisation helpers (sorted query strings, url encoding rules & body hashing) for
+/// generating the bytes to sign.
+pub mod canonical;
+
+/// [`SigV4`](sigv4::SigV4) AWS Signature Version 4 [`BuildStrategy`].
+pub mod sigv4;
+
+/// [`RecvWindow`](recv_window::RecvWindow) signed request validity windows (eg/ Binance
+/// `recvWindow`), configurable per client & per request.
+pub mod recv_window;
+
+/// [`SignerVector`](test_util::SignerVector) harness for validating [`Signer`]s against known
+/// signature vectors without hitting the live API.
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+
+/// Shared strictly increasing [`NonceProvider`](nonce::NonceProvider)s for private requests.
+pub mod nonce;
+
 /// API specific signing logic used by a [`RequestSigner`].
 #[allow(clippy::needless_lifetimes)]
 pub trait Signer {
@@ -21,15 +74,15 @@ pub trait Signer {
     ///
     /// ## Private REST Request: FTX
     /// ```rust,ignore
-    /// fn config<Request>(&self, _: Request, _: &RequestBuilder) -> Self::Config
+    /// fn config<Request>(&self, request: Request, _: &RequestBuilder) -> Self::Config
     /// where
     ///     Request: RestRequest
     /// {
     ///     FtxSignConfig {
     ///         api_key: self.api_key.as_str(),
     ///         time: Utc::now(),
-    ///         method: Request::method(),
-    ///         path: Request::path()
+    ///         method: request.method(),
+    ///         path: request.path()
     ///     }
     /// }
     /// ```
@@ -60,6 +113,10 @@ pub trait Signer {
     /// Build a signed [`reqwest::Request`] from the provided [`Self::Config`],
     /// [`RequestBuilder`](reqwest::RequestBuilder), and generated cryptographic signature `String`.
     ///
+    /// If the [`RequestSigner`] is configured with a [`SignaturePlacement`], the signature is
+    /// placed after this method builds the request, so it need only add the remaining auth
+    /// fields (eg/ api key header).
+    ///
     /// # Examples
     ///
     /// ## Private REST Request: FTX
@@ -79,21 +136,39 @@ pub trait Signer {
         builder: reqwest::RequestBuilder,
         signature: String,
     ) -> Result<reqwest::Request, SocketError>;
+
+    /// Inject the [`RestRequest::idempotency_key`] (eg/ client order id) into the request in the
+    /// consistent place required by this API, before it is signed.
+    ///
+    /// See [`BuildStrategy::inject_idempotency_key`].
+    fn inject_idempotency_key(
+        &self,
+        builder: reqwest::RequestBuilder,
+        _key: &str,
+    ) -> reqwest::RequestBuilder {
+        builder
+    }
+
+    /// See [`BuildStrategy::injects_idempotency_key`].
+    fn injects_idempotency_key(&self) -> bool {
+        false
+    }
 }
 
 /// Generically signs Http [`RestRequest`]s utilising API specific [`Signer`] logic, a hashable
-/// [`Mac`], and a signature [`Encoder`].
+/// [`Mac`] (or other [`SignatureAlgorithm`], eg/ an Ed25519 key), and a signature [`Encoder`].
 #[derive(Debug, Copy, Clone)]
 pub struct RequestSigner<Sig, Hmac, SigEncoder> {
     signer: Sig,
     mac: Hmac,
     encoder: SigEncoder,
+    placement: Option<SignaturePlacement>,
 }
 
 impl<Sig, Hmac, SigEncoder> BuildStrategy for RequestSigner<Sig, Hmac, SigEncoder>
 where
     Sig: Signer,
-    Hmac: Mac + Clone,
+    Hmac: SignatureAlgorithm,
     SigEncoder: Encoder,
 {
     fn build<Request>(
@@ -107,15 +182,31 @@ where
         // Build configuration required for generating signed requests
         let config = self.signer.config(request, &builder)?;
 
-        // Update Mac state & finalise bytes
-        let mut mac = self.mac.clone();
-        Sig::add_bytes_to_sign(&mut mac, &config);
-        let bytes_to_encode = mac.finalize().into_bytes();
+        // Sign the bytes added by the Signer
+        let bytes_to_encode = self.mac.sign::<Sig>(&config)?;
 
-        // Encode signature from Mac bytes
+        // Encode signature from signed bytes
         let signature = self.encoder.encode(bytes_to_encode);
 
-        Sig::build_signed_request(config, builder, signature)
+        match self.placement {
+            Some(placement) => {
+                let request = Sig::build_signed_request(config, builder, signature.clone())?;
+                placement.apply(request, &signature)
+            }
+            None => Sig::build_signed_request(config, builder, signature),
+        }
+    }
+
+    fn inject_idempotency_key(
+        &self,
+        builder: reqwest::RequestBuilder,
+        key: &str,
+    ) -> reqwest::RequestBuilder {
+        self.signer.inject_idempotency_key(builder, key)
+    }
+
+    fn injects_idempotency_key(&self) -> bool {
+        self.signer.injects_idempotency_key()
     }
 }
 
@@ -126,6 +217,16 @@ impl<Sig, Hmac, SigEncoder> RequestSigner<Sig, Hmac, SigEncoder> {
             signer,
             mac,
             encoder,
+            placement: None,
+        }
+    }
+
+    /// Place the encoded signature in the provided [`SignaturePlacement`] once
+    /// [`Signer::build_signed_request`] has built the request.
+    pub fn with_placement(self, placement: SignaturePlacement) -> Self {
+        Self {
+            placement: Some(placement),
+            ..self
         }
     }
 }
@@ -0,0 +1,238 @@
+use crate::error::SocketError;
+use chrono::Utc;
+use std::{
+    fmt::Debug,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+/// Provides strictly increasing nonces shared across every private request of an account.
+///
+/// eg/ Kraken rejects any request with a nonce less than or equal to the last nonce it received.
+///
+/// [`Signer`](super::Signer)s should hold a clone of the account's provider, and use
+/// [`NonceProvider::next`] when constructing their [`Signer::Config`](super::Signer::Config), such
+/// that concurrently built requests never collide.
+pub trait NonceProvider: Debug + Send + Sync {
+    /// Generate the next nonce, strictly greater than every nonce previously generated.
+    ///
+    /// Fails closed if a nonce that is guaranteed to never be reused cannot be generated (eg/
+    /// [`PersistedNonce`] failed to persist), in which case the request must not be sent.
+    fn next(&self) -> Result<u64, SocketError>;
+}
+
+impl<Provider> NonceProvider for Arc<Provider>
+where
+    Provider: NonceProvider + ?Sized,
+{
+    fn next(&self) -> Result<u64, SocketError> {
+        Provider::next(self)
+    }
+}
+
+/// [`NonceProvider`] generating an atomically incrementing counter.
+///
+/// Cheaply cloneable, with every clone sharing the same counter.
+#[derive(Debug, Clone, Default)]
+pub struct AtomicNonce {
+    last: Arc<AtomicU64>,
+}
+
+impl AtomicNonce {
+    /// Construct a new [`AtomicNonce`] that generates nonces greater than the provided nonce.
+    pub fn new(last: u64) -> Self {
+        Self {
+            last: Arc::new(AtomicU64::new(last)),
+        }
+    }
+}
+
+impl NonceProvider for AtomicNonce {
+    fn next(&self) -> Result<u64, SocketError> {
+        Ok(self.last.fetch_add(1, Ordering::SeqCst) + 1)
+    }
+}
+
+/// [`NonceProvider`] generating the current Unix timestamp in microseconds, incremented if
+/// necessary to remain strictly increasing when many nonces are generated at once.
+///
+/// Cheaply cloneable, with every clone sharing the same last nonce.
+#[derive(Debug, Clone, Default)]
+pub struct TimestampNonce {
+    last: Arc<AtomicU64>,
+}
+
+impl TimestampNonce {
+    /// Construct a new [`TimestampNonce`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl NonceProvider for TimestampNonce {
+    fn next(&self) -> Result<u64, SocketError> {
+        let now = Utc::now().timestamp_micros() as u64;
+        let previous = self
+            .last
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |last| {
+                Some(now.max(last + 1))
+            })
+            .expect("closure always returns Some");
+        Ok(now.max(previous + 1))
+    }
+}
+
+/// [`NonceProvider`] that persists nonces to a file, such that nonces are never reused across
+/// restarts.
+///
+/// Nonces are reserved in blocks, with only the upper bound of each block persisted, so the file
+/// is written once per `reserve` nonces. Nonces remaining in a block are skipped upon restart.
+///
+/// Reservations are written atomically (temporary file, fsync & rename), and no nonce beyond the
+/// last durably persisted reservation is ever generated - [`NonceProvider::next`] fails instead.
+#[derive(Debug)]
+pub struct PersistedNonce {
+    path: PathBuf,
+    reserve: u64,
+    state: Mutex<PersistedState>,
+}
+
+#[derive(Debug)]
+struct PersistedState {
+    last: u64,
+    reserved: u64,
+}
+
+impl PersistedNonce {
+    /// Construct a new [`PersistedNonce`] that persists reserved blocks of nonces to the provided
+    /// file, generating nonces greater than any previously persisted.
+    pub fn new<P>(path: P, reserve: u64) -> Result<Self, SocketError>
+    where
+        P: Into<PathBuf>,
+    {
+        let path = path.into();
+        let last = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents.trim().parse::<u64>().map_err(|error| {
+                SocketError::Io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("invalid persisted nonce in {}: {error}", path.display()),
+                ))
+            })?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => 0,
+            Err(error) => return Err(SocketError::Io(error)),
+        };
+
+        Ok(Self {
+            path,
+            reserve: reserve.max(1),
+            state: Mutex::new(PersistedState {
+                last,
+                reserved: last,
+            }),
+        })
+    }
+}
+
+impl NonceProvider for PersistedNonce {
+    fn next(&self) -> Result<u64, SocketError> {
+        let mut state = self.state.lock().expect("PersistedNonce Mutex poisoned");
+        let next = state.last + 1;
+
+        if next > state.reserved {
+            let reserved = next + self.reserve - 1;
+            persist(&self.path, reserved)?;
+            state.reserved = reserved;
+        }
+
+        state.last = next;
+        Ok(next)
+    }
+}
+
+/// Durably & atomically persist the provided reserved nonce upper bound to the provided file.
+fn persist(path: &Path, reserved: u64) -> Result<(), SocketError> {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+
+    let mut file = std::fs::File::create(&temp)?;
+    file.write_all(reserved.to_string().as_bytes())?;
+    file.sync_all()?;
+    std::fs::rename(&temp, path)?;
+
+    // Persist the rename itself, where the platform supports syncing directories
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        if let Ok(directory) = std::fs::File::open(parent) {
+            let _ = directory.sync_all();
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nonce_providers() {
+        let atomic = AtomicNonce::new(10);
+        assert_eq!(
+            (atomic.next().unwrap(), atomic.clone().next().unwrap()),
+            (11, 12)
+        );
+
+        let timestamp = TimestampNonce::new();
+        let nonces = (0..100)
+            .map(|_| timestamp.next().unwrap())
+            .collect::<Vec<_>>();
+        assert!(nonces.windows(2).all(|pair| pair[0] < pair[1]));
+
+        // Restarted PersistedNonce never reuses nonces
+        let path = std::env::temp_dir().join(format!("nonce_{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let persisted = PersistedNonce::new(&path, 10).unwrap();
+        assert_eq!(
+            (persisted.next().unwrap(), persisted.next().unwrap()),
+            (1, 2)
+        );
+
+        let restarted = PersistedNonce::new(&path, 10).unwrap();
+        assert_eq!(restarted.next().unwrap(), 11);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_persisted_nonce_fails_closed() {
+        let directory = std::env::temp_dir().join(format!("nonce_dir_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("nonce");
+
+        let persisted = PersistedNonce::new(&path, 2).unwrap();
+        assert_eq!(
+            (persisted.next().unwrap(), persisted.next().unwrap()),
+            (1, 2)
+        );
+
+        // Reservation cannot be persisted, so no nonce beyond the last reservation is generated
+        std::fs::remove_dir_all(&directory).unwrap();
+        assert!(persisted.next().is_err());
+        assert!(persisted.next().is_err());
+
+        // Once persistence recovers, nonces continue from the last reservation
+        std::fs::create_dir_all(&directory).unwrap();
+        assert_eq!(persisted.next().unwrap(), 3);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "4");
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
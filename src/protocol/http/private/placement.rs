@@ -0,0 +1,183 @@
+use crate::error::SocketError;
+use reqwest::header::{HeaderValue, CONTENT_TYPE};
+use serde_json::Value;
+
+/// Placement of the encoded signature within a signed request, applied by a
+/// [`RequestSigner`](super::RequestSigner) after
+/// [`Signer::build_signed_request`](super::Signer::build_signed_request).
+///
+/// Allows the same signing core to serve APIs that expect the signature in a header (eg/ FTX),
+/// the query string (eg/ Binance), or a body field.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum SignaturePlacement {
+    /// Header with the provided name. eg/ `X-MBX-SIGNATURE`.
+    Header(&'static str),
+    /// Query parameter with the provided name, appended last. eg/ `signature`.
+    Query(&'static str),
+    /// Field with the provided name, added to the JSON object or url encoded form body.
+    /// eg/ `sign`.
+    Body(&'static str),
+}
+
+impl SignaturePlacement {
+    /// Place the provided encoded signature into the built request.
+    pub fn apply(
+        self,
+        mut request: reqwest::Request,
+        signature: &str,
+    ) -> Result<reqwest::Request, SocketError> {
+        match self {
+            Self::Header(name) => {
+                let value = HeaderValue::from_str(signature)
+                    .map_err(|error| SocketError::InvalidHeader(error.to_string()))?;
+                request.headers_mut().insert(name, value);
+            }
+            Self::Query(name) => {
+                request
+                    .url_mut()
+                    .query_pairs_mut()
+                    .append_pair(name, signature);
+            }
+            Self::Body(name) => {
+                let body = place_body_field(&request, name, signature)?;
+                *request.body_mut() = Some(body.into());
+            }
+        }
+
+        Ok(request)
+    }
+}
+
+/// Add the signature field to the JSON object or url encoded form body of the request.
+///
+/// The field is spliced into the raw body bytes, such that the signed bytes are sent unchanged
+/// (ie/ no key reordering or number reformatting from re-serialising).
+fn place_body_field(
+    request: &reqwest::Request,
+    name: &str,
+    signature: &str,
+) -> Result<Vec<u8>, SocketError> {
+    let unsupported = |item: &str| SocketError::Unsupported {
+        entity: "SignaturePlacement::Body",
+        item: item.to_string(),
+    };
+
+    let body = request
+        .body()
+        .and_then(reqwest::Body::as_bytes)
+        .ok_or_else(|| unsupported("request without a buffered body"))?;
+
+    let content_type = request
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    if content_type.starts_with("application/json") {
+        let object = match serde_json::from_slice(body) {
+            Ok(Value::Object(object)) => object,
+            _ => return Err(unsupported("JSON body that is not an object")),
+        };
+
+        // Valid JSON object, so the last non-whitespace byte is the closing brace
+        let end = body
+            .iter()
+            .rposition(|byte| *byte == b'}')
+            .ok_or_else(|| unsupported("JSON body that is not an object"))?;
+
+        let mut spliced = body[..end].to_vec();
+        if !object.is_empty() {
+            spliced.push(b',');
+        }
+        serde_json::to_writer(&mut spliced, name).map_err(SocketError::Serialise)?;
+        spliced.push(b':');
+        serde_json::to_writer(&mut spliced, signature).map_err(SocketError::Serialise)?;
+        spliced.extend_from_slice(&body[end..]);
+        Ok(spliced)
+    } else if content_type.starts_with("application/x-www-form-urlencoded") {
+        let mut form = body.to_vec();
+        if !form.is_empty() {
+            form.push(b'&');
+        }
+        let field = serde_urlencoded::to_string([(name, signature)])?;
+        form.extend_from_slice(field.as_bytes());
+        Ok(form)
+    } else {
+        Err(unsupported(&format!("body content type '{content_type}'")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_placement_apply() {
+        let client = reqwest::Client::new();
+
+        let request = client
+            .get("https://api.x.com/account?timestamp=1")
+            .build()
+            .unwrap();
+        let request = SignaturePlacement::Query("signature")
+            .apply(request, "a+b/c")
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.x.com/account?timestamp=1&signature=a%2Bb%2Fc"
+        );
+
+        let request = client.get("https://api.x.com/account").build().unwrap();
+        let request = SignaturePlacement::Header("x-signature")
+            .apply(request, "abc")
+            .unwrap();
+        assert_eq!(request.headers()["x-signature"], "abc");
+
+        // Signed JSON bytes are sent unchanged, despite unsorted keys & non canonical numbers
+        let request = client
+            .post("https://api.x.com/order")
+            .header(CONTENT_TYPE, "application/json")
+            .body(r#"{"symbol":"BTCUSDT","price":1.50}"#)
+            .build()
+            .unwrap();
+        let request = SignaturePlacement::Body("sign")
+            .apply(request, "abc")
+            .unwrap();
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            br#"{"symbol":"BTCUSDT","price":1.50,"sign":"abc"}"#
+        );
+
+        let request = client
+            .post("https://api.x.com/order")
+            .json(&serde_json::json!({}))
+            .build()
+            .unwrap();
+        let request = SignaturePlacement::Body("sign")
+            .apply(request, "abc")
+            .unwrap();
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            br#"{"sign":"abc"}"#
+        );
+
+        let request = client
+            .post("https://api.x.com/order")
+            .form(&[("symbol", "BTCUSDT")])
+            .build()
+            .unwrap();
+        let request = SignaturePlacement::Body("sign")
+            .apply(request, "a=b")
+            .unwrap();
+        assert_eq!(
+            request.body().unwrap().as_bytes().unwrap(),
+            b"symbol=BTCUSDT&sign=a%3Db"
+        );
+
+        let request = client.post("https://api.x.com/order").build().unwrap();
+        assert!(matches!(
+            SignaturePlacement::Body("sign").apply(request, "abc"),
+            Err(SocketError::Unsupported { .. })
+        ));
+    }
+}
@@ -0,0 +1,174 @@
+use crate::protocol::http::rest::RestRequest;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Placement & format of a signed request validity window.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RecvWindowPlacement {
+    /// Query parameter with the provided name, containing the window in milliseconds.
+    /// eg/ Binance `recvWindow`.
+    Query(&'static str),
+    /// Header with the provided name, containing the window in milliseconds.
+    /// eg/ Bybit `X-BAPI-RECV-WINDOW`.
+    Header(&'static str),
+    /// Header with the provided name, containing the absolute expiry unix timestamp in seconds.
+    /// eg/ BitMEX `api-expires`.
+    ExpiresHeader(&'static str),
+}
+
+/// Client wide validity window of signed requests, after which the server rejects them, that
+/// [`RestRequest::recv_window`] may override per request.
+///
+/// [`Signer`](super::Signer)s hold a [`RecvWindow`], [`resolve`](Self::resolve) the window when
+/// generating their [`Signer::Config`](super::Signer::Config), sign the
+/// [`value`](Self::value) where the API requires it, & [`inject`](Self::inject) it when building
+/// the signed request.
+///
+/// eg/ `RecvWindow::BINANCE.with_default(Duration::from_millis(5000))`
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct RecvWindow {
+    pub placement: RecvWindowPlacement,
+    pub default: Option<Duration>,
+}
+
+impl RecvWindow {
+    /// Binance `recvWindow` query parameter.
+    pub const BINANCE: Self = Self::new(RecvWindowPlacement::Query("recvWindow"));
+
+    /// Bybit `X-BAPI-RECV-WINDOW` header.
+    pub const BYBIT: Self = Self::new(RecvWindowPlacement::Header("X-BAPI-RECV-WINDOW"));
+
+    /// BitMEX `api-expires` header.
+    pub const BITMEX: Self = Self::new(RecvWindowPlacement::ExpiresHeader("api-expires"));
+
+    /// Construct a new [`RecvWindow`] with the provided placement & no client wide default, such
+    /// that only requests providing a [`RestRequest::recv_window`] are windowed.
+    pub const fn new(placement: RecvWindowPlacement) -> Self {
+        Self {
+            placement,
+            default: None,
+        }
+    }
+
+    /// Window every request using the provided client wide default, unless overridden.
+    pub const fn with_default(self, window: Duration) -> Self {
+        Self {
+            default: Some(window),
+            ..self
+        }
+    }
+
+    /// Window of the provided request, preferring its [`RestRequest::recv_window`] over the
+    /// client wide default.
+    pub fn resolve<Request>(&self, request: &Request) -> Option<Duration>
+    where
+        Request: RestRequest,
+    {
+        request.recv_window().or(self.default)
+    }
+
+    /// Formatted value of the provided window for a request signed at the provided time, as
+    /// sent (& potentially signed).
+    ///
+    /// Absolute expiry timestamps are rounded up to the next whole second, such that sub-second
+    /// windows never expire before the request is sent.
+    pub fn value(&self, timestamp: DateTime<Utc>, window: Duration) -> String {
+        match self.placement {
+            RecvWindowPlacement::Query(_) | RecvWindowPlacement::Header(_) => {
+                window.as_millis().to_string()
+            }
+            RecvWindowPlacement::ExpiresHeader(_) => {
+                let expires_ms = timestamp.timestamp_millis() + window.as_millis() as i64;
+                (expires_ms + 999).div_euclid(1000).to_string()
+            }
+        }
+    }
+
+    /// Inject the provided window for a request signed at the provided time.
+    pub fn inject(
+        &self,
+        builder: reqwest::RequestBuilder,
+        timestamp: DateTime<Utc>,
+        window: Duration,
+    ) -> reqwest::RequestBuilder {
+        let value = self.value(timestamp, window);
+        match self.placement {
+            RecvWindowPlacement::Query(name) => builder.query(&[(name, value)]),
+            RecvWindowPlacement::Header(name) | RecvWindowPlacement::ExpiresHeader(name) => {
+                builder.header(name, value)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::borrow::Cow;
+
+    struct Order(Option<Duration>);
+
+    impl RestRequest for Order {
+        type Response = ();
+        type QueryParams = ();
+        type Body = ();
+
+        fn path(&self) -> Cow<'static, str> {
+            Cow::Borrowed("/order")
+        }
+
+        fn recv_window(&self) -> Option<Duration> {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_recv_window() {
+        let window = RecvWindow::BINANCE.with_default(Duration::from_secs(5));
+        let timestamp = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+
+        assert_eq!(window.resolve(&Order(None)), Some(Duration::from_secs(5)));
+        let resolved = window
+            .resolve(&Order(Some(Duration::from_millis(500))))
+            .unwrap();
+        assert_eq!(resolved, Duration::from_millis(500));
+        assert_eq!(RecvWindow::BYBIT.resolve(&Order(None)), None);
+
+        let request = window
+            .inject(
+                reqwest::Client::new().post("https://api.x.com/order?symbol=BTCUSDT"),
+                timestamp,
+                resolved,
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.url().as_str(),
+            "https://api.x.com/order?symbol=BTCUSDT&recvWindow=500"
+        );
+
+        let request = RecvWindow::BITMEX
+            .inject(
+                reqwest::Client::new().post("https://api.x.com/order"),
+                timestamp,
+                Duration::from_secs(60),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(request.headers()["api-expires"], "1700000060");
+
+        // Sub-second windows round up rather than expiring immediately
+        assert_eq!(
+            RecvWindow::BITMEX.value(timestamp, Duration::from_millis(500)),
+            "1700000001"
+        );
+        assert_eq!(
+            RecvWindow::BITMEX.value(
+                timestamp + chrono::Duration::milliseconds(600),
+                Duration::from_millis(500)
+            ),
+            "1700000002"
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use super::RequestSigner;
+use crate::{
+    error::SocketError,
+    protocol::{
+        http::{rest::RestRequest, BuildStrategy},
+        websocket::{auth::WsAuthenticator, WsMessage},
+    },
+};
+use std::sync::{Arc, RwLock};
+use tracing::info;
+
+/// Hot swappable credentials holder (eg/ a [`RequestSigner`] or
+/// [`WsAuthenticator`]), allowing API keys to be rotated at runtime in long-running services
+/// without rebuilding any clients.
+///
+/// Every request is built using a consistent snapshot, so a rotation is atomic with respect to
+/// in-flight requests.
+///
+/// Cheaply cloneable, with every clone sharing the same credentials. eg/ pass one clone to a
+/// [`RestClient`](super::super::rest::client::RestClient) & keep another for rotation.
+#[derive(Debug)]
+pub struct Rotating<T> {
+    current: Arc<RwLock<Arc<T>>>,
+}
+
+impl<T> Clone for Rotating<T> {
+    fn clone(&self) -> Self {
+        Self {
+            current: Arc::clone(&self.current),
+        }
+    }
+}
+
+impl<T> Rotating<T> {
+    /// Construct a new [`Rotating`] holding the provided initial credentials.
+    pub fn new(value: T) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(value))),
+        }
+    }
+
+    /// Snapshot of the current credentials.
+    pub fn load(&self) -> Arc<T> {
+        Arc::clone(&self.current.read().expect("Rotating RwLock poisoned"))
+    }
+
+    /// Atomically replace the current credentials with the provided credentials.
+    pub fn rotate(&self, value: T) {
+        *self.current.write().expect("Rotating RwLock poisoned") = Arc::new(value);
+        info!("rotated credentials");
+    }
+}
+
+impl<Sig, Hmac, SigEncoder> Rotating<RequestSigner<Sig, Hmac, SigEncoder>>
+where
+    SigEncoder: Clone,
+{
+    /// Atomically replace the API specific [`Signer`](super::Signer) (eg/ holding the api key) &
+    /// signing secret, keeping the current signature encoder & placement.
+    pub fn rotate_credentials(&self, signer: Sig, mac: Hmac) {
+        let current = self.load();
+        self.rotate(RequestSigner {
+            signer,
+            mac,
+            encoder: current.encoder.clone(),
+            placement: current.placement,
+        });
+    }
+}
+
+impl<Strategy> BuildStrategy for Rotating<Strategy>
+where
+    Strategy: BuildStrategy,
+{
+    fn build<Request>(
+        &self,
+        request: Request,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Request, SocketError>
+    where
+        Request: RestRequest,
+    {
+        self.load().build(request, builder)
+    }
+
+    fn inject_idempotency_key(
+        &self,
+        builder: reqwest::RequestBuilder,
+        key: &str,
+    ) -> reqwest::RequestBuilder {
+        self.load().inject_idempotency_key(builder, key)
+    }
+
+    fn injects_idempotency_key(&self) -> bool {
+        self.load().injects_idempotency_key()
+    }
+}
+
+impl<Auth> WsAuthenticator for Rotating<Auth>
+where
+    Auth: WsAuthenticator,
+{
+    fn auth_message(&self) -> Result<WsMessage, SocketError> {
+        self.load().auth_message()
+    }
+
+    fn auth_response(&self, message: &WsMessage) -> Option<Result<(), SocketError>> {
+        self.load().auth_response(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        protocol::http::private::encoder::HexEncoder,
+        test_util::{Account, PayloadSigner},
+    };
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    #[test]
+    fn test_rotating_request_signer() {
+        let mac = |secret: &[u8]| Hmac::<Sha256>::new_from_slice(secret).unwrap();
+        let strategy = Rotating::new(RequestSigner::new(
+            PayloadSigner("old"),
+            mac(b"old"),
+            HexEncoder,
+        ));
+        let handle = strategy.clone();
+
+        let client = reqwest::Client::new();
+        let builder = || client.get("https://api.x.com/account");
+
+        let old = strategy.build(Account, builder()).unwrap();
+        assert_eq!(old.headers()["x-payload"], "old");
+
+        handle.rotate_credentials(PayloadSigner("new"), mac(b"new"));
+
+        let new = strategy.build(Account, builder()).unwrap();
+        assert_eq!(new.headers()["x-payload"], "new");
+        assert_ne!(new.headers()["signature"], old.headers()["signature"]);
+    }
+}
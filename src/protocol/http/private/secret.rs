@@ -0,0 +1,92 @@
+use serde::{Deserialize, Deserializer};
+use std::fmt::{Debug, Display, Formatter};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// API secret or passphrase used by a [`Signer`](super::Signer), that is zeroised on drop &
+/// redacted when formatted, such that credentials do not leak into logs or core dumps.
+///
+/// eg/ `Hmac::<Sha256>::new_from_slice(secret.expose().as_bytes())`
+#[derive(Clone, Eq, PartialEq, Default)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Construct a new [`Secret`] from the provided secret value.
+    pub fn new<S>(secret: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self(secret.into())
+    }
+
+    /// Expose the secret value, eg/ to initialise a [`Mac`](hmac::Mac) or sign a payload.
+    ///
+    /// The returned value must never be logged or otherwise persisted.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Secret(***)")
+    }
+}
+
+impl Display for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("***")
+    }
+}
+
+impl From<String> for Secret {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Zeroize for Secret {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for Secret {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_redacted() {
+        let secret = serde_json::from_str::<Secret>(r#""s3cr3t""#).unwrap();
+
+        assert_eq!(secret.expose(), "s3cr3t");
+        assert_eq!(format!("{secret:?}"), "Secret(***)");
+        assert_eq!(secret.to_string(), "***");
+
+        let mut secret = secret;
+        secret.zeroize();
+        assert_eq!(secret.expose(), "");
+    }
+}
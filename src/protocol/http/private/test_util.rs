@@ -0,0 +1,184 @@
+use super::{
+    algorithm::{SignatureAlgorithm, SigningBytes},
+    encoder::Encoder,
+    nonce::NonceProvider,
+    Signer,
+};
+use crate::error::SocketError;
+use std::fmt::{Debug, Formatter};
+
+/// Known-good signature of a [`Signer`], typically taken from the API documentation, used to
+/// validate a new exchange signer without hitting the live API.
+///
+/// The [`Signer::Config`] is constructed directly with a fixed timestamp / nonce (eg/ via
+/// [`FixedNonce`]), rather than generated by [`Signer::config`].
+pub struct SignerVector<'a, Sig>
+where
+    Sig: Signer + 'a,
+{
+    pub name: &'static str,
+    pub config: Sig::Config<'a>,
+    /// Expected bytes to sign added by [`Signer::add_bytes_to_sign`], if documented.
+    pub payload: Option<&'static str>,
+    /// Expected encoded signature.
+    pub signature: &'static str,
+}
+
+impl<'a, Sig> Debug for SignerVector<'a, Sig>
+where
+    Sig: Signer + 'a,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SignerVector")
+            .field("name", &self.name)
+            .field("payload", &self.payload)
+            .field("signature", &self.signature)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Assert the [`Signer`] generates the expected payload & signature of every [`SignerVector`]
+/// using the provided [`SignatureAlgorithm`] & [`Encoder`].
+///
+/// Panics with the name of the failing vector, and the payload that was actually signed, since
+/// a payload mismatch is the most common cause of an invalid signature.
+///
+/// eg/ `assert_signer_vectors(&Hmac::<Sha256>::new_from_slice(secret)?, &HexEncoder, vectors)`
+pub fn assert_signer_vectors<'a, Sig, Algorithm, SigEncoder, Vectors>(
+    algorithm: &Algorithm,
+    encoder: &SigEncoder,
+    vectors: Vectors,
+) where
+    Sig: Signer + 'a,
+    Algorithm: SignatureAlgorithm,
+    SigEncoder: Encoder,
+    Vectors: IntoIterator<Item = SignerVector<'a, Sig>>,
+{
+    for vector in vectors {
+        let payload = SigningBytes::collect::<Sig>(&vector.config).0;
+        let payload = String::from_utf8_lossy(&payload);
+
+        if let Some(expected) = vector.payload {
+            assert_eq!(
+                payload, expected,
+                "SignerVector {} signed an unexpected payload",
+                vector.name
+            );
+        }
+
+        let signature = algorithm
+            .sign::<Sig>(&vector.config)
+            .map(|signature| encoder.encode(signature))
+            .unwrap_or_else(|error| panic!("SignerVector {} failed to sign: {error}", vector.name));
+
+        assert_eq!(
+            signature, vector.signature,
+            "SignerVector {} generated an unexpected signature of payload: {payload}",
+            vector.name
+        );
+    }
+}
+
+/// [`NonceProvider`] that always generates the same nonce, for reproducing [`SignerVector`]s of
+/// nonce based signers.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FixedNonce(pub u64);
+
+impl NonceProvider for FixedNonce {
+    fn next(&self) -> Result<u64, SocketError> {
+        Ok(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::http::private::encoder::HexEncoder;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    /// Binance style [`Signer`] signing the query string.
+    struct QuerySigner;
+
+    struct QueryConfig {
+        query: &'static str,
+        timestamp: u64,
+    }
+
+    impl Signer for QuerySigner {
+        type Config<'a> = QueryConfig;
+
+        fn config<'a, Request>(
+            &'a self,
+            _: Request,
+            _: &reqwest::RequestBuilder,
+        ) -> Result<Self::Config<'a>, SocketError> {
+            Err(SocketError::InvalidConfig(
+                "SignerVector configs are constructed directly".to_string(),
+            ))
+        }
+
+        fn add_bytes_to_sign<M>(mac: &mut M, config: &Self::Config<'_>)
+        where
+            M: Mac,
+        {
+            mac.update(config.query.as_bytes());
+            mac.update(format!("&timestamp={}", config.timestamp).as_bytes());
+        }
+
+        fn build_signed_request(
+            _: Self::Config<'_>,
+            builder: reqwest::RequestBuilder,
+            _: String,
+        ) -> Result<reqwest::Request, SocketError> {
+            builder.build().map_err(SocketError::from)
+        }
+    }
+
+    /// Binance API documentation HMAC SHA256 example.
+    fn binance_vector(signature: &'static str) -> SignerVector<'static, QuerySigner> {
+        SignerVector {
+            name: "binance_new_order",
+            config: QueryConfig {
+                query: "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1\
+                        &price=0.1&recvWindow=5000",
+                timestamp: FixedNonce(1499827319559).next().unwrap(),
+            },
+            payload: Some(
+                "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1\
+                 &recvWindow=5000&timestamp=1499827319559",
+            ),
+            signature,
+        }
+    }
+
+    fn binance_mac() -> Hmac<Sha256> {
+        Hmac::<Sha256>::new_from_slice(
+            b"NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_assert_signer_vectors() {
+        assert_signer_vectors(
+            &binance_mac(),
+            &HexEncoder,
+            [binance_vector(
+                "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71",
+            )],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "SignerVector binance_new_order generated an unexpected signature")]
+    fn test_assert_signer_vectors_signature_mismatch() {
+        assert_signer_vectors(
+            &binance_mac(),
+            &HexEncoder,
+            [binance_vector(
+                "0000000000000000000000000000000000000000000000000000000000000000",
+            )],
+        );
+    }
+}
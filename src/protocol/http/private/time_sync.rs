@@ -0,0 +1,125 @@
+use crate::protocol::http::{
+    rest::{client::RestClient, RestRequest},
+    BuildStrategy, HttpParser,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use std::{
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tracing::{debug, warn};
+
+/// Server time response of an exchange's server-time endpoint.
+///
+/// eg/ Binance `GET /api/v3/time` returns `{"serverTime": 1499827319559}`.
+pub trait ServerTime {
+    fn server_time(&self) -> DateTime<Utc>;
+}
+
+/// Tracks the offset between the local clock & an exchange's server clock, such that signed
+/// request timestamps (and any `recvWindow`) remain valid when the local clock drifts.
+///
+/// [`Signer`](super::Signer)s should hold a clone & use [`TimeSync::now`] when constructing
+/// their [`Signer::Config`](super::Signer::Config).
+///
+/// Cheaply cloneable, with every clone sharing the same offset.
+#[derive(Debug, Clone, Default)]
+pub struct TimeSync {
+    offset_ms: Arc<AtomicI64>,
+}
+
+impl TimeSync {
+    /// Construct a new [`TimeSync`] with no offset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current time according to the server clock.
+    pub fn now(&self) -> DateTime<Utc> {
+        Utc::now() + self.offset()
+    }
+
+    /// Offset of the server clock relative to the local clock.
+    pub fn offset(&self) -> TimeDelta {
+        TimeDelta::milliseconds(self.offset_ms.load(Ordering::Relaxed))
+    }
+
+    /// Update the offset using the provided server time, observed by a request sent & received
+    /// at the provided local times.
+    ///
+    /// The server time is assumed to be generated halfway through the round trip.
+    pub fn observe(&self, server: DateTime<Utc>, sent: DateTime<Utc>, received: DateTime<Utc>) {
+        let midpoint = sent + (received - sent) / 2;
+        let offset = (server - midpoint).num_milliseconds();
+        self.offset_ms.store(offset, Ordering::Relaxed);
+        debug!(offset_ms = offset, "TimeSync updated server clock offset");
+    }
+
+    /// Query the server-time endpoint once using the provided [`RestClient`] & request, updating
+    /// the offset.
+    pub async fn sync<Strategy, Parser, Request>(
+        &self,
+        client: &RestClient<Strategy, Parser>,
+        request: Request,
+    ) -> Result<TimeDelta, Parser::OutputError>
+    where
+        Strategy: BuildStrategy,
+        Parser: HttpParser,
+        Request: RestRequest,
+        Request::Response: ServerTime,
+    {
+        let sent = Utc::now();
+        let (response, _) = client.execute(request).await?;
+        self.observe(response.server_time(), sent, Utc::now());
+        Ok(self.offset())
+    }
+
+    /// Periodically query the server-time endpoint using the provided [`RestClient`] & request
+    /// generator, updating the offset. Failed queries are logged & retried at the next interval.
+    ///
+    /// Never returns, so should be spawned onto a runtime. The interval is timed using the
+    /// [`RestClient::runtime`].
+    pub async fn run<Strategy, Parser, Request, FnRequest>(
+        self,
+        client: Arc<RestClient<Strategy, Parser>>,
+        request: FnRequest,
+        interval: Duration,
+    ) where
+        Strategy: BuildStrategy,
+        Parser: HttpParser,
+        Parser::OutputError: std::fmt::Debug,
+        Request: RestRequest,
+        Request::Response: ServerTime,
+        FnRequest: Fn() -> Request,
+    {
+        loop {
+            if let Err(error) = self.sync(&client, request()).await {
+                warn!(?error, "TimeSync failed to query server time");
+            }
+            client.runtime.sleep(interval).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_sync_observe() {
+        let time_sync = TimeSync::new();
+        let sent = DateTime::<Utc>::from_timestamp_millis(1_000_000).unwrap();
+        let received = sent + TimeDelta::milliseconds(100);
+
+        // Server clock is 2s ahead of the round trip midpoint
+        let server = sent + TimeDelta::milliseconds(2050);
+        time_sync.clone().observe(server, sent, received);
+
+        assert_eq!(time_sync.offset(), TimeDelta::milliseconds(2000));
+        let drift = (time_sync.now() - Utc::now()) - TimeDelta::milliseconds(2000);
+        assert!(drift.num_milliseconds().abs() < 100);
+    }
+}
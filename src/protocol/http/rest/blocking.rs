@@ -0,0 +1,96 @@
+use crate::{
+    error::SocketError,
+    metric::Metric,
+    protocol::http::{
+        rest::{
+            client::{ResponseMeta, RestClient},
+            RestRequest,
+        },
+        BuildStrategy, HttpParser,
+    },
+};
+
+/// Synchronous facade over a [`RestClient`], executing requests on a dedicated single threaded
+/// runtime.
+///
+/// Useful for CLI tools & scripts that do not want to manage an async runtime just to execute a
+/// few (signed) requests.
+///
+/// Methods block the current thread, and so panic if called from within an async runtime.
+#[derive(Debug)]
+pub struct BlockingRestClient<Strategy, Parser> {
+    client: RestClient<Strategy, Parser>,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl<Strategy, Parser> BlockingRestClient<Strategy, Parser>
+where
+    Strategy: BuildStrategy,
+    Parser: HttpParser,
+{
+    /// Construct a new [`BlockingRestClient`] wrapping the provided [`RestClient`].
+    pub fn new(client: RestClient<Strategy, Parser>) -> Result<Self, SocketError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Self { client, runtime })
+    }
+
+    /// Execute the provided [`RestRequest`], blocking until the response is received.
+    pub fn execute<Request>(
+        &self,
+        request: Request,
+    ) -> Result<(Request::Response, Metric), Parser::OutputError>
+    where
+        Request: RestRequest,
+    {
+        self.runtime.block_on(self.client.execute(request))
+    }
+
+    /// Execute the provided [`RestRequest`], blocking until the response & [`ResponseMeta`] is
+    /// received.
+    pub fn execute_with_meta<Request>(
+        &self,
+        request: Request,
+    ) -> Result<(Request::Response, ResponseMeta), Parser::OutputError>
+    where
+        Request: RestRequest,
+    {
+        self.runtime
+            .block_on(self.client.execute_with_meta(request))
+    }
+
+    /// Reference to the wrapped [`RestClient`].
+    pub fn client(&self) -> &RestClient<Strategy, Parser> {
+        &self.client
+    }
+
+    /// Consume the [`BlockingRestClient`], returning the wrapped [`RestClient`].
+    pub fn into_inner(self) -> RestClient<Strategy, Parser> {
+        self.client
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        protocol::http::public::PublicNoHeaders,
+        test_util::{ok, MockServer, Ping, TestParser},
+    };
+
+    #[test]
+    fn test_blocking_rest_client() {
+        let server = MockServer::responses([ok("{}")]);
+
+        let client = BlockingRestClient::new(RestClient::new(
+            server.base_url.clone(),
+            PublicNoHeaders,
+            TestParser,
+        ))
+        .unwrap();
+        let (response, _) = client.execute(Ping).unwrap();
+        assert_eq!(response, serde_json::json!({}));
+    }
+}
@@ -0,0 +1,407 @@
+use crate::{
+    audit::AuditLog,
+    error::SocketError,
+    metric::MetricSink,
+    protocol::{
+        http::rest::{
+            cache::ResponseCache,
+            circuit::CircuitBreaker,
+            client::RestClient,
+            inflight::{ClientScope, InflightCoalescer},
+            logging::RequestLogger,
+            rate_limit::RateLimiter,
+            retry::RetryPolicy,
+            usage::RateLimitParser,
+            DEFAULT_HTTP_REQUEST_TIMEOUT,
+        },
+        identity::ClientIdentity,
+        proxy::ProxyConfig,
+    },
+    runtime::{Runtime, TokioRuntime},
+};
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Formatter},
+    sync::Arc,
+    time::Duration,
+};
+
+/// Builder for a [`RestClient`], with defaults for the underlying [`reqwest::Client`].
+///
+/// Request timeouts are configured per [`RestRequest`](super::RestRequest) via
+/// [`RestRequest::timeout`](super::RestRequest::timeout).
+///
+/// eg/ `RestClient::builder(base_url, strategy, parser).user_agent("barter/1.0").build()?`
+#[derive(Debug)]
+pub struct RestClientBuilder<Strategy, Parser> {
+    base_url: Cow<'static, str>,
+    strategy: Strategy,
+    parser: Parser,
+    http_client: Option<reqwest::Client>,
+    configure: Option<ConfigureClient>,
+    connect_timeout: Duration,
+    identity: ClientIdentity,
+    proxy: Option<ProxyConfig>,
+    audit: Option<AuditLog>,
+    rate_limiter: Option<RateLimiter>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limit_parser: Option<Arc<dyn RateLimitParser>>,
+    logger: Option<RequestLogger>,
+    metric_sink: Option<Arc<dyn MetricSink>>,
+    metrics: bool,
+    scope: ClientScope,
+    coalescer: Option<InflightCoalescer>,
+    cache: Option<ResponseCache>,
+    circuit_breaker: Option<CircuitBreaker>,
+    runtime: Arc<dyn Runtime>,
+    #[cfg(feature = "gzip")]
+    gzip: bool,
+    #[cfg(feature = "brotli")]
+    brotli: bool,
+}
+
+impl<Strategy, Parser> RestClientBuilder<Strategy, Parser> {
+    /// Construct a new [`RestClientBuilder`] using the provided base Url, build strategy &
+    /// parser.
+    pub fn new<Url>(base_url: Url, strategy: Strategy, parser: Parser) -> Self
+    where
+        Url: Into<Cow<'static, str>>,
+    {
+        Self {
+            base_url: base_url.into(),
+            strategy,
+            parser,
+            http_client: None,
+            configure: None,
+            connect_timeout: DEFAULT_HTTP_REQUEST_TIMEOUT,
+            identity: ClientIdentity::default(),
+            proxy: None,
+            audit: None,
+            rate_limiter: None,
+            retry_policy: None,
+            rate_limit_parser: None,
+            logger: None,
+            metric_sink: None,
+            metrics: true,
+            scope: ClientScope::unique(),
+            coalescer: None,
+            cache: None,
+            circuit_breaker: None,
+            runtime: Arc::new(TokioRuntime),
+            #[cfg(feature = "gzip")]
+            gzip: true,
+            #[cfg(feature = "brotli")]
+            brotli: true,
+        }
+    }
+
+    /// Use the provided [`reqwest::Client`] (eg/ shared with other components, or with custom
+    /// TLS configuration).
+    ///
+    /// An injected [`reqwest::Client`] is used as is, so the connect timeout, user agent,
+    /// [`ClientIdentity`] & [`ProxyConfig`] are not applied to it.
+    pub fn http_client(self, http_client: reqwest::Client) -> Self {
+        Self {
+            http_client: Some(http_client),
+            ..self
+        }
+    }
+
+    /// Customise the [`reqwest::ClientBuilder`] after the defaults have been applied.
+    ///
+    /// eg/ Connection pool size, keep-alive, HTTP/2 or local bind address:
+    /// `.configure_client(|builder| builder.pool_max_idle_per_host(8).tcp_keepalive(Duration::from_secs(30)))`
+    ///
+    /// Ignored if a [`reqwest::Client`] is injected via [`http_client`](Self::http_client).
+    pub fn configure_client<F>(self, configure: F) -> Self
+    where
+        F: FnOnce(reqwest::ClientBuilder) -> reqwest::ClientBuilder + Send + 'static,
+    {
+        Self {
+            configure: Some(ConfigureClient(Box::new(configure))),
+            ..self
+        }
+    }
+
+    /// Maximum [`Duration`] allowed to establish a connection. Defaults to 5 seconds.
+    pub fn connect_timeout(self, connect_timeout: Duration) -> Self {
+        Self {
+            connect_timeout,
+            ..self
+        }
+    }
+
+    /// `User-Agent` header sent with every request.
+    pub fn user_agent<S>(mut self, user_agent: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.identity.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// [`ClientIdentity`] headers sent with every request, replacing any configured
+    /// `User-Agent`.
+    ///
+    /// Applied alongside every other [`reqwest::ClientBuilder`] setting (eg/ proxy, connect
+    /// timeout, decompression & [`configure_client`](Self::configure_client)).
+    pub fn identity(self, identity: ClientIdentity) -> Self {
+        Self { identity, ..self }
+    }
+
+    /// Route every request through the provided [`ProxyConfig`].
+    pub fn proxy(self, proxy: ProxyConfig) -> Self {
+        Self {
+            proxy: Some(proxy),
+            ..self
+        }
+    }
+
+    /// Record every executed request to the provided [`AuditLog`].
+    pub fn audit_log(self, audit: AuditLog) -> Self {
+        Self {
+            audit: Some(audit),
+            ..self
+        }
+    }
+
+    /// Await capacity from the provided [`RateLimiter`] before executing every request.
+    pub fn rate_limiter(self, rate_limiter: RateLimiter) -> Self {
+        Self {
+            rate_limiter: Some(rate_limiter),
+            ..self
+        }
+    }
+
+    /// Apply the provided [`RetryPolicy`] to transient request failures.
+    pub fn retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy: Some(retry_policy),
+            ..self
+        }
+    }
+
+    /// Extract the rate limit usage of every response using the provided [`RateLimitParser`].
+    pub fn rate_limit_parser<RateParser>(self, parser: RateParser) -> Self
+    where
+        RateParser: RateLimitParser + 'static,
+    {
+        Self {
+            rate_limit_parser: Some(Arc::new(parser)),
+            ..self
+        }
+    }
+
+    /// Log every request & response using the provided [`RequestLogger`], redacting secrets.
+    pub fn request_logger(self, logger: RequestLogger) -> Self {
+        Self {
+            logger: Some(logger),
+            ..self
+        }
+    }
+
+    /// Send the Http request duration [`Metric`](crate::metric::Metric) of every response to the
+    /// provided [`MetricSink`].
+    pub fn metric_sink<Sink>(self, sink: Sink) -> Self
+    where
+        Sink: MetricSink + 'static,
+    {
+        Self {
+            metric_sink: Some(Arc::new(sink)),
+            ..self
+        }
+    }
+
+    /// Skip Http request duration [`Metric`](crate::metric::Metric) measurement entirely.
+    pub fn without_metrics(self) -> Self {
+        Self {
+            metrics: false,
+            ..self
+        }
+    }
+
+    /// Identify the client's credentials with the provided [`ClientScope`], sharing coalesced &
+    /// cached responses with other clients of the same scope.
+    pub fn client_scope(self, scope: ClientScope) -> Self {
+        Self { scope, ..self }
+    }
+
+    /// Share one in-flight request & response between concurrent identical idempotent requests.
+    pub fn inflight_coalescer(self, coalescer: InflightCoalescer) -> Self {
+        Self {
+            coalescer: Some(coalescer),
+            ..self
+        }
+    }
+
+    /// Serve successful idempotent responses from the provided [`ResponseCache`].
+    pub fn response_cache(self, cache: ResponseCache) -> Self {
+        Self {
+            cache: Some(cache),
+            ..self
+        }
+    }
+
+    /// Short-circuit requests to failing endpoints using the provided [`CircuitBreaker`].
+    pub fn circuit_breaker(self, circuit_breaker: CircuitBreaker) -> Self {
+        Self {
+            circuit_breaker: Some(circuit_breaker),
+            ..self
+        }
+    }
+
+    /// Use the provided [`Runtime`] for retry backoff timers & background tasks, rather than
+    /// tokio.
+    pub fn runtime<R>(self, runtime: R) -> Self
+    where
+        R: Runtime + 'static,
+    {
+        Self {
+            runtime: Arc::new(runtime),
+            ..self
+        }
+    }
+
+    /// Transparently decompress gzip encoded responses. Enabled by default.
+    ///
+    /// Response size [`Metric`](crate::metric::Metric) fields reflect the decompressed size.
+    #[cfg(feature = "gzip")]
+    pub fn gzip(self, gzip: bool) -> Self {
+        Self { gzip, ..self }
+    }
+
+    /// Transparently decompress brotli encoded responses. Enabled by default.
+    ///
+    /// Response size [`Metric`](crate::metric::Metric) fields reflect the decompressed size.
+    #[cfg(feature = "brotli")]
+    pub fn brotli(self, brotli: bool) -> Self {
+        Self { brotli, ..self }
+    }
+
+    /// Build the configured [`RestClient`].
+    pub fn build(self) -> Result<RestClient<Strategy, Parser>, SocketError> {
+        let http_client = match self.http_client {
+            Some(http_client) => http_client,
+            None => {
+                let mut builder = reqwest::Client::builder()
+                    .connect_timeout(self.connect_timeout)
+                    .default_headers(self.identity.header_map()?);
+
+                if let Some(proxy) = &self.proxy {
+                    builder = builder.proxy(proxy.reqwest_proxy()?);
+                }
+
+                #[cfg(feature = "gzip")]
+                {
+                    builder = builder.gzip(self.gzip);
+                }
+                #[cfg(feature = "brotli")]
+                {
+                    builder = builder.brotli(self.brotli);
+                }
+
+                match self.configure {
+                    Some(ConfigureClient(configure)) => configure(builder),
+                    None => builder,
+                }
+                .build()
+                .map_err(SocketError::Http)?
+            }
+        };
+
+        Ok(RestClient {
+            http_client,
+            base_url: self.base_url,
+            strategy: Arc::new(self.strategy),
+            parser: Arc::new(self.parser),
+            audit: self.audit,
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy,
+            rate_limit_parser: self.rate_limit_parser,
+            logger: self.logger,
+            metric_sink: self.metric_sink,
+            metrics: self.metrics,
+            scope: self.scope,
+            coalescer: self.coalescer,
+            cache: self.cache,
+            circuit_breaker: self.circuit_breaker,
+            runtime: self.runtime,
+        })
+    }
+}
+
+/// Caller provided [`reqwest::ClientBuilder`] customisation.
+struct ConfigureClient(Box<dyn FnOnce(reqwest::ClientBuilder) -> reqwest::ClientBuilder + Send>);
+
+impl Debug for ConfigureClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("ConfigureClient").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        protocol::http::public::PublicNoHeaders,
+        test_util::{ok, MockServer, Ping, TestParser},
+    };
+
+    #[test]
+    fn test_rest_client_builder() {
+        let base_url = String::from("https://api.x.com");
+        let client = RestClient::builder(base_url, PublicNoHeaders, ())
+            .user_agent("barter/1.0")
+            .retry_policy(RetryPolicy::default())
+            .configure_client(|builder| builder.pool_max_idle_per_host(8))
+            .proxy(ProxyConfig::new("http://eu-egress:3128").with_auth("user", "pass"))
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url, "https://api.x.com");
+        assert!(client.retry_policy.is_some());
+
+        let invalid = RestClient::builder("https://api.x.com", PublicNoHeaders, ())
+            .identity(ClientIdentity::default().with_header("X Broker", "barter"))
+            .build();
+        assert!(matches!(invalid, Err(SocketError::InvalidHeader(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rest_client_builder_identity() {
+        let server = MockServer::responses([ok("{}")]);
+
+        // Identity headers are applied alongside the configured reqwest::ClientBuilder settings
+        let client = RestClient::builder(server.base_url.clone(), PublicNoHeaders, TestParser)
+            .identity(ClientIdentity::new("barter/1.0").with_header("X-Broker-Id", "barter"))
+            .configure_client(|builder| {
+                builder.default_headers(reqwest::header::HeaderMap::from_iter([(
+                    reqwest::header::HeaderName::from_static("x-configured"),
+                    reqwest::header::HeaderValue::from_static("true"),
+                )]))
+            })
+            .build()
+            .unwrap();
+        client.execute(Ping).await.unwrap();
+
+        let request = server.next_request().to_lowercase();
+        assert!(request.contains("user-agent: barter/1.0"));
+        assert!(request.contains("x-broker-id: barter"));
+        assert!(request.contains("x-configured: true"));
+    }
+
+    #[tokio::test]
+    async fn test_rest_client_builder_proxy() {
+        let proxy = MockServer::responses([ok("{}")]);
+
+        // Requests are sent to the proxy using the absolute target Url, with basic authentication
+        let client = RestClient::builder("http://api.x.invalid", PublicNoHeaders, TestParser)
+            .proxy(ProxyConfig::new(proxy.base_url.clone()).with_auth("user", "pass"))
+            .build()
+            .unwrap();
+        client.execute(Ping).await.unwrap();
+
+        let request = proxy.next_request().to_lowercase();
+        assert!(request.starts_with("get http://api.x.invalid/ping http/1.1"));
+        assert!(request.contains("proxy-authorization: basic dxnlcjpwyxnz"));
+    }
+}
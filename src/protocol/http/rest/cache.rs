@@ -0,0 +1,117 @@
+use crate::protocol::http::rest::{client::ResponseMeta, inflight::InflightKey};
+use bytes::Bytes;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Opt-in cache of successful idempotent request responses, keyed by request identity
+/// ([`InflightKey`]), with a per-request TTL determined by
+/// [`RestRequest::cache_ttl`](super::RestRequest::cache_ttl).
+///
+/// Request identity includes the executing client's
+/// [`ClientScope`](super::inflight::ClientScope), so a cache shared between clients of different
+/// credentials never serves one account's response to another.
+///
+/// Repeated requests for rarely changing resources (eg/ exchange info) are served locally, so
+/// rate limit budget is preserved for trading requests.
+///
+/// Cheaply cloneable, with every clone sharing the same cached responses.
+#[derive(Debug, Clone, Default)]
+pub struct ResponseCache {
+    entries: Arc<Mutex<HashMap<InflightKey, CacheEntry>>>,
+}
+
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    expires: Instant,
+    response: (ResponseMeta, Bytes),
+}
+
+impl ResponseCache {
+    /// Construct a new empty [`ResponseCache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the unexpired cached response of the provided request, if any.
+    pub fn get(&self, key: &InflightKey) -> Option<(ResponseMeta, Bytes)> {
+        let mut entries = self.lock();
+        match entries.get(key) {
+            Some(entry) if entry.expires > Instant::now() => Some(entry.response.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Cache the response of the provided request for the provided TTL, pruning any expired
+    /// responses.
+    pub fn insert(&self, key: InflightKey, ttl: Duration, response: (ResponseMeta, Bytes)) {
+        let now = Instant::now();
+        let mut entries = self.lock();
+        entries.retain(|_, entry| entry.expires > now);
+        entries.insert(
+            key,
+            CacheEntry {
+                expires: now + ttl,
+                response,
+            },
+        );
+    }
+
+    /// Remove the cached response of the provided request, if any.
+    pub fn invalidate(&self, key: &InflightKey) {
+        self.lock().remove(key);
+    }
+
+    /// Remove every cached response.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<InflightKey, CacheEntry>> {
+        self.entries.lock().expect("ResponseCache Mutex poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{metric::Metric, protocol::http::rest::inflight::ClientScope};
+
+    #[test]
+    fn test_response_cache() {
+        let cache = ResponseCache::new();
+        let key = InflightKey {
+            scope: ClientScope::unique(),
+            method: reqwest::Method::GET,
+            url: "https://api.x.com/exchangeInfo".to_string(),
+            headers: Vec::new(),
+            account_id: None,
+        };
+        let response = (
+            ResponseMeta {
+                status: reqwest::StatusCode::OK,
+                headers: reqwest::header::HeaderMap::new(),
+                latency: Metric::empty("http_request_duration"),
+                rate_limit: None,
+                idempotency_key: None,
+            },
+            Bytes::from_static(b"{}"),
+        );
+
+        cache.insert(key.clone(), Duration::from_secs(60), response.clone());
+        assert_eq!(cache.get(&key).unwrap().1, response.1);
+
+        cache.invalidate(&key);
+        assert!(cache.get(&key).is_none());
+
+        // Expired responses are not served
+        cache.insert(key.clone(), Duration::ZERO, response);
+        assert!(cache.get(&key).is_none());
+    }
+}
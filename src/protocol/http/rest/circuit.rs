@@ -0,0 +1,391 @@
+use crate::{
+    error::SocketError,
+    protocol::http::rest::{
+        retry::{is_transient, Attempt},
+        RestRequest,
+    },
+};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, VecDeque},
+    fmt::{Debug, Display, Formatter},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// Endpoint (method & path) that a [`CircuitBreaker`] tracks an independent circuit for.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct CircuitKey {
+    pub method: reqwest::Method,
+
+    /// Endpoint identifier, independent of any path parameters or query string.
+    ///
+    /// eg/ "/api/v3/order/{order_id}", rather than one circuit per order id.
+    pub path: Cow<'static, str>,
+}
+
+impl CircuitKey {
+    /// Construct the [`CircuitKey`] of the provided [`RestRequest`], keyed by its
+    /// [`rate_limit_key`](RestRequest::rate_limit_key) or
+    /// [`path_template`](RestRequest::path_template) if provided, otherwise its
+    /// [`path`](RestRequest::path) excluding any query string.
+    pub fn from_request<Request>(request: &Request) -> Self
+    where
+        Request: RestRequest,
+    {
+        let path = match (request.rate_limit_key(), request.path_template()) {
+            (Some(key), _) => Cow::Borrowed(key),
+            (None, Some(template)) => Cow::Borrowed(template.template()),
+            (None, None) => match request.path() {
+                Cow::Borrowed(path) => Cow::Borrowed(path.split('?').next().unwrap_or_default()),
+                Cow::Owned(mut path) => {
+                    path.truncate(path.find('?').unwrap_or(path.len()));
+                    Cow::Owned(path)
+                }
+            },
+        };
+
+        Self {
+            method: request.method(),
+            path,
+        }
+    }
+}
+
+impl Display for CircuitKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.method, self.path)
+    }
+}
+
+/// State of an endpoint circuit.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum CircuitState {
+    /// Requests are executed as normal.
+    Closed,
+    /// Requests are short-circuited with a [`SocketError::CircuitOpen`] until the provided
+    /// [`Instant`].
+    Open { until: Instant },
+    /// A single probe request is in-flight, determining if the circuit should close or re-open.
+    HalfOpen { probed_at: Instant },
+}
+
+/// Configuration of when a [`CircuitBreaker`] trips an endpoint circuit, and for how long.
+#[derive(Copy, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Number of consecutive failures that trips the circuit.
+    pub failure_threshold: u32,
+
+    /// Optional failure rate (eg/ 0.5 for 50%) over the last [`window`](Self::window) requests
+    /// that trips the circuit.
+    pub error_rate: Option<f64>,
+
+    /// Number of most recent requests the [`error_rate`](Self::error_rate) is calculated over.
+    pub window: usize,
+
+    /// [`Duration`] the circuit remains open before a half-open probe request is allowed.
+    pub open_duration: Duration,
+
+    /// Predicate determining if the final [`Attempt`] of a request is a failure.
+    pub is_failure: fn(&Attempt<'_>) -> bool,
+}
+
+impl Debug for CircuitBreakerConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CircuitBreakerConfig")
+            .field("failure_threshold", &self.failure_threshold)
+            .field("error_rate", &self.error_rate)
+            .field("window", &self.window)
+            .field("open_duration", &self.open_duration)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            error_rate: None,
+            window: 20,
+            open_duration: Duration::from_secs(30),
+            is_failure: is_transient,
+        }
+    }
+}
+
+/// Per endpoint (method & path, see [`CircuitKey`]) circuit breaker used by a
+/// [`RestClient`](super::client::RestClient) to stop hammering a failing venue.
+///
+/// After the configured consecutive failures (or failure rate), the endpoint circuit opens and
+/// requests are short-circuited with a [`SocketError::CircuitOpen`]. Once the
+/// [`open_duration`](CircuitBreakerConfig::open_duration) has elapsed, a single half-open probe
+/// request is allowed, closing the circuit if it succeeds, or re-opening it if it fails.
+///
+/// Cheaply cloneable, with every clone sharing the same circuits.
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    circuits: Arc<Mutex<HashMap<CircuitKey, Circuit>>>,
+}
+
+#[derive(Debug)]
+struct Circuit {
+    state: CircuitState,
+    consecutive_failures: u32,
+    outcomes: VecDeque<bool>,
+}
+
+impl Default for Circuit {
+    fn default() -> Self {
+        Self {
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            outcomes: VecDeque::new(),
+        }
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(CircuitBreakerConfig::default())
+    }
+}
+
+impl CircuitBreaker {
+    /// Construct a new [`CircuitBreaker`] using the provided [`CircuitBreakerConfig`].
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            circuits: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Current [`CircuitState`] of the provided endpoint.
+    pub fn state(&self, key: &CircuitKey) -> CircuitState {
+        self.lock()
+            .get(key)
+            .map_or(CircuitState::Closed, |circuit| circuit.state)
+    }
+
+    /// Determine if a request to the provided endpoint may be executed, transitioning an expired
+    /// open circuit to half-open & allowing this request as the probe.
+    pub fn acquire(&self, key: &CircuitKey) -> Result<(), SocketError> {
+        let mut circuits = self.lock();
+        let Some(circuit) = circuits.get_mut(key) else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        let retry_after = match circuit.state {
+            CircuitState::Closed => return Ok(()),
+            CircuitState::Open { until } => until.saturating_duration_since(now),
+            // Allow another probe if the previous probe never completed (eg/ cancelled)
+            CircuitState::HalfOpen { probed_at } => {
+                (probed_at + self.config.open_duration).saturating_duration_since(now)
+            }
+        };
+
+        if retry_after.is_zero() {
+            circuit.state = CircuitState::HalfOpen { probed_at: now };
+            Ok(())
+        } else {
+            Err(SocketError::CircuitOpen {
+                endpoint: key.to_string(),
+                retry_after,
+            })
+        }
+    }
+
+    /// Record the final [`Attempt`] of a request to the provided endpoint, tripping or closing
+    /// its circuit as required.
+    pub fn record(&self, key: &CircuitKey, attempt: &Attempt<'_>) {
+        let failure = (self.config.is_failure)(attempt);
+
+        let mut circuits = self.lock();
+        let circuit = circuits.entry(key.clone()).or_default();
+
+        if !failure {
+            if matches!(circuit.state, CircuitState::HalfOpen { .. }) {
+                *circuit = Circuit::default();
+            }
+            circuit.consecutive_failures = 0;
+            circuit.push_outcome(false, self.config.window);
+            return;
+        }
+
+        circuit.consecutive_failures += 1;
+        circuit.push_outcome(true, self.config.window);
+
+        let error_rate_exceeded = self.config.error_rate.is_some_and(|error_rate| {
+            circuit.outcomes.len() >= self.config.window && circuit.failure_rate() >= error_rate
+        });
+
+        let trip = match circuit.state {
+            CircuitState::Closed => {
+                circuit.consecutive_failures >= self.config.failure_threshold || error_rate_exceeded
+            }
+            CircuitState::HalfOpen { .. } => true,
+            CircuitState::Open { .. } => false,
+        };
+
+        if trip {
+            warn!(
+                endpoint = %key,
+                consecutive_failures = circuit.consecutive_failures,
+                open_duration = ?self.config.open_duration,
+                "circuit breaker opened"
+            );
+            circuit.state = CircuitState::Open {
+                until: Instant::now() + self.config.open_duration,
+            };
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<CircuitKey, Circuit>> {
+        self.circuits.lock().expect("CircuitBreaker Mutex poisoned")
+    }
+}
+
+impl Circuit {
+    fn push_outcome(&mut self, failure: bool, window: usize) {
+        self.outcomes.push_back(failure);
+        while self.outcomes.len() > window.max(1) {
+            self.outcomes.pop_front();
+        }
+    }
+
+    fn failure_rate(&self) -> f64 {
+        let failures = self.outcomes.iter().filter(|failure| **failure).count();
+        failures as f64 / self.outcomes.len().max(1) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::StatusCode;
+
+    fn key() -> CircuitKey {
+        CircuitKey {
+            method: reqwest::Method::GET,
+            path: Cow::Borrowed("/api/v3/ticker"),
+        }
+    }
+
+    #[test]
+    fn test_circuit_breaker_consecutive_failures() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_duration: Duration::from_millis(20),
+            ..Default::default()
+        });
+        let (key, failure, success) = (
+            key(),
+            Attempt::Status(StatusCode::SERVICE_UNAVAILABLE),
+            Attempt::Status(StatusCode::OK),
+        );
+
+        breaker.record(&key, &failure);
+        assert!(breaker.acquire(&key).is_ok());
+        breaker.record(&key, &failure);
+        assert!(matches!(
+            breaker.acquire(&key),
+            Err(SocketError::CircuitOpen { .. })
+        ));
+
+        // Half-open probe fails, re-opening the circuit
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(breaker.acquire(&key).is_ok());
+        assert!(breaker.acquire(&key).is_err());
+        breaker.record(&key, &failure);
+        assert!(matches!(breaker.state(&key), CircuitState::Open { .. }));
+
+        // Half-open probe succeeds, closing the circuit
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(breaker.acquire(&key).is_ok());
+        breaker.record(&key, &success);
+        assert_eq!(breaker.state(&key), CircuitState::Closed);
+        assert!(breaker.acquire(&key).is_ok());
+    }
+
+    #[test]
+    fn test_circuit_breaker_error_rate() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: u32::MAX,
+            error_rate: Some(0.5),
+            window: 4,
+            ..Default::default()
+        });
+        let key = key();
+
+        for status in [StatusCode::OK, StatusCode::BAD_GATEWAY, StatusCode::OK] {
+            breaker.record(&key, &Attempt::Status(status));
+        }
+        assert_eq!(breaker.state(&key), CircuitState::Closed);
+
+        breaker.record(&key, &Attempt::Status(StatusCode::BAD_GATEWAY));
+        assert!(breaker.acquire(&key).is_err());
+    }
+
+    #[test]
+    fn test_circuit_key_from_request() {
+        use crate::protocol::http::rest::path::PathTemplate;
+
+        struct Order {
+            order_id: u64,
+            rate_limit_key: Option<&'static str>,
+        }
+
+        impl RestRequest for Order {
+            type Response = ();
+            type QueryParams = ();
+            type Body = ();
+
+            fn path(&self) -> Cow<'static, str> {
+                Cow::Borrowed("/order/{order_id}")
+            }
+
+            fn path_template(&self) -> Option<PathTemplate> {
+                Some(PathTemplate::new("/order/{order_id}").param("order_id", self.order_id))
+            }
+
+            fn rate_limit_key(&self) -> Option<&'static str> {
+                self.rate_limit_key
+            }
+        }
+
+        struct ListenKey(String);
+
+        impl RestRequest for ListenKey {
+            type Response = ();
+            type QueryParams = ();
+            type Body = ();
+
+            fn path(&self) -> Cow<'static, str> {
+                Cow::Owned(format!("/userDataStream?listenKey={}", self.0))
+            }
+        }
+
+        // Every order id shares the same circuit, keyed by the path template
+        let order = |order_id, rate_limit_key| Order {
+            order_id,
+            rate_limit_key,
+        };
+        let key = CircuitKey::from_request(&order(1, None));
+        assert_eq!(key, CircuitKey::from_request(&order(2, None)));
+        assert_eq!(key.path, "/order/{order_id}");
+
+        // Rate limit key takes precedence over the path template
+        assert_eq!(
+            CircuitKey::from_request(&order(1, Some("order"))).path,
+            "order"
+        );
+
+        // Query string is excluded from plain paths
+        assert_eq!(
+            CircuitKey::from_request(&ListenKey("abc".to_string())),
+            CircuitKey::from_request(&ListenKey("def".to_string()))
+        );
+    }
+}
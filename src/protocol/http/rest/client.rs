@@ -1,24 +1,49 @@
 use crate::{
+    audit::AuditLog,
     error::SocketError,
-    metric::{Field, Metric, Tag},
-    protocol::http::{rest::RestRequest, BuildStrategy, HttpParser},
+    metric::{Field, Metric, MetricSink, Tag},
+    protocol::http::{
+        private::account::AsAccount,
+        rest::{
+            builder::RestClientBuilder,
+            cache::ResponseCache,
+            circuit::{CircuitBreaker, CircuitKey},
+            graphql::{GraphQl, GraphQlResponse},
+            inflight::{ClientScope, InflightCoalescer, InflightKey},
+            logging::RequestLogger,
+            paginate::Paginated,
+            path::render_path,
+            rate_limit::RateLimiter,
+            retry::{Attempt, RetryPolicy},
+            usage::{RateLimitParser, RateLimitUsage},
+            RestRequest,
+        },
+        BuildStrategy, HttpParser,
+    },
+    runtime::{Runtime, TokioRuntime},
 };
 use bytes::Bytes;
 use chrono::Utc;
-use std::borrow::Cow;
+use futures::{Stream, StreamExt};
+use std::{borrow::Cow, sync::Arc};
+use tracing::warn;
 
 /// Configurable REST client capable of executing signed [`RestRequest`]s. Use this when
 /// integrating APIs that require Http in order to interact with resources. Each API will require
 /// a specific combination of [`Signer`](super::super::private::Signer), [`Mac`](hmac::Mac),
 /// signature [`Encoder`](super::super::private::encoder::Encoder), and
 /// [`HttpParser`].
+///
+/// Cheaply cloneable, with every clone sharing the same configuration, connection pool, and any
+/// [`RateLimiter`], [`InflightCoalescer`], [`ResponseCache`] & [`CircuitBreaker`] state. A single
+/// configured client can therefore be handed to many tasks.
 #[derive(Debug)]
-pub struct RestClient<'a, Strategy, Parser> {
+pub struct RestClient<Strategy, Parser> {
     /// HTTP [`reqwest::Client`] for executing signed [`reqwest::Request`]s.
     pub http_client: reqwest::Client,
 
     /// Base Url of the API being interacted with.
-    pub base_url: Cow<'a, str>,
+    pub base_url: Cow<'static, str>,
 
     /// [`RestRequest`] build strategy for the API being interacted with that implements
     /// [`BuildStrategy`].
@@ -27,14 +52,75 @@ pub struct RestClient<'a, Strategy, Parser> {
     /// [`Signer`](super::super::private::Signer) logic, a hashable [`Mac`](hmac::Mac), and a
     /// signature [`Encoder`](super::super::private::encoder::Encoder). Where as a non authorised
     /// [`RestRequest`] may add any mandatory `reqwest` headers that are required.
-    pub strategy: Strategy,
+    pub strategy: Arc<Strategy>,
 
     /// [`HttpParser`] that deserialises [`RestRequest::Response`]s, and upon failure parses
     /// API errors returned from the server.
-    pub parser: Parser,
+    pub parser: Arc<Parser>,
+
+    /// Optional [`AuditLog`] that every built [`reqwest::Request`] is recorded to before it is
+    /// executed.
+    pub audit: Option<AuditLog>,
+
+    /// Optional [`RateLimiter`] that every [`RestRequest`] must acquire capacity from before it
+    /// is executed.
+    pub rate_limiter: Option<RateLimiter>,
+
+    /// Optional [`RetryPolicy`] applied to transient [`RestRequest`] failures.
+    pub retry_policy: Option<RetryPolicy>,
+
+    /// Optional [`RateLimitParser`] used to extract the [`RateLimitUsage`] of every response,
+    /// which is surfaced via the [`ResponseMeta`] and fed into the [`RateLimiter`].
+    pub rate_limit_parser: Option<Arc<dyn RateLimitParser>>,
+
+    /// Optional [`RequestLogger`] that logs every request & response with secrets redacted.
+    pub logger: Option<RequestLogger>,
+
+    /// Optional [`MetricSink`] that the Http request duration [`Metric`] of every response is
+    /// sent to, in addition to being returned to the caller.
+    pub metric_sink: Option<Arc<dyn MetricSink>>,
+
+    /// Measure the Http request duration [`Metric`] of every response.
+    ///
+    /// Disable to skip the measurement overhead entirely (eg/ latency sensitive order
+    /// placement), in which case the returned [`Metric`]s are empty.
+    pub metrics: bool,
+
+    /// [`ClientScope`] identifying the credentials requests are executed with, such that shared
+    /// [`InflightCoalescer`]s & [`ResponseCache`]s never serve responses across clients.
+    pub scope: ClientScope,
+
+    /// Optional [`InflightCoalescer`] that concurrent identical `GET` & `HEAD` requests share one
+    /// in-flight request & response via.
+    pub coalescer: Option<InflightCoalescer>,
+
+    /// Optional [`ResponseCache`] that successful `GET` & `HEAD` responses are served from,
+    /// for the [`RestRequest::cache_ttl`].
+    pub cache: Option<ResponseCache>,
+
+    /// Optional [`CircuitBreaker`] that short-circuits [`RestRequest`]s to failing endpoints.
+    pub circuit_breaker: Option<CircuitBreaker>,
+
+    /// [`Runtime`] used for retry backoff timers & any background tasks spawned on behalf of the
+    /// client (eg/ listen key keep-alive).
+    pub runtime: Arc<dyn Runtime>,
 }
 
-impl<'a, Strategy, Parser> RestClient<'a, Strategy, Parser>
+/// Metadata of an executed [`RestRequest`] response.
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    pub status: reqwest::StatusCode,
+    /// Response headers. eg/ rate limit counters, request ids, server time.
+    pub headers: reqwest::header::HeaderMap,
+    /// Http request round trip duration [`Metric`], empty if [`RestClient::metrics`] is disabled.
+    pub latency: Metric,
+    /// [`RateLimitUsage`] communicated by the server, if a [`RateLimitParser`] is configured.
+    pub rate_limit: Option<RateLimitUsage>,
+    /// [`RestRequest::idempotency_key`] sent with the request, if any.
+    pub idempotency_key: Option<String>,
+}
+
+impl<Strategy, Parser> RestClient<Strategy, Parser>
 where
     Strategy: BuildStrategy,
     Parser: HttpParser,
@@ -47,16 +133,435 @@ where
     where
         Request: RestRequest,
     {
-        // Use provided Request to construct a signed reqwest::Request
-        let request = self.build(request)?;
+        self.execute_with_meta(request)
+            .await
+            .map(|(response, meta)| (response, meta.latency))
+    }
+
+    /// Execute the provided [`RestRequest`], returning the [`ResponseMeta`] (status, headers &
+    /// latency) alongside the deserialised response.
+    ///
+    /// Response headers carry useful metadata such as rate limit counters, request ids & server
+    /// time.
+    pub async fn execute_with_meta<Request>(
+        &self,
+        request: Request,
+    ) -> Result<(Request::Response, ResponseMeta), Parser::OutputError>
+    where
+        Request: RestRequest,
+    {
+        let cache_ttl = request.cache_ttl().filter(|_| self.cache.is_some());
+        let key = self.request_key(&request, cache_ttl.is_some())?;
 
-        // Measure request execution
-        let (status, payload, latency) = self.measured_execution::<Request>(request).await?;
+        // Serve an unexpired cached response, if caching is enabled
+        let cached = match (&self.cache, cache_ttl, &key) {
+            (Some(cache), Some(_), Some(key)) => cache.get(key),
+            _ => None,
+        };
+
+        let (meta, payload) = match cached {
+            Some(cached) => cached,
+            None => {
+                // Share any identical in-flight idempotent request, if coalescing is enabled
+                let response = match (&self.coalescer, key.clone()) {
+                    (Some(coalescer), Some(key)) => {
+                        coalescer
+                            .execute(key, self.execute_payload(request))
+                            .await?
+                    }
+                    _ => self.execute_payload(request).await?,
+                };
+
+                if let (Some(cache), Some(ttl), Some(key)) = (&self.cache, cache_ttl, key) {
+                    if response.0.status.is_success() {
+                        cache.insert(key, ttl, response.clone());
+                    }
+                }
+
+                response
+            }
+        };
 
         // Attempt to parse API Success or Error response
         self.parser
-            .parse::<Request::Response>(status, &payload)
-            .map(|response| (response, latency))
+            .parse_format::<Request::Response>(meta.status, &payload, Request::response_format())
+            .map(|response| (response, meta))
+    }
+
+    /// Execute the provided [`RestRequest`], returning the [`reqwest::StatusCode`] & raw payload
+    /// bytes without deserialising the response.
+    ///
+    /// Useful for debugging new endpoints, or for responses whose schema is still unknown. The
+    /// request is still signed, rate limited, retried & measured like any other.
+    pub async fn execute_raw<Request>(
+        &self,
+        request: Request,
+    ) -> Result<(reqwest::StatusCode, Bytes), Parser::OutputError>
+    where
+        Request: RestRequest,
+    {
+        self.execute_payload(request)
+            .await
+            .map(|(meta, payload)| (meta.status, payload))
+            .map_err(Parser::OutputError::from)
+    }
+
+    /// Execute the provided [`RestRequest`], returning the [`ResponseMeta`] & payload bytes of
+    /// the final attempt, unless short-circuited by an open [`CircuitBreaker`] endpoint circuit.
+    async fn execute_payload<Request>(
+        &self,
+        request: Request,
+    ) -> Result<(ResponseMeta, Bytes), SocketError>
+    where
+        Request: RestRequest,
+    {
+        let Some(circuit_breaker) = &self.circuit_breaker else {
+            return self.execute_attempts(request).await;
+        };
+
+        let key = CircuitKey::from_request(&request);
+        circuit_breaker.acquire(&key)?;
+
+        let outcome = self.execute_attempts(request).await;
+        let attempt = match &outcome {
+            Ok((meta, _)) => Attempt::Status(meta.status),
+            Err(error) => Attempt::Error(error),
+        };
+        circuit_breaker.record(&key, &attempt);
+
+        outcome
+    }
+
+    /// Execute the provided [`RestRequest`], retrying transient failures if a [`RetryPolicy`] is
+    /// configured, returning the [`ResponseMeta`] & payload bytes of the final attempt.
+    async fn execute_attempts<Request>(
+        &self,
+        request: Request,
+    ) -> Result<(ResponseMeta, Bytes), SocketError>
+    where
+        Request: RestRequest,
+    {
+        let (rate_limit_key, weight, priority, idempotency_key) = (
+            request.rate_limit_key(),
+            request.weight(),
+            request.priority(),
+            request.idempotency_key(),
+        );
+
+        // Wait for rate limit capacity before signing, so any signed timestamp remains fresh
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire_with_priority(rate_limit_key, weight, priority)
+                .await;
+        }
+
+        // Idempotency keys only make non-idempotent requests retry-safe if the BuildStrategy
+        // actually sends them to the server to deduplicate by
+        let sent_idempotency_key = idempotency_key
+            .as_deref()
+            .filter(|_| self.strategy.injects_idempotency_key());
+        let retry_policy = self
+            .retry_policy
+            .filter(|policy| policy.allows(&request.method(), sent_idempotency_key));
+        let mut attempt = 1;
+
+        // Measure request execution, retrying transient failures if a RetryPolicy is configured
+        let (meta, payload) = loop {
+            // Construct a freshly signed reqwest::Request every attempt, such that retries never
+            // resend a stale timestamp, recvWindow or nonce
+            let signed = self.build(&request)?;
+
+            // Record exactly what is about to be sent to the venue, if auditing is enabled
+            if let Some(audit) = &self.audit {
+                audit.record_http(&signed, &*self.runtime).await?;
+            }
+
+            let outcome = self.measured_execution_with_meta(signed).await;
+
+            // Synchronise the RateLimiter with the server communicated usage
+            if let (Some(rate_limiter), Ok((meta, _))) = (&self.rate_limiter, &outcome) {
+                if let Some(usage) = &meta.rate_limit {
+                    rate_limiter.observe(rate_limit_key, usage);
+                }
+            }
+
+            let Some(policy) = retry_policy.filter(|policy| attempt < policy.max_attempts) else {
+                break outcome?;
+            };
+
+            let backoff = match &outcome {
+                Ok((meta, _)) if meta.status == reqwest::StatusCode::TOO_MANY_REQUESTS => {
+                    policy.rate_limited_backoff(attempt, &meta.headers)
+                }
+                Ok((meta, _)) => (policy.retry_on)(&Attempt::Status(meta.status))
+                    .then(|| policy.backoff(attempt)),
+                Err(error) => {
+                    (policy.retry_on)(&Attempt::Error(error)).then(|| policy.backoff(attempt))
+                }
+            };
+            let Some(backoff) = backoff else {
+                break outcome?;
+            };
+
+            warn!(
+                attempt,
+                ?backoff,
+                outcome = ?outcome.as_ref().map(|(meta, _)| meta.status),
+                "retrying transient RestRequest failure"
+            );
+            self.runtime.sleep(backoff).await;
+
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter
+                    .acquire_with_priority(rate_limit_key, weight, priority)
+                    .await;
+            }
+
+            attempt += 1;
+        };
+
+        Ok((
+            ResponseMeta {
+                idempotency_key,
+                ..meta
+            },
+            payload,
+        ))
+    }
+
+    /// Determine the [`InflightKey`] identity of the provided idempotent [`RestRequest`], if
+    /// required for coalescing or caching.
+    fn request_key<Request>(
+        &self,
+        request: &Request,
+        cached: bool,
+    ) -> Result<Option<InflightKey>, SocketError>
+    where
+        Request: RestRequest,
+    {
+        let method = request.method();
+        if !matches!(method, reqwest::Method::GET | reqwest::Method::HEAD)
+            || (self.coalescer.is_none() && !cached)
+        {
+            return Ok(None);
+        }
+
+        // Unsigned Url, since signatures & timestamps differ between identical requests
+        let mut builder = self.http_client.request(
+            method.clone(),
+            format!("{}{}", self.base_url, render_path(request)?),
+        );
+        if let Some(query_params) = request.query_params() {
+            builder = builder.query(query_params);
+        }
+        let url = builder.build()?.url().to_string();
+
+        let mut headers = request
+            .headers()
+            .into_iter()
+            .filter_map(|(name, value)| Some((name?, value)))
+            .collect::<Vec<_>>();
+        headers.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+
+        Ok(Some(InflightKey {
+            scope: self.scope,
+            method,
+            url,
+            headers,
+            account_id: request.account_id(),
+        }))
+    }
+
+    /// Execute the provided [`RestRequest`]s concurrently, with at most `concurrency` requests
+    /// in-flight at once, returning the results in the same order as the requests.
+    ///
+    /// Every request acquires capacity from the configured [`RateLimiter`], if any.
+    pub async fn execute_all<Request, Requests>(
+        &self,
+        requests: Requests,
+        concurrency: usize,
+    ) -> Vec<Result<(Request::Response, Metric), Parser::OutputError>>
+    where
+        Request: RestRequest,
+        Requests: IntoIterator<Item = Request>,
+    {
+        futures::stream::iter(requests)
+            .map(|request| self.execute(request))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Execute the provided [`RestRequest`], streaming the response body in chunks rather than
+    /// buffering it fully. Useful for large downloads (eg/ historical candle dumps).
+    ///
+    /// Unsuccessful responses are buffered and parsed as an API error. Streamed requests are
+    /// never retried, but are short-circuited by an open [`CircuitBreaker`] endpoint circuit &
+    /// measured like any other (the [`Metric`] omits the streamed response size). See
+    /// [`decode_ndjson`](super::ndjson::decode_ndjson) to decode newline delimited JSON bodies.
+    pub async fn execute_stream<Request>(
+        &self,
+        request: Request,
+    ) -> Result<impl Stream<Item = Result<Bytes, SocketError>>, Parser::OutputError>
+    where
+        Request: RestRequest,
+    {
+        let circuit = match &self.circuit_breaker {
+            Some(circuit_breaker) => {
+                let key = CircuitKey::from_request(&request);
+                circuit_breaker.acquire(&key)?;
+                Some((circuit_breaker, key))
+            }
+            None => None,
+        };
+
+        let rate_limit_key = request.rate_limit_key();
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire_with_priority(rate_limit_key, request.weight(), request.priority())
+                .await;
+        }
+
+        let request = self.build(request)?;
+        if let Some(audit) = &self.audit {
+            audit.record_http(&request, &*self.runtime).await?;
+        }
+
+        let latency = self.latency_metric(&request);
+        let logged = self.logger.as_ref().map(|logger| {
+            logger.log_request(&request);
+            (logger, request.method().clone(), request.url().clone())
+        });
+
+        let start = std::time::Instant::now();
+        let response = self
+            .http_client
+            .execute(request)
+            .await
+            .map_err(SocketError::from);
+        let elapsed = start.elapsed();
+
+        if let Some((circuit_breaker, key)) = &circuit {
+            let attempt = match &response {
+                Ok(response) => Attempt::Status(response.status()),
+                Err(error) => Attempt::Error(error),
+            };
+            circuit_breaker.record(key, &attempt);
+        }
+        let response = response?;
+
+        if let Some((logger, method, url)) = logged {
+            logger.log_response(&method, &url, response.status(), elapsed);
+        }
+
+        let status = response.status();
+        self.complete_latency_metric(latency, status, Some(elapsed), None);
+
+        // Synchronise the RateLimiter with the server communicated usage
+        if let (Some(rate_limiter), Some(parser)) = (&self.rate_limiter, &self.rate_limit_parser) {
+            if let Some(usage) = parser.parse(status, response.headers()) {
+                rate_limiter.observe(rate_limit_key, &usage);
+            }
+        }
+
+        if !status.is_success() {
+            let payload = response.bytes().await.map_err(SocketError::from)?;
+            return Err(match serde_json::from_slice::<Parser::ApiError>(&payload) {
+                Ok(api_error) => self.parser.parse_api_error(status, api_error),
+                Err(_) => Parser::OutputError::from(SocketError::HttpResponse(
+                    status,
+                    String::from_utf8_lossy(&payload).into_owned(),
+                )),
+            });
+        }
+
+        Ok(futures::stream::unfold(
+            Some(response),
+            |response| async move {
+                let mut response = response?;
+                match response.chunk().await {
+                    Ok(Some(chunk)) => Some((Ok(chunk), Some(response))),
+                    Ok(None) => None,
+                    Err(error) => Some((Err(SocketError::from(error)), None)),
+                }
+            },
+        ))
+    }
+
+    /// Execute the provided [`Paginated`] request, followed by a request for every subsequent
+    /// page, yielding each page as it is received.
+    ///
+    /// The `Stream` ends after the last page (no next cursor), or after yielding the first
+    /// error.
+    pub fn execute_paginated<'b, Request>(
+        &'b self,
+        request: Request,
+    ) -> impl Stream<Item = Result<(Request::Response, Metric), Parser::OutputError>> + 'b
+    where
+        Request: Paginated + Clone + 'b,
+    {
+        futures::stream::unfold(Some(request), move |request| async move {
+            let request = request?;
+            match self.execute(request.clone()).await {
+                Ok((response, metric)) => {
+                    let next =
+                        Request::next_cursor(&response).map(|cursor| request.with_cursor(cursor));
+                    Some((Ok((response, metric)), next))
+                }
+                Err(error) => Some((Err(error), None)),
+            }
+        })
+    }
+
+    /// Execute the provided [`RestRequest`] as the provided account, signed with its credentials
+    /// by a [`MultiAccount`](super::super::private::account::MultiAccount) strategy.
+    pub async fn execute_as<Request, S>(
+        &self,
+        account_id: S,
+        request: Request,
+    ) -> Result<(Request::Response, Metric), Parser::OutputError>
+    where
+        Request: RestRequest,
+        S: Into<String>,
+    {
+        self.execute(AsAccount::new(account_id, request)).await
+    }
+
+    /// Execute the provided [`GraphQl`] request, returning the response `data`.
+    ///
+    /// GraphQL errors are returned within a successful response envelope, so any `errors` are
+    /// mapped via the [`HttpParser`] if they deserialise into its `ApiError`, otherwise into a
+    /// [`SocketError::Exchange`]. Partial `data` accompanying `errors` is discarded.
+    pub async fn execute_graphql<Variables, Data>(
+        &self,
+        request: GraphQl<Variables, Data>,
+    ) -> Result<(Data, Metric), Parser::OutputError>
+    where
+        Variables: serde::Serialize,
+        Data: serde::de::DeserializeOwned,
+    {
+        let (GraphQlResponse { data, errors }, meta) = self.execute_with_meta(request).await?;
+
+        if !errors.is_empty() {
+            let envelope = serde_json::json!({ "errors": errors });
+            return Err(match serde_json::from_value::<Parser::ApiError>(envelope) {
+                Ok(api_error) => self.parser.parse_api_error(meta.status, api_error),
+                Err(_) => Parser::OutputError::from(SocketError::Exchange(
+                    errors
+                        .into_iter()
+                        .map(|error| error.message)
+                        .collect::<Vec<_>>()
+                        .join("; "),
+                )),
+            });
+        }
+
+        match data {
+            Some(data) => Ok((data, meta.latency)),
+            None => Err(Parser::OutputError::from(SocketError::Exchange(
+                "GraphQL response contained neither data nor errors".to_string(),
+            ))),
+        }
     }
 
     /// Use the provided [`RestRequest`] to construct a signed Http [`reqwest::Request`].
@@ -64,13 +569,13 @@ where
     where
         Request: RestRequest,
     {
-        // Construct url
-        let url = format!("{}{}", self.base_url, request.path());
+        // Construct url, rendering any path template
+        let url = format!("{}{}", self.base_url, render_path(&request)?);
 
         // Construct RequestBuilder with method & url
         let mut builder = self
             .http_client
-            .request(Request::method(), url)
+            .request(request.method(), url)
             .timeout(Request::timeout());
 
         // Add optional query parameters
@@ -80,7 +585,18 @@ where
 
         // Add optional Body
         if let Some(body) = request.body() {
-            builder = builder.json(body);
+            builder = Request::body_kind().attach(builder, body)?;
+        }
+
+        // Merge any additional RestRequest headers
+        let headers = request.headers();
+        if !headers.is_empty() {
+            builder = builder.headers(headers);
+        }
+
+        // Inject any idempotency key in the API specific place, before signing
+        if let Some(key) = request.idempotency_key() {
+            builder = self.strategy.inject_idempotency_key(builder, &key);
         }
 
         // Use RequestBuilder (public or private strategy) to build reqwest::Request
@@ -97,45 +613,919 @@ where
     where
         Request: RestRequest,
     {
-        // Construct Http request duration Metric
-        let mut latency = Metric {
+        self.measured_execution_with_meta(request)
+            .await
+            .map(|(meta, payload)| (meta.status, payload, meta.latency))
+    }
+
+    /// Execute the built [`reqwest::Request`] using the [`reqwest::Client`], returning the
+    /// [`ResponseMeta`] & payload bytes.
+    async fn measured_execution_with_meta(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<(ResponseMeta, Bytes), SocketError> {
+        let latency = self.latency_metric(&request);
+
+        let logged = self.logger.as_ref().map(|logger| {
+            logger.log_request(&request);
+            (logger, request.method().clone(), request.url().clone())
+        });
+
+        // Measure the HTTP request round trip duration, if required
+        let start = (latency.is_some() || logged.is_some()).then(std::time::Instant::now);
+        let response = self.http_client.execute(request).await?;
+        let elapsed = start.map(|start| start.elapsed());
+
+        if let (Some((logger, method, url)), Some(elapsed)) = (logged, elapsed) {
+            logger.log_response(&method, &url, response.status(), elapsed);
+        }
+
+        // Extract Status Code, Headers & reqwest::Response Bytes (decompressed, if enabled)
+        let status = response.status();
+        let headers = response.headers().clone();
+        let payload = response.bytes().await?;
+
+        let latency = self.complete_latency_metric(latency, status, elapsed, Some(payload.len()));
+
+        let rate_limit = self
+            .rate_limit_parser
+            .as_ref()
+            .and_then(|parser| parser.parse(status, &headers));
+
+        Ok((
+            ResponseMeta {
+                status,
+                headers,
+                latency,
+                rate_limit,
+                idempotency_key: None,
+            },
+            payload,
+        ))
+    }
+
+    /// Construct the Http request duration [`Metric`] for the provided request, unless
+    /// measurement is disabled.
+    fn latency_metric(&self, request: &reqwest::Request) -> Option<Metric> {
+        self.metrics.then(|| Metric {
             name: "http_request_duration",
             time: Utc::now().timestamp_millis() as u64,
             tags: vec![
-                Tag::new("http_method", Request::method().as_str()),
+                Tag::new("http_method", request.method().as_str()),
                 Tag::new("base_url", self.base_url.as_ref()),
                 Tag::new("path", request.url().path()),
             ],
-            fields: Vec::with_capacity(1),
-        };
+            fields: Vec::with_capacity(2),
+        })
+    }
 
-        // Measure the HTTP request round trip duration
-        let start = std::time::Instant::now();
-        let response = self.http_client.execute(request).await?;
-        let duration = start.elapsed().as_millis() as u64;
+    /// Update the Http request duration [`Metric`] with the response status, request duration &
+    /// decompressed response size (if known), and send it to the [`MetricSink`] if configured.
+    fn complete_latency_metric(
+        &self,
+        latency: Option<Metric>,
+        status: reqwest::StatusCode,
+        elapsed: Option<std::time::Duration>,
+        response_bytes: Option<usize>,
+    ) -> Metric {
+        let (Some(mut latency), Some(elapsed)) = (latency, elapsed) else {
+            return Metric::empty("http_request_duration");
+        };
 
-        // Update Metric with response status and request duration
+        latency.tags.push(Tag::new("status_code", status.as_str()));
         latency
-            .tags
-            .push(Tag::new("status_code", response.status().as_str()));
-        latency.fields.push(Field::new("duration", duration));
-
-        // Extract Status Code & reqwest::Response Bytes
-        let status_code = response.status();
-        let payload = response.bytes().await?;
+            .fields
+            .push(Field::new("duration", elapsed.as_millis() as u64));
+        if let Some(response_bytes) = response_bytes {
+            latency
+                .fields
+                .push(Field::new("response_bytes", response_bytes as u64));
+        }
+        if let Some(sink) = &self.metric_sink {
+            sink.send(latency.clone());
+        }
+        latency
+    }
+}
 
-        Ok((status_code, payload, latency))
+impl<Strategy, Parser> Clone for RestClient<Strategy, Parser> {
+    fn clone(&self) -> Self {
+        Self {
+            http_client: self.http_client.clone(),
+            base_url: self.base_url.clone(),
+            strategy: Arc::clone(&self.strategy),
+            parser: Arc::clone(&self.parser),
+            audit: self.audit.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            retry_policy: self.retry_policy,
+            rate_limit_parser: self.rate_limit_parser.clone(),
+            logger: self.logger.clone(),
+            metric_sink: self.metric_sink.clone(),
+            metrics: self.metrics,
+            scope: self.scope,
+            coalescer: self.coalescer.clone(),
+            cache: self.cache.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            runtime: Arc::clone(&self.runtime),
+        }
     }
 }
 
-impl<'a, Strategy, Parser> RestClient<'a, Strategy, Parser> {
+impl<Strategy, Parser> RestClient<Strategy, Parser> {
     /// Construct a new [`Self`] using the provided configuration.
-    pub fn new<Url: Into<Cow<'a, str>>>(base_url: Url, strategy: Strategy, parser: Parser) -> Self {
+    pub fn new<Url: Into<Cow<'static, str>>>(
+        base_url: Url,
+        strategy: Strategy,
+        parser: Parser,
+    ) -> Self {
         Self {
             http_client: reqwest::Client::new(),
             base_url: base_url.into(),
-            strategy,
-            parser,
+            strategy: Arc::new(strategy),
+            parser: Arc::new(parser),
+            audit: None,
+            rate_limiter: None,
+            retry_policy: None,
+            rate_limit_parser: None,
+            logger: None,
+            metric_sink: None,
+            metrics: true,
+            scope: ClientScope::unique(),
+            coalescer: None,
+            cache: None,
+            circuit_breaker: None,
+            runtime: Arc::new(TokioRuntime),
+        }
+    }
+
+    /// Construct a new [`Self`] that skips Http request duration [`Metric`] measurement
+    /// entirely, returning empty [`Metric`]s.
+    ///
+    /// eg/ Latency sensitive order placement paths.
+    pub fn without_metrics<Url: Into<Cow<'static, str>>>(
+        base_url: Url,
+        strategy: Strategy,
+        parser: Parser,
+    ) -> Self {
+        Self {
+            metrics: false,
+            ..Self::new(base_url, strategy, parser)
+        }
+    }
+
+    /// Construct a new [`RestClientBuilder`] for configuring the underlying [`reqwest::Client`]
+    /// (eg/ connect timeout, user agent, or an injected client).
+    pub fn builder<Url: Into<Cow<'static, str>>>(
+        base_url: Url,
+        strategy: Strategy,
+        parser: Parser,
+    ) -> RestClientBuilder<Strategy, Parser> {
+        RestClientBuilder::new(base_url, strategy, parser)
+    }
+
+    /// Use the provided pre-configured [`reqwest::Client`] (eg/ custom connection pool, keep-alive
+    /// or HTTP/2 settings).
+    ///
+    /// [`reqwest::Client`]s are cheaply cloneable, so one can be shared across many
+    /// [`RestClient`]s, along with its connection pool.
+    pub fn with_http_client(self, http_client: reqwest::Client) -> Self {
+        Self {
+            http_client,
+            ..self
+        }
+    }
+
+    /// Apply the provided [`RetryPolicy`] to transient [`RestRequest`] failures.
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy: Some(retry_policy),
+            ..self
+        }
+    }
+
+    /// Await capacity from the provided [`RateLimiter`] before executing every [`RestRequest`].
+    ///
+    /// Clones of the [`RateLimiter`] share capacity, so it can be shared across many clients.
+    pub fn with_rate_limiter(self, rate_limiter: RateLimiter) -> Self {
+        Self {
+            rate_limiter: Some(rate_limiter),
+            ..self
+        }
+    }
+
+    /// Extract the [`RateLimitUsage`] of every response using the provided [`RateLimitParser`],
+    /// surfacing it via the [`ResponseMeta`] and feeding it into the [`RateLimiter`].
+    pub fn with_rate_limit_parser<RateParser>(self, parser: RateParser) -> Self
+    where
+        RateParser: RateLimitParser + 'static,
+    {
+        Self {
+            rate_limit_parser: Some(Arc::new(parser)),
+            ..self
+        }
+    }
+
+    /// Log every request & response using the provided [`RequestLogger`], redacting secrets.
+    pub fn with_request_logger(self, logger: RequestLogger) -> Self {
+        Self {
+            logger: Some(logger),
+            ..self
+        }
+    }
+
+    /// Send the Http request duration [`Metric`] of every response to the provided
+    /// [`MetricSink`].
+    pub fn with_metric_sink<Sink>(self, sink: Sink) -> Self
+    where
+        Sink: MetricSink + 'static,
+    {
+        Self {
+            metric_sink: Some(Arc::new(sink)),
+            ..self
+        }
+    }
+
+    /// Identify this client's credentials with the provided [`ClientScope`].
+    ///
+    /// Clients sharing an [`InflightCoalescer`] or [`ResponseCache`] only share responses if
+    /// they use the same [`ClientScope`], so only share a scope between equivalent clients (eg/
+    /// unauthenticated market data clients).
+    pub fn with_client_scope(self, scope: ClientScope) -> Self {
+        Self { scope, ..self }
+    }
+
+    /// Share one in-flight request & response between concurrent identical `GET` & `HEAD`
+    /// [`RestRequest`]s using the provided [`InflightCoalescer`].
+    ///
+    /// Clones of the [`InflightCoalescer`] share in-flight requests, so it can be shared across
+    /// many clients with the same [`ClientScope`].
+    pub fn with_inflight_coalescer(self, coalescer: InflightCoalescer) -> Self {
+        Self {
+            coalescer: Some(coalescer),
+            ..self
+        }
+    }
+
+    /// Serve successful `GET` & `HEAD` responses from the provided [`ResponseCache`] for the
+    /// [`RestRequest::cache_ttl`].
+    ///
+    /// Clones of the [`ResponseCache`] share cached responses, so it can be shared across many
+    /// clients.
+    pub fn with_response_cache(self, cache: ResponseCache) -> Self {
+        Self {
+            cache: Some(cache),
+            ..self
+        }
+    }
+
+    /// Use the provided [`Runtime`] for retry backoff timers & background tasks, rather than
+    /// tokio.
+    pub fn with_runtime<R>(self, runtime: R) -> Self
+    where
+        R: Runtime + 'static,
+    {
+        Self {
+            runtime: Arc::new(runtime),
+            ..self
+        }
+    }
+
+    /// Short-circuit [`RestRequest`]s to failing endpoints using the provided
+    /// [`CircuitBreaker`].
+    ///
+    /// Clones of the [`CircuitBreaker`] share endpoint circuits, so it can be shared across many
+    /// clients.
+    pub fn with_circuit_breaker(self, circuit_breaker: CircuitBreaker) -> Self {
+        Self {
+            circuit_breaker: Some(circuit_breaker),
+            ..self
+        }
+    }
+
+    /// Record every executed [`RestRequest`] to the provided [`AuditLog`].
+    pub fn with_audit_log(self, audit: AuditLog) -> Self {
+        Self {
+            audit: Some(audit),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        protocol::http::{
+            private::{encoder::HexEncoder, RequestSigner, Signer},
+            public::PublicNoHeaders,
+            rest::path::PathTemplate,
+            IdempotencyPlacement,
+        },
+        test_util::{http_response, ok, MockServer, Ping, TestParser},
+    };
+
+    struct CachedPing;
+
+    impl RestRequest for CachedPing {
+        type Response = serde_json::Value;
+        type QueryParams = ();
+        type Body = ();
+
+        fn path(&self) -> Cow<'static, str> {
+            Cow::Borrowed("/ping")
+        }
+
+        fn cache_ttl(&self) -> Option<std::time::Duration> {
+            Some(std::time::Duration::from_secs(60))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_meta() {
+        let server = MockServer::responses([http_response(
+            "200 OK",
+            &[("x-mbx-used-weight-1m", "42")],
+            "{}",
+        )]);
+
+        let (metric_tx, mut metric_rx) = tokio::sync::mpsc::unbounded_channel();
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser)
+            .with_metric_sink(metric_tx);
+        let (response, meta) = client.execute_with_meta(Ping).await.unwrap();
+
+        assert!(server.next_request().starts_with("GET /ping HTTP/1.1"));
+        assert_eq!(response, serde_json::json!({}));
+        assert_eq!(meta.status, reqwest::StatusCode::OK);
+        assert_eq!(meta.headers["x-mbx-used-weight-1m"], "42");
+        assert_eq!(meta.latency.name, "http_request_duration");
+        assert_eq!(metric_rx.try_recv().unwrap(), meta.latency);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[tokio::test]
+    async fn test_execute_gzip_response() {
+        // {"id":1} gzip compressed
+        let body = [
+            31, 139, 8, 0, 0, 0, 0, 0, 2, 3, 171, 86, 202, 76, 81, 178, 50, 172, 5, 0, 197, 248,
+            93, 68, 8, 0, 0, 0,
+        ];
+        let server = MockServer::responses([http_response(
+            "200 OK",
+            &[("content-encoding", "gzip")],
+            body,
+        )]);
+
+        let client = RestClient::builder(server.base_url.clone(), PublicNoHeaders, TestParser)
+            .gzip(true)
+            .build()
+            .unwrap();
+        let (response, metric) = client.execute(Ping).await.unwrap();
+
+        assert_eq!(response, serde_json::json!({"id": 1}));
+        assert!(metric.fields.contains(&Field::new("response_bytes", 8_u64)));
+    }
+
+    #[tokio::test]
+    async fn test_execute_raw() {
+        let server = MockServer::responses([http_response("418 I'm a teapot", &[], "not json!")]);
+
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser);
+        let (status, payload) = client.execute_raw(Ping).await.unwrap();
+
+        assert_eq!(status, reqwest::StatusCode::IM_A_TEAPOT);
+        assert_eq!(payload, Bytes::from_static(b"not json!"));
+    }
+
+    #[test]
+    fn test_build_renders_path_template() {
+        struct Order(Option<&'static str>);
+
+        impl RestRequest for Order {
+            type Response = serde_json::Value;
+            type QueryParams = ();
+            type Body = ();
+
+            fn path(&self) -> Cow<'static, str> {
+                Cow::Borrowed("/orders/{order_id}")
+            }
+
+            fn path_template(&self) -> Option<PathTemplate> {
+                let template = PathTemplate::new("/orders/{order_id}");
+                Some(match self.0 {
+                    Some(order_id) => template.param("order_id", order_id),
+                    None => template,
+                })
+            }
+        }
+
+        let client = RestClient::new("http://localhost", PublicNoHeaders, TestParser);
+        let request = client.build(Order(Some("a/b"))).unwrap();
+        assert_eq!(request.url().path(), "/orders/a%2Fb");
+        assert_eq!(Order(None).path(), "/orders/{order_id}");
+
+        // Missing parameter is surfaced rather than panicking
+        assert!(matches!(
+            client.build(Order(None)),
+            Err(SocketError::UrlTemplate(_))
+        ));
+    }
+
+    #[test]
+    fn test_build_uses_instance_method_and_path() {
+        enum Order {
+            Query(u64),
+            Cancel(u64),
+        }
+
+        impl RestRequest for Order {
+            type Response = serde_json::Value;
+            type QueryParams = ();
+            type Body = ();
+
+            fn path(&self) -> Cow<'static, str> {
+                match self {
+                    Order::Query(id) | Order::Cancel(id) => Cow::Owned(format!("/orders/{id}")),
+                }
+            }
+
+            fn method(&self) -> reqwest::Method {
+                match self {
+                    Order::Query(_) => reqwest::Method::GET,
+                    Order::Cancel(_) => reqwest::Method::DELETE,
+                }
+            }
+        }
+
+        let client = RestClient::new("http://localhost", PublicNoHeaders, TestParser);
+
+        let request = client.build(Order::Query(1)).unwrap();
+        assert_eq!(request.method(), reqwest::Method::GET);
+        assert_eq!(request.url().path(), "/orders/1");
+
+        let request = client.build(Order::Cancel(2)).unwrap();
+        assert_eq!(request.method(), reqwest::Method::DELETE);
+        assert_eq!(request.url().path(), "/orders/2");
+
+        // Method defaults to GET
+        assert_eq!(Ping.method(), reqwest::Method::GET);
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_response_cache() {
+        // Server only serves a single response, so subsequent requests must be cached
+        let server = MockServer::responses([ok(r#"{"id":1}"#)]);
+
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser)
+            .with_response_cache(ResponseCache::new());
+        for _ in 0..3 {
+            let (response, _) = client.execute(CachedPing).await.unwrap();
+            assert_eq!(response, serde_json::json!({"id": 1}));
+        }
+
+        // Clones executing on other tasks share the same ResponseCache
+        let clone = client.clone();
+        let (response, _) = tokio::spawn(async move { clone.execute(CachedPing).await })
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(response, serde_json::json!({"id": 1}));
+    }
+
+    #[test]
+    fn test_request_key_scope() {
+        struct KeyedPing(&'static str);
+
+        impl RestRequest for KeyedPing {
+            type Response = serde_json::Value;
+            type QueryParams = ();
+            type Body = ();
+
+            fn path(&self) -> Cow<'static, str> {
+                Cow::Borrowed("/ping")
+            }
+
+            fn headers(&self) -> reqwest::header::HeaderMap {
+                let mut headers = reqwest::header::HeaderMap::new();
+                headers.insert("x-api-key", self.0.parse().unwrap());
+                headers
+            }
         }
+
+        let coalescer = InflightCoalescer::new();
+        let client = |scope| {
+            RestClient::new("https://api.x.com", PublicNoHeaders, TestParser)
+                .with_inflight_coalescer(coalescer.clone())
+                .with_client_scope(scope)
+        };
+        let key = |client: &RestClient<_, _>, request| {
+            client.request_key(&request, false).unwrap().unwrap()
+        };
+
+        let (a, b) = (client(ClientScope::unique()), client(ClientScope::unique()));
+
+        // Clients sharing a coalescer only share responses within the same ClientScope
+        assert_eq!(key(&a, KeyedPing("a")), key(&a.clone(), KeyedPing("a")));
+        assert_ne!(key(&a, KeyedPing("a")), key(&b, KeyedPing("a")));
+        assert_eq!(
+            key(&a, KeyedPing("a")),
+            key(&b.with_client_scope(a.scope), KeyedPing("a"))
+        );
+
+        // Requests sending different headers are never shared
+        assert_ne!(key(&a, KeyedPing("a")), key(&a, KeyedPing("b")));
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_shared_response_cache() {
+        let server = MockServer::responses([ok(r#"{"id":1}"#), ok(r#"{"id":2}"#)]);
+
+        let cache = ResponseCache::new();
+        let client = || {
+            RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser)
+                .with_response_cache(cache.clone())
+        };
+        let (a, b) = (client(), client());
+
+        // Clients of different ClientScopes (ie/ credentials) never share cached responses
+        let (response, _) = a.execute(CachedPing).await.unwrap();
+        assert_eq!(response, serde_json::json!({"id": 1}));
+        let (response, _) = b.execute(CachedPing).await.unwrap();
+        assert_eq!(response, serde_json::json!({"id": 2}));
+
+        // Clients of the same ClientScope do
+        let (response, _) = client()
+            .with_client_scope(a.scope)
+            .execute(CachedPing)
+            .await
+            .unwrap();
+        assert_eq!(response, serde_json::json!({"id": 1}));
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_shared_http_client() {
+        let server = MockServer::responses([ok("{}"), ok("{}")]);
+
+        // One pre-configured reqwest::Client shared across many RestClients
+        let http_client = reqwest::Client::builder()
+            .default_headers(reqwest::header::HeaderMap::from_iter([(
+                reqwest::header::HeaderName::from_static("x-shared"),
+                reqwest::header::HeaderValue::from_static("true"),
+            )]))
+            .build()
+            .unwrap();
+        let client = || {
+            RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser)
+                .with_http_client(http_client.clone())
+        };
+
+        for client in [client(), client()] {
+            client.execute(Ping).await.unwrap();
+            assert!(server
+                .next_request()
+                .to_lowercase()
+                .contains("x-shared: true"));
+        }
+    }
+
+    /// `GET /echo/{id}` [`RestRequest`], for asserting which request a response belongs to.
+    struct Echo(u64);
+
+    impl RestRequest for Echo {
+        type Response = u64;
+        type QueryParams = ();
+        type Body = ();
+
+        fn path(&self) -> Cow<'static, str> {
+            Cow::Owned(format!("/echo/{}", self.0))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_all() {
+        // Respond to each request with the id in its path
+        let server = MockServer::start(|request| {
+            let id = request
+                .split_whitespace()
+                .nth(1)
+                .and_then(|path| path.strip_prefix("/echo/"))?;
+            Some(ok(id))
+        });
+
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser);
+        let results = client.execute_all((0..5).map(Echo), 2).await;
+
+        // Results are returned in request order, regardless of completion order
+        let ids = results
+            .into_iter()
+            .map(|result| result.unwrap().0)
+            .collect::<Vec<_>>();
+        assert_eq!(ids, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_execute_retries_rate_limited() {
+        let server = MockServer::responses([
+            http_response("429 Too Many Requests", &[("retry-after", "0.05")], "{}"),
+            ok("{}"),
+        ]);
+
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser)
+            .with_retry_policy(RetryPolicy::default());
+        let started = std::time::Instant::now();
+        let (_, meta) = client.execute_with_meta(Ping).await.unwrap();
+
+        assert_eq!(meta.status, reqwest::StatusCode::OK);
+        assert!(started.elapsed() >= std::time::Duration::from_millis(50));
+    }
+
+    /// [`Signer`] that signs a fresh nonce on every build, like a timestamp or nonce based API.
+    #[derive(Debug, Default)]
+    struct NonceSigner(std::sync::atomic::AtomicU64);
+
+    impl Signer for NonceSigner {
+        type Config<'a> = String;
+
+        fn config<'a, Request>(
+            &'a self,
+            _: Request,
+            _: &reqwest::RequestBuilder,
+        ) -> Result<Self::Config<'a>, SocketError>
+        where
+            Request: RestRequest,
+        {
+            Ok(self
+                .0
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+                .to_string())
+        }
+
+        fn add_bytes_to_sign<M>(mac: &mut M, config: &Self::Config<'_>)
+        where
+            M: hmac::Mac,
+        {
+            mac.update(config.as_bytes());
+        }
+
+        fn build_signed_request(
+            config: Self::Config<'_>,
+            builder: reqwest::RequestBuilder,
+            signature: String,
+        ) -> Result<reqwest::Request, SocketError> {
+            builder
+                .header("x-nonce", config)
+                .header("signature", signature)
+                .build()
+                .map_err(SocketError::from)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_retry_is_resigned() {
+        let server = MockServer::responses([
+            http_response("429 Too Many Requests", &[("retry-after", "0")], "{}"),
+            ok("{}"),
+        ]);
+
+        let strategy = RequestSigner::new(
+            NonceSigner::default(),
+            <hmac::Hmac<sha2::Sha256> as hmac::Mac>::new_from_slice(b"secret").unwrap(),
+            HexEncoder,
+        );
+        let client = RestClient::new(server.base_url.clone(), strategy, TestParser)
+            .with_retry_policy(RetryPolicy::default());
+        let (_, meta) = client.execute_with_meta(Ping).await.unwrap();
+        assert_eq!(meta.status, reqwest::StatusCode::OK);
+
+        let header = |request: &str, name: &str| {
+            request
+                .lines()
+                .find_map(|line| line.strip_prefix(&format!("{name}: ")).map(str::to_owned))
+                .unwrap()
+        };
+        let (first, retry) = (server.next_request(), server.next_request());
+        assert_eq!(header(&first, "x-nonce"), "0");
+        assert_eq!(header(&retry, "x-nonce"), "1");
+        assert_ne!(header(&first, "signature"), header(&retry, "signature"));
+    }
+
+    /// `POST /order` [`RestRequest`] carrying an idempotency key.
+    struct PlaceOrder;
+
+    impl RestRequest for PlaceOrder {
+        type Response = serde_json::Value;
+        type QueryParams = ();
+        type Body = ();
+
+        fn path(&self) -> Cow<'static, str> {
+            Cow::Borrowed("/order")
+        }
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::POST
+        }
+
+        fn idempotency_key(&self) -> Option<String> {
+            Some("order-1".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_retries_idempotent_post() {
+        /// Sends the idempotency key as the `x-request-id` header.
+        struct RequestIdHeader;
+
+        impl BuildStrategy for RequestIdHeader {
+            fn build<Request>(
+                &self,
+                _: Request,
+                builder: reqwest::RequestBuilder,
+            ) -> Result<reqwest::Request, SocketError> {
+                builder.build().map_err(SocketError::from)
+            }
+
+            fn inject_idempotency_key(
+                &self,
+                builder: reqwest::RequestBuilder,
+                key: &str,
+            ) -> reqwest::RequestBuilder {
+                IdempotencyPlacement::Header("x-request-id").inject(builder, key)
+            }
+
+            fn injects_idempotency_key(&self) -> bool {
+                true
+            }
+        }
+
+        let server = MockServer::responses([
+            http_response("503 Service Unavailable", &[], "{}"),
+            ok("{}"),
+        ]);
+
+        let client = RestClient::new(server.base_url.clone(), RequestIdHeader, TestParser)
+            .with_retry_policy(RetryPolicy {
+                initial_backoff: std::time::Duration::ZERO,
+                ..RetryPolicy::default()
+            });
+        let (_, meta) = client.execute_with_meta(PlaceOrder).await.unwrap();
+
+        assert_eq!(meta.status, reqwest::StatusCode::OK);
+        assert_eq!(meta.idempotency_key.as_deref(), Some("order-1"));
+        for _ in 0..2 {
+            let request = server.next_request();
+            assert!(request.starts_with("POST /order HTTP/1.1"));
+            assert!(request.contains("x-request-id: order-1"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_does_not_retry_post_without_injected_key() {
+        // PublicNoHeaders does not send the idempotency key, so the server cannot deduplicate a
+        // retried order
+        let server = MockServer::responses([
+            http_response("503 Service Unavailable", &[], "{}"),
+            ok("{}"),
+        ]);
+
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser)
+            .with_retry_policy(RetryPolicy {
+                initial_backoff: std::time::Duration::ZERO,
+                ..RetryPolicy::default()
+            });
+        let (_, meta) = client.execute_with_meta(PlaceOrder).await.unwrap();
+
+        // Retrying would have been served the 200 OK
+        assert_eq!(meta.status, reqwest::StatusCode::SERVICE_UNAVAILABLE);
+        assert!(!server.next_request().contains("order-1"));
+    }
+
+    #[tokio::test]
+    async fn test_execute_graphql() {
+        let server = MockServer::responses([
+            ok(r#"{"data":{"pools":[1]}}"#),
+            ok(r#"{"errors":[{"message":"Bad"}]}"#),
+        ]);
+
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser);
+        let query = || GraphQl::<(), serde_json::Value>::new("/graphql", "{ pools }");
+
+        let (data, _) = client.execute_graphql(query()).await.unwrap();
+        assert_eq!(data, serde_json::json!({"pools": [1]}));
+
+        // TestParser::ApiError is a serde_json::Value, so errors are mapped by the HttpParser
+        let error = client.execute_graphql(query()).await.unwrap_err();
+        assert!(matches!(
+            error,
+            SocketError::HttpResponse(reqwest::StatusCode::OK, _)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_execute_without_metrics() {
+        let server = MockServer::responses([ok("{}")]);
+
+        let (metric_tx, mut metric_rx) = tokio::sync::mpsc::unbounded_channel();
+        let client =
+            RestClient::without_metrics(server.base_url.clone(), PublicNoHeaders, TestParser)
+                .with_metric_sink(metric_tx);
+        let (response, metric) = client.execute(Ping).await.unwrap();
+
+        assert_eq!(response, serde_json::json!({}));
+        assert_eq!(metric, Metric::empty("http_request_duration"));
+        assert!(metric_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_stream() {
+        let server = MockServer::responses([concat!(
+            "HTTP/1.1 200 OK\r\n",
+            "transfer-encoding: chunked\r\n",
+            "connection: close\r\n\r\n",
+            "e\r\n{\"close\":1.0}\n\r\n",
+            "e\r\n{\"close\":2.0}\n\r\n",
+            "0\r\n\r\n"
+        )
+        .as_bytes()
+        .to_vec()]);
+
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser);
+        let chunks = client.execute_stream(Ping).await.unwrap();
+        let closes = crate::protocol::http::rest::ndjson::decode_ndjson::<_, serde_json::Value>(
+            Box::pin(chunks),
+        )
+        .map(|candle| candle.unwrap()["close"].as_f64().unwrap())
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(closes, vec![1.0, 2.0]);
+    }
+
+    #[tokio::test]
+    async fn test_execute_stream_circuit_breaker_and_metrics() {
+        use crate::protocol::http::rest::circuit::CircuitBreakerConfig;
+
+        let server = MockServer::responses([http_response("503 Service Unavailable", &[], "{}")]);
+
+        let (metric_tx, mut metric_rx) = tokio::sync::mpsc::unbounded_channel();
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser)
+            .with_metric_sink(metric_tx)
+            .with_circuit_breaker(CircuitBreaker::new(CircuitBreakerConfig {
+                failure_threshold: 1,
+                ..CircuitBreakerConfig::default()
+            }));
+
+        // Failed streamed request is measured & trips the endpoint circuit
+        assert!(client.execute_stream(Ping).await.is_err());
+        let metric = metric_rx.try_recv().unwrap();
+        assert_eq!(metric.name, "http_request_duration");
+        assert!(metric.tags.contains(&Tag::new("status_code", "503")));
+
+        assert!(matches!(
+            client.execute_stream(Ping).await,
+            Err(SocketError::CircuitOpen { .. })
+        ));
+        assert!(metric_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_execute_stream_calibrates_weighted_rate_limiter() {
+        /// Binance style [`RestRequest`] consuming multiple units of weight.
+        struct Klines;
+
+        impl RestRequest for Klines {
+            type Response = serde_json::Value;
+            type QueryParams = ();
+            type Body = ();
+
+            fn path(&self) -> Cow<'static, str> {
+                Cow::Borrowed("/klines")
+            }
+
+            fn weight(&self) -> u32 {
+                4
+            }
+        }
+
+        // Server reports the full weight limit as used, eg/ by other clients sharing the key
+        let server = MockServer::responses([http_response(
+            "200 OK",
+            &[("x-mbx-used-weight-1m", "10")],
+            "{}",
+        )]);
+
+        let limiter = RateLimiter::new(crate::protocol::http::rest::rate_limit::RateLimit::new(
+            10,
+            std::time::Duration::from_millis(200),
+        ))
+        .unwrap();
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser)
+            .with_rate_limiter(limiter.clone())
+            .with_rate_limit_parser(crate::protocol::http::rest::usage::MbxUsedWeight::new(10));
+
+        // Without calibration 6 of 10 weight would remain after the request, so the next weighted
+        // acquisition would be immediate
+        let _ = client.execute_stream(Klines).await.unwrap();
+        let start = std::time::Instant::now();
+        limiter.acquire(None, 4).await;
+        assert!(start.elapsed() >= std::time::Duration::from_millis(50));
     }
 }
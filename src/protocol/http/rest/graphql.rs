@@ -0,0 +1,133 @@
+use crate::protocol::http::rest::RestRequest;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{borrow::Cow, marker::PhantomData};
+
+/// GraphQL [`RestRequest`] of a query (or mutation) & optional variables, executed via
+/// [`RestClient::execute_graphql`](super::client::RestClient::execute_graphql).
+///
+/// eg/ `GraphQl::<_, Pools>::new("/graphql", POOLS_QUERY).with_variables(PoolsVars { first: 10 })`
+#[derive(Debug, Clone)]
+pub struct GraphQl<Variables, Data> {
+    pub path: Cow<'static, str>,
+    pub body: GraphQlBody<Variables>,
+    phantom: PhantomData<Data>,
+}
+
+/// Body of a [`GraphQl`] request.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQlBody<Variables> {
+    pub query: Cow<'static, str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_name: Option<Cow<'static, str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<Variables>,
+}
+
+/// GraphQL response envelope containing the `data` and/or `errors`.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GraphQlResponse<Data> {
+    pub data: Option<Data>,
+    #[serde(default)]
+    pub errors: Vec<GraphQlError>,
+}
+
+/// Error returned in a [`GraphQlResponse`] envelope.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct GraphQlError {
+    pub message: String,
+    #[serde(default)]
+    pub path: Vec<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extensions: Option<Value>,
+}
+
+impl<Variables, Data> GraphQl<Variables, Data> {
+    /// Construct a new [`GraphQl`] request of the provided query, sent to the provided path.
+    pub fn new<Path, Query>(path: Path, query: Query) -> Self
+    where
+        Path: Into<Cow<'static, str>>,
+        Query: Into<Cow<'static, str>>,
+    {
+        Self {
+            path: path.into(),
+            body: GraphQlBody {
+                query: query.into(),
+                operation_name: None,
+                variables: None,
+            },
+            phantom: PhantomData,
+        }
+    }
+
+    /// Send the provided query variables.
+    pub fn with_variables(mut self, variables: Variables) -> Self {
+        self.body.variables = Some(variables);
+        self
+    }
+
+    /// Execute the named operation of a query document containing many operations.
+    pub fn with_operation_name<S>(mut self, operation_name: S) -> Self
+    where
+        S: Into<Cow<'static, str>>,
+    {
+        self.body.operation_name = Some(operation_name.into());
+        self
+    }
+}
+
+impl<Variables, Data> RestRequest for GraphQl<Variables, Data>
+where
+    Variables: Serialize,
+    Data: DeserializeOwned,
+{
+    type Response = GraphQlResponse<Data>;
+    type QueryParams = ();
+    type Body = GraphQlBody<Variables>;
+
+    fn path(&self) -> Cow<'static, str> {
+        self.path.clone()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::POST
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        Some(&self.body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_graphql_body() {
+        #[derive(Serialize)]
+        struct Variables {
+            first: u32,
+        }
+
+        let request = GraphQl::<_, Value>::new("/graphql", "query Pools($first: Int) { pools }")
+            .with_variables(Variables { first: 10 })
+            .with_operation_name("Pools");
+
+        assert_eq!(
+            serde_json::to_value(request.body().unwrap()).unwrap(),
+            serde_json::json!({
+                "query": "query Pools($first: Int) { pools }",
+                "operationName": "Pools",
+                "variables": { "first": 10 },
+            })
+        );
+
+        let response = serde_json::from_str::<GraphQlResponse<Value>>(
+            r#"{"errors":[{"message":"Unknown field","path":["pools"]}]}"#,
+        )
+        .unwrap();
+        assert_eq!(response.data, None);
+        assert_eq!(response.errors[0].message, "Unknown field");
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{error::SocketError, protocol::http::rest::client::ResponseMeta};
+use bytes::Bytes;
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tokio::sync::oneshot;
+
+/// Outcome of an in-flight request shared with every coalesced waiter.
+type Shared = Result<(ResponseMeta, Bytes), String>;
+
+/// Identity of an idempotent request (executing [`ClientScope`], method, full Url including the
+/// query, request headers & any [`RestRequest::account_id`](super::RestRequest::account_id)),
+/// used to detect identical in-flight requests.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct InflightKey {
+    pub scope: ClientScope,
+    pub method: reqwest::Method,
+    pub url: String,
+    /// [`RestRequest::headers`](super::RestRequest::headers), sorted by name.
+    pub headers: Vec<(reqwest::header::HeaderName, reqwest::header::HeaderValue)>,
+    pub account_id: Option<String>,
+}
+
+/// Identity of the credentials & [`BuildStrategy`](crate::protocol::http::BuildStrategy) a
+/// [`RestClient`](super::client::RestClient) executes requests with, such that clients sharing
+/// an [`InflightCoalescer`] or [`ResponseCache`](super::cache::ResponseCache) never receive
+/// responses of requests signed with other credentials.
+///
+/// Every [`RestClient`](super::client::RestClient) is constructed with a
+/// [`unique`](Self::unique) scope, which its clones share. Clients known to be equivalent (eg/
+/// public market data clients) may opt in to sharing responses by using the same scope.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct ClientScope(u64);
+
+impl ClientScope {
+    /// Construct a new process wide unique [`ClientScope`].
+    pub fn unique() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Coalesces concurrent identical idempotent requests, such that they share one in-flight
+/// request & response. Reduces pressure on rate limits when many tasks request the same
+/// resource (eg/ symbol metadata) at once.
+///
+/// Waiters receive a clone of the leading request's [`ResponseMeta`] & payload. If the leading
+/// request fails, waiters receive a [`SocketError::Coalesced`], and if it is cancelled they
+/// execute their own request instead.
+///
+/// Cheaply cloneable, with every clone sharing the same in-flight requests.
+#[derive(Debug, Clone, Default)]
+pub struct InflightCoalescer {
+    inflight: Arc<Mutex<HashMap<InflightKey, Vec<oneshot::Sender<Shared>>>>>,
+}
+
+impl InflightCoalescer {
+    /// Construct a new [`InflightCoalescer`] with no in-flight requests.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of identical requests currently in-flight, including coalesced waiters.
+    pub fn inflight(&self, key: &InflightKey) -> usize {
+        self.lock().get(key).map_or(0, |waiters| waiters.len() + 1)
+    }
+
+    /// Execute the provided request future, unless an identical request is already in-flight,
+    /// in which case its outcome is awaited & shared instead.
+    pub async fn execute<Fut>(
+        &self,
+        key: InflightKey,
+        request: Fut,
+    ) -> Result<(ResponseMeta, Bytes), SocketError>
+    where
+        Fut: Future<Output = Result<(ResponseMeta, Bytes), SocketError>>,
+    {
+        let waiter = {
+            let mut inflight = self.lock();
+            match inflight.get_mut(&key) {
+                Some(waiters) => {
+                    let (tx, rx) = oneshot::channel();
+                    waiters.push(tx);
+                    Some(rx)
+                }
+                None => {
+                    inflight.insert(key.clone(), Vec::new());
+                    None
+                }
+            }
+        };
+
+        match waiter {
+            Some(rx) => match rx.await {
+                Ok(shared) => shared.map_err(SocketError::Coalesced),
+                // Leading request was cancelled, so execute independently
+                Err(_) => request.await,
+            },
+            None => {
+                let mut guard = LeaderGuard {
+                    coalescer: self,
+                    key: Some(key),
+                };
+                let outcome = request.await;
+
+                let shared = match &outcome {
+                    Ok(response) => Ok(response.clone()),
+                    Err(error) => Err(error.to_string()),
+                };
+                for waiter in guard.complete() {
+                    let _ = waiter.send(shared.clone());
+                }
+
+                outcome
+            }
+        }
+    }
+
+    fn lock(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<InflightKey, Vec<oneshot::Sender<Shared>>>> {
+        self.inflight
+            .lock()
+            .expect("InflightCoalescer Mutex poisoned")
+    }
+}
+
+/// Removes the leading request's key upon completion or cancellation, such that subsequent
+/// requests are not coalesced with it.
+struct LeaderGuard<'a> {
+    coalescer: &'a InflightCoalescer,
+    key: Option<InflightKey>,
+}
+
+impl LeaderGuard<'_> {
+    fn complete(&mut self) -> Vec<oneshot::Sender<Shared>> {
+        self.key
+            .take()
+            .and_then(|key| self.coalescer.lock().remove(&key))
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for LeaderGuard<'_> {
+    fn drop(&mut self) {
+        self.complete();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::Metric;
+    use std::sync::atomic::AtomicUsize;
+
+    #[tokio::test]
+    async fn test_inflight_coalescer() {
+        let coalescer = InflightCoalescer::new();
+        let executions = AtomicUsize::new(0);
+        let key = InflightKey {
+            scope: ClientScope::unique(),
+            method: reqwest::Method::GET,
+            url: "https://api.x.com/exchangeInfo".to_string(),
+            headers: Vec::new(),
+            account_id: None,
+        };
+
+        let request = || async {
+            executions.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            Ok((
+                ResponseMeta {
+                    status: reqwest::StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    latency: Metric::empty("http_request_duration"),
+                    rate_limit: None,
+                    idempotency_key: None,
+                },
+                Bytes::from_static(b"{}"),
+            ))
+        };
+
+        let (a, b, c) = tokio::join!(
+            coalescer.execute(key.clone(), request()),
+            coalescer.execute(key.clone(), request()),
+            coalescer.execute(key.clone(), request()),
+        );
+
+        assert_eq!(executions.load(Ordering::SeqCst), 1);
+        for outcome in [a, b, c] {
+            assert_eq!(outcome.unwrap().1, Bytes::from_static(b"{}"));
+        }
+        assert_eq!(coalescer.inflight(&key), 0);
+
+        // Subsequent requests are not coalesced with completed requests
+        coalescer.execute(key, request()).await.unwrap();
+        assert_eq!(executions.load(Ordering::SeqCst), 2);
+    }
+}
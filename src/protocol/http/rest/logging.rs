@@ -0,0 +1,213 @@
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+use tracing::debug;
+use url::Url;
+
+/// Placeholder substituted for redacted values.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Opt-in debug logging of every [`RestClient`](super::client::RestClient) request & response,
+/// recording the method, url, status & latency.
+///
+/// Header, query parameter & body field values whose name contains any of the configured keys
+/// (case-insensitive) are redacted, so credentials never hit logs.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RequestLogger {
+    /// Case-insensitive keys used to redact header, query parameter & body field values.
+    pub redact: Vec<String>,
+
+    /// Log request bodies (redacted).
+    pub log_bodies: bool,
+}
+
+impl Default for RequestLogger {
+    fn default() -> Self {
+        Self {
+            redact: [
+                "authorization",
+                "key",
+                "sign",
+                "secret",
+                "passphrase",
+                "token",
+                "otp",
+            ]
+            .map(String::from)
+            .to_vec(),
+            log_bodies: false,
+        }
+    }
+}
+
+impl RequestLogger {
+    /// Redact values of the additional key, as well as the defaults.
+    pub fn with_redacted<S>(mut self, key: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.redact.push(key.into().to_lowercase());
+        self
+    }
+
+    /// Log request bodies (redacted).
+    pub fn with_bodies(self) -> Self {
+        Self {
+            log_bodies: true,
+            ..self
+        }
+    }
+
+    /// Determine if values of the provided header, query parameter or field name are redacted.
+    pub fn is_redacted(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        self.redact
+            .iter()
+            .any(|key| name.contains(key.to_lowercase().as_str()))
+    }
+
+    /// Log the provided [`reqwest::Request`] at `debug` level.
+    pub fn log_request(&self, request: &reqwest::Request) {
+        let body = self
+            .log_bodies
+            .then(|| request.body().and_then(|body| body.as_bytes()))
+            .flatten()
+            .map(|body| self.redact_body(body));
+
+        debug!(
+            method = %request.method(),
+            url = %self.redact_url(request.url()),
+            headers = ?self.redact_headers(request.headers()),
+            body,
+            "executing RestRequest"
+        );
+    }
+
+    /// Log the response to a request at `debug` level.
+    pub fn log_response(&self, method: &Method, url: &Url, status: StatusCode, latency: Duration) {
+        debug!(
+            %method,
+            url = %self.redact_url(url),
+            %status,
+            ?latency,
+            "received RestRequest response"
+        );
+    }
+
+    /// Redact the values of matching headers.
+    pub fn redact_headers(&self, headers: &HeaderMap) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if self.is_redacted(name.as_str()) {
+                    REDACTED.to_string()
+                } else {
+                    String::from_utf8_lossy(value.as_bytes()).into_owned()
+                };
+                (name.to_string(), value)
+            })
+            .collect()
+    }
+
+    /// Redact the values of matching query parameters.
+    pub fn redact_url(&self, url: &Url) -> String {
+        if url.query().is_none() {
+            return url.to_string();
+        }
+
+        let mut redacted = url.clone();
+        redacted
+            .query_pairs_mut()
+            .clear()
+            .extend_pairs(url.query_pairs().map(|(name, value)| {
+                let value = if self.is_redacted(&name) {
+                    REDACTED.into()
+                } else {
+                    value
+                };
+                (name, value)
+            }));
+        redacted.to_string()
+    }
+
+    /// Redact the values of matching fields in a JSON or form url encoded body.
+    pub fn redact_body(&self, body: &[u8]) -> String {
+        if let Ok(mut json) = serde_json::from_slice::<Value>(body) {
+            self.redact_json(&mut json);
+            return json.to_string();
+        }
+
+        String::from_utf8_lossy(body)
+            .split('&')
+            .map(|pair| match pair.split_once('=') {
+                Some((name, _)) if self.is_redacted(name) => format!("{name}={REDACTED}"),
+                _ => pair.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    fn redact_json(&self, value: &mut Value) {
+        match value {
+            Value::Object(fields) => {
+                for (name, value) in fields.iter_mut() {
+                    if self.is_redacted(name) {
+                        *value = Value::from(REDACTED);
+                    } else {
+                        self.redact_json(value);
+                    }
+                }
+            }
+            Value::Array(values) => values.iter_mut().for_each(|value| self.redact_json(value)),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_request_logger_redaction() {
+        let logger = RequestLogger::default().with_redacted("nonce");
+
+        let headers = HeaderMap::from_iter([
+            (
+                reqwest::header::HeaderName::from_static("x-mbx-apikey"),
+                HeaderValue::from_static("my_key"),
+            ),
+            (
+                reqwest::header::CONTENT_TYPE,
+                HeaderValue::from_static("application/json"),
+            ),
+        ]);
+        assert_eq!(
+            logger.redact_headers(&headers),
+            vec![
+                ("x-mbx-apikey".to_string(), REDACTED.to_string()),
+                ("content-type".to_string(), "application/json".to_string()),
+            ]
+        );
+
+        let url = Url::parse("https://api.x.com/order?symbol=BTCUSDT&signature=abc").unwrap();
+        assert_eq!(
+            logger.redact_url(&url),
+            "https://api.x.com/order?symbol=BTCUSDT&signature=%5BREDACTED%5D"
+        );
+
+        let json = br#"{"symbol":"BTCUSDT","auth":{"apiKey":"k","nonce":1}}"#;
+        assert_eq!(
+            logger.redact_body(json),
+            r#"{"auth":{"apiKey":"[REDACTED]","nonce":"[REDACTED]"},"symbol":"BTCUSDT"}"#
+        );
+
+        let form = b"pair=XBTUSD&nonce=1&otp=2";
+        assert_eq!(
+            logger.redact_body(form),
+            "pair=XBTUSD&nonce=[REDACTED]&otp=[REDACTED]"
+        );
+    }
+}
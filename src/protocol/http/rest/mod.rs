@@ -1,12 +1,69 @@
-use serde::{de::DeserializeOwned, Serialize};
+use super::ResponseFormat;
+use crate::error::SocketError;
+use reqwest::{
+    header::{HeaderMap, CONTENT_TYPE},
+    RequestBuilder,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::time::Duration;
 
 /// Configurable [`client::RestClient`] capable of executing signed [`RestRequest`]s and parsing
 /// responses.
 pub mod client;
 
+/// [`RestClientBuilder`](builder::RestClientBuilder) for configuring a
+/// [`RestClient`](self::client::RestClient) and its underlying [`reqwest::Client`].
+pub mod builder;
+
+/// Token bucket [`RateLimiter`](rate_limit::RateLimiter) used by a
+/// [`RestClient`](self::client::RestClient) to await capacity before sending requests.
+pub mod rate_limit;
+
+/// [`RateLimitParser`](usage::RateLimitParser)s that extract the server communicated rate limit
+/// usage from response headers.
+pub mod usage;
+
+/// [`RetryPolicy`](retry::RetryPolicy) with exponential backoff applied by a
+/// [`RestClient`](self::client::RestClient) to transient failures.
+pub mod retry;
+
+/// Opt-in [`RequestLogger`](logging::RequestLogger) that logs requests & responses with secrets
+/// redacted.
+pub mod logging;
+
+/// [`InflightCoalescer`](inflight::InflightCoalescer) that shares one in-flight request between
+/// concurrent identical idempotent requests.
+pub mod inflight;
+
+/// Per endpoint [`CircuitBreaker`](circuit::CircuitBreaker) that short-circuits requests to a
+/// failing venue.
+pub mod circuit;
+
+/// Opt-in TTL [`ResponseCache`](cache::ResponseCache) of idempotent request responses.
+pub mod cache;
+
+/// [`PathTemplate`](path::PathTemplate) that renders [`RestRequest`] paths with percent-encoded
+/// path parameters.
+pub mod path;
+
+/// Newline delimited JSON decoding of streamed response bodies.
+pub mod ndjson;
+
+/// Cursor based [`Paginated`](paginate::Paginated) [`RestRequest`]s that can be executed as a
+/// `Stream` of pages.
+pub mod paginate;
+
+/// [`GraphQl`](graphql::GraphQl) requests (query & variables) executed via a
+/// [`RestClient`](self::client::RestClient), with response envelope error handling.
+pub mod graphql;
+
+/// Synchronous [`BlockingRestClient`](blocking::BlockingRestClient) facade for CLI tools &
+/// scripts.
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
 /// Default Http [`reqwest::Request`] timeout Duration.
-const DEFAULT_HTTP_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+pub(crate) const DEFAULT_HTTP_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
 
 /// Http REST request that can be executed by a [`RestClient`](self::client::RestClient).
 pub trait RestRequest {
@@ -20,10 +77,34 @@ pub trait RestRequest {
     type Body: Serialize;
 
     /// Additional [`Url`](url::Url) path to the resource.
+    ///
+    /// Requests providing a [`path_template`](Self::path_template) return the raw template
+    /// here, which the [`RestClient`](self::client::RestClient) renders in its place.
     fn path(&self) -> std::borrow::Cow<'static, str>;
 
-    /// Http [`reqwest::Method`] of this request.
-    fn method() -> reqwest::Method;
+    /// Optional [`PathTemplate`](path::PathTemplate) & parameters the path is rendered from
+    /// when the request is built, used for paths with parameters (eg/ `/orders/{order_id}`).
+    ///
+    /// Render errors are surfaced by the [`RestClient`](self::client::RestClient), rather than
+    /// panicking within [`path`](Self::path).
+    fn path_template(&self) -> Option<path::PathTemplate> {
+        None
+    }
+
+    /// Http [`reqwest::Method`] of this request. Defaults to GET.
+    fn method(&self) -> reqwest::Method {
+        reqwest::Method::GET
+    }
+
+    /// Encoding used to attach the [`Self::Body`] to the request.
+    fn body_kind() -> BodyKind {
+        BodyKind::Json
+    }
+
+    /// Encoding of the [`Self::Response`] payload.
+    fn response_format() -> ResponseFormat {
+        ResponseFormat::Json
+    }
 
     /// Optional query parameters for this request.
     fn query_params(&self) -> Option<&Self::QueryParams> {
@@ -35,8 +116,303 @@ pub trait RestRequest {
         None
     }
 
+    /// Additional non-authentication headers for this request.
+    ///
+    /// eg/ `X-MBX-APIKEY` on public but key-gated routes.
+    fn headers(&self) -> HeaderMap {
+        HeaderMap::new()
+    }
+
+    /// Key used to apply a per-endpoint [`RateLimit`](rate_limit::RateLimit) override, if
+    /// configured on the [`RateLimiter`](rate_limit::RateLimiter).
+    fn rate_limit_key(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Rate limit weight consumed by this request. Defaults to 1.
+    ///
+    /// eg/ Binance assigns a weight per endpoint. Combine with a
+    /// [`RateLimitParser`](usage::RateLimitParser) (eg/ [`MbxUsedWeight`](usage::MbxUsedWeight))
+    /// to keep the [`RateLimiter`](rate_limit::RateLimiter) calibrated with the server.
+    fn weight(&self) -> u32 {
+        1
+    }
+
+    /// [`RequestPriority`](rate_limit::RequestPriority) hint used by the
+    /// [`RateLimiter`](rate_limit::RateLimiter) when capacity is scarce. Defaults to `Normal`.
+    ///
+    /// eg/ Order management requests should be `High`, so they preempt market data requests.
+    fn priority(&self) -> rate_limit::RequestPriority {
+        rate_limit::RequestPriority::Normal
+    }
+
+    /// Idempotency key of this request (eg/ client order id, request id), injected by the
+    /// [`BuildStrategy`](super::BuildStrategy) & surfaced via the
+    /// [`ResponseMeta`](client::ResponseMeta).
+    ///
+    /// The same key is sent with every retry attempt, so retried order submissions are safe. Must
+    /// be stable across calls, eg/ generated when the request is constructed.
+    fn idempotency_key(&self) -> Option<String> {
+        None
+    }
+
+    /// Id of the account whose credentials sign this request, when executed by a
+    /// [`MultiAccount`](super::private::account::MultiAccount) strategy. Defaults to `None`
+    /// (the default account).
+    ///
+    /// eg/ Sub-account key id. See [`AsAccount`](super::private::account::AsAccount).
+    fn account_id(&self) -> Option<String> {
+        None
+    }
+
+    /// Duration a successful response to this request may be served from a
+    /// [`ResponseCache`](cache::ResponseCache), if configured. Defaults to `None` (not cached).
+    ///
+    /// Only `GET` & `HEAD` requests are cached. eg/ Rarely changing exchange info endpoints.
+    fn cache_ttl(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Validity window of this signed request, overriding the client wide default of a
+    /// [`RecvWindow`](super::private::recv_window::RecvWindow). Defaults to `None`.
+    ///
+    /// eg/ Latency sensitive order requests may use a shorter window than account queries.
+    fn recv_window(&self) -> Option<Duration> {
+        None
+    }
+
     /// Http request timeout [`Duration`].
     fn timeout() -> Duration {
         DEFAULT_HTTP_REQUEST_TIMEOUT
     }
 }
+
+/// Borrowed [`RestRequest`]s are themselves [`RestRequest`]s, such that a
+/// [`RestClient`](self::client::RestClient) can rebuild & re-sign the same request for every
+/// retry attempt.
+impl<Request> RestRequest for &Request
+where
+    Request: RestRequest,
+{
+    type Response = Request::Response;
+    type QueryParams = Request::QueryParams;
+    type Body = Request::Body;
+
+    fn path(&self) -> std::borrow::Cow<'static, str> {
+        (*self).path()
+    }
+
+    fn path_template(&self) -> Option<path::PathTemplate> {
+        (*self).path_template()
+    }
+
+    fn method(&self) -> reqwest::Method {
+        (*self).method()
+    }
+
+    fn body_kind() -> BodyKind {
+        Request::body_kind()
+    }
+
+    fn response_format() -> ResponseFormat {
+        Request::response_format()
+    }
+
+    fn query_params(&self) -> Option<&Self::QueryParams> {
+        (*self).query_params()
+    }
+
+    fn body(&self) -> Option<&Self::Body> {
+        (*self).body()
+    }
+
+    fn headers(&self) -> HeaderMap {
+        (*self).headers()
+    }
+
+    fn rate_limit_key(&self) -> Option<&'static str> {
+        (*self).rate_limit_key()
+    }
+
+    fn weight(&self) -> u32 {
+        (*self).weight()
+    }
+
+    fn priority(&self) -> rate_limit::RequestPriority {
+        (*self).priority()
+    }
+
+    fn idempotency_key(&self) -> Option<String> {
+        (*self).idempotency_key()
+    }
+
+    fn account_id(&self) -> Option<String> {
+        (*self).account_id()
+    }
+
+    fn cache_ttl(&self) -> Option<Duration> {
+        (*self).cache_ttl()
+    }
+
+    fn recv_window(&self) -> Option<Duration> {
+        (*self).recv_window()
+    }
+
+    fn timeout() -> Duration {
+        Request::timeout()
+    }
+}
+
+/// Encoding used by a [`RestClient`](self::client::RestClient) to attach a [`RestRequest::Body`].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum BodyKind {
+    /// `application/json` body.
+    #[default]
+    Json,
+    /// `application/x-www-form-urlencoded` body. eg/ Kraken, Bitstamp.
+    Form,
+    /// `text/plain` body, for bodies that serialise to a string.
+    Text,
+    /// No body is attached, even if one is provided.
+    None,
+}
+
+impl BodyKind {
+    /// Attach the provided body to the [`RequestBuilder`] using this [`BodyKind`] encoding.
+    pub fn attach<Body>(
+        &self,
+        builder: RequestBuilder,
+        body: &Body,
+    ) -> Result<RequestBuilder, SocketError>
+    where
+        Body: Serialize,
+    {
+        match self {
+            BodyKind::Json => Ok(builder.json(body)),
+            BodyKind::Form => Ok(builder.form(body)),
+            BodyKind::Text => match serde_json::to_value(body).map_err(SocketError::Serialise)? {
+                serde_json::Value::String(text) => {
+                    Ok(builder.header(CONTENT_TYPE, "text/plain").body(text))
+                }
+                other => Err(SocketError::Unsupported {
+                    entity: "BodyKind::Text",
+                    item: other.to_string(),
+                }),
+            },
+            BodyKind::None => Ok(builder),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_body_kind_attach() {
+        #[derive(Serialize)]
+        struct Order {
+            pair: &'static str,
+            volume: f64,
+        }
+
+        struct TestCase {
+            kind: BodyKind,
+            body: serde_json::Value,
+            expected: Option<(&'static str, &'static str)>,
+        }
+
+        let order = serde_json::to_value(Order {
+            pair: "XBTUSD",
+            volume: 1.5,
+        })
+        .unwrap();
+
+        let cases = vec![
+            TestCase {
+                // TC0: Json body
+                kind: BodyKind::Json,
+                body: order.clone(),
+                expected: Some(("application/json", r#"{"pair":"XBTUSD","volume":1.5}"#)),
+            },
+            TestCase {
+                // TC1: Form url encoded body
+                kind: BodyKind::Form,
+                body: order.clone(),
+                expected: Some((
+                    "application/x-www-form-urlencoded",
+                    "pair=XBTUSD&volume=1.5",
+                )),
+            },
+            TestCase {
+                // TC2: Text body
+                kind: BodyKind::Text,
+                body: serde_json::Value::from("raw"),
+                expected: Some(("text/plain", "raw")),
+            },
+            TestCase {
+                // TC3: No body attached
+                kind: BodyKind::None,
+                body: order,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let builder = reqwest::Client::new().post("https://api.x.com");
+            let request = test
+                .kind
+                .attach(builder, &test.body)
+                .unwrap()
+                .build()
+                .unwrap();
+
+            let actual = request.body().map(|body| {
+                (
+                    request.headers()[CONTENT_TYPE].to_str().unwrap(),
+                    std::str::from_utf8(body.as_bytes().unwrap()).unwrap(),
+                )
+            });
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+
+        // Text bodies must serialise to a string
+        let builder = reqwest::Client::new().post("https://api.x.com");
+        assert!(BodyKind::Text
+            .attach(builder, &serde_json::json!({ "a": 1 }))
+            .is_err());
+    }
+
+    #[test]
+    fn test_rest_request_headers() {
+        struct KeyGatedRequest;
+
+        impl RestRequest for KeyGatedRequest {
+            type Response = ();
+            type QueryParams = ();
+            type Body = ();
+
+            fn path(&self) -> std::borrow::Cow<'static, str> {
+                std::borrow::Cow::Borrowed("/api/v3/exchangeInfo")
+            }
+
+            fn headers(&self) -> HeaderMap {
+                HeaderMap::from_iter([(
+                    reqwest::header::HeaderName::from_static("x-mbx-apikey"),
+                    reqwest::header::HeaderValue::from_static("key"),
+                )])
+            }
+        }
+
+        let client = client::RestClient::new(
+            "https://api.x.com",
+            crate::protocol::http::public::PublicNoHeaders,
+            crate::test_util::TestParser,
+        );
+        let request = client.build(KeyGatedRequest).unwrap();
+        assert_eq!(request.headers()["x-mbx-apikey"], "key");
+        assert_eq!(request.method(), reqwest::Method::GET);
+    }
+}
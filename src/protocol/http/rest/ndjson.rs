@@ -0,0 +1,111 @@
+use crate::error::SocketError;
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+
+/// Decode a `Stream` of response body chunks (eg/ from
+/// [`RestClient::execute_stream`](super::client::RestClient::execute_stream)) as newline
+/// delimited JSON, yielding each deserialised line as soon as it is complete.
+///
+/// Lines may span many chunks, and empty lines are skipped. The `Stream` ends after the body
+/// ends, or after yielding the first chunk error.
+pub fn decode_ndjson<St, T>(chunks: St) -> impl Stream<Item = Result<T, SocketError>>
+where
+    St: Stream<Item = Result<Bytes, SocketError>> + Unpin,
+    T: DeserializeOwned,
+{
+    let state = NdJson {
+        chunks,
+        buffer: Vec::new(),
+        lines: VecDeque::new(),
+        done: false,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(line) = state.lines.pop_front() {
+                return Some((decode(line), state));
+            }
+            if state.done {
+                return None;
+            }
+
+            match state.chunks.next().await {
+                Some(Ok(chunk)) => state.push(&chunk),
+                Some(Err(error)) => {
+                    state.done = true;
+                    state.lines.clear();
+                    return Some((Err(error), state));
+                }
+                None => {
+                    state.done = true;
+                    let remaining = std::mem::take(&mut state.buffer);
+                    state.push_line(remaining);
+                }
+            }
+        }
+    })
+}
+
+struct NdJson<St> {
+    chunks: St,
+    buffer: Vec<u8>,
+    lines: VecDeque<Vec<u8>>,
+    done: bool,
+}
+
+impl<St> NdJson<St> {
+    /// Append a chunk to the buffer, splitting off every complete line.
+    fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+        while let Some(newline) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line = self.buffer.drain(..=newline).collect();
+            self.push_line(line);
+        }
+    }
+
+    fn push_line(&mut self, line: Vec<u8>) {
+        if !line.trim_ascii().is_empty() {
+            self.lines.push_back(line);
+        }
+    }
+}
+
+fn decode<T>(line: Vec<u8>) -> Result<T, SocketError>
+where
+    T: DeserializeOwned,
+{
+    serde_json::from_slice(line.trim_ascii()).map_err(|error| SocketError::DeserialiseBinary {
+        error,
+        payload: line,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Candle {
+        close: f64,
+    }
+
+    #[tokio::test]
+    async fn test_decode_ndjson() {
+        let chunks = [
+            "{\"close\":1.0}\n{\"clo",
+            "se\":2.0}\n\n",
+            "not_json\n{\"close\":3.0}",
+        ]
+        .map(|chunk| Ok(Bytes::from(chunk)));
+
+        let actual = decode_ndjson::<_, Candle>(futures::stream::iter(chunks))
+            .map(|candle| candle.map(|candle| candle.close).ok())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(actual, vec![Some(1.0), Some(2.0), None, Some(3.0)]);
+    }
+}
@@ -0,0 +1,100 @@
+use super::RestRequest;
+
+/// [`RestRequest`] for a cursor paginated resource, describing how to extract the next cursor
+/// from a [`RestRequest::Response`] and how to request the page it identifies.
+///
+/// Used by [`RestClient::execute_paginated`](super::client::RestClient::execute_paginated) to
+/// produce a `Stream` of pages, removing hand-rolled pagination loops.
+pub trait Paginated: RestRequest + Sized {
+    /// Cursor identifying the next page (eg/ `fromId`, `nextPageToken`, etc.).
+    type Cursor;
+
+    /// Extract the cursor of the next page from the provided response, if there is one.
+    fn next_cursor(response: &Self::Response) -> Option<Self::Cursor>;
+
+    /// Construct the [`RestRequest`] for the page identified by the provided cursor.
+    fn with_cursor(&self, cursor: Self::Cursor) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        protocol::http::{public::PublicNoHeaders, rest::client::RestClient},
+        test_util::{ok, MockServer, TestParser},
+    };
+    use futures::StreamExt;
+    use serde::Deserialize;
+    use std::borrow::Cow;
+
+    #[derive(Clone)]
+    struct FetchTrades {
+        from_id: Option<u64>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Trades {
+        trades: Vec<u64>,
+        next: Option<u64>,
+    }
+
+    impl RestRequest for FetchTrades {
+        type Response = Trades;
+        type QueryParams = ();
+        type Body = ();
+
+        fn path(&self) -> Cow<'static, str> {
+            match self.from_id {
+                Some(from_id) => Cow::Owned(format!("/trades/{from_id}")),
+                None => Cow::Borrowed("/trades/0"),
+            }
+        }
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::GET
+        }
+    }
+
+    impl Paginated for FetchTrades {
+        type Cursor = u64;
+
+        fn next_cursor(response: &Self::Response) -> Option<Self::Cursor> {
+            response.next
+        }
+
+        fn with_cursor(&self, cursor: Self::Cursor) -> Self {
+            Self {
+                from_id: Some(cursor),
+            }
+        }
+    }
+
+    /// Serve each `/trades/{from_id}` request with two trades & the next cursor, ending at 4.
+    fn serve_trades(request: &str) -> Option<Vec<u8>> {
+        let from_id: u64 = request
+            .split_whitespace()
+            .nth(1)
+            .and_then(|path| path.strip_prefix("/trades/"))
+            .and_then(|from_id| from_id.parse().ok())
+            .unwrap();
+
+        let next = (from_id + 2 < 6).then_some(from_id + 2);
+        Some(ok(
+            serde_json::json!({ "trades": [from_id, from_id + 1], "next": next }).to_string(),
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_execute_paginated() {
+        let server = MockServer::start(serve_trades);
+
+        let client = RestClient::new(server.base_url.clone(), PublicNoHeaders, TestParser);
+        let trades = client
+            .execute_paginated(FetchTrades { from_id: None })
+            .map(|page| page.unwrap().0.trades)
+            .concat()
+            .await;
+
+        assert_eq!(trades, vec![0, 1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,90 @@
+use super::RestRequest;
+use crate::{error::SocketError, protocol::template::Template};
+use std::{borrow::Cow, collections::BTreeMap, fmt::Display};
+
+/// [`RestRequest::path_template`] with named `{param}` placeholders, and the typed parameter
+/// values substituted into it.
+///
+/// Rendered using a [`Template`] when the [`RestClient`](super::client::RestClient) builds the
+/// request, such that every parameter is percent-encoded as a single path segment rather than
+/// hand-formatted in each integration, and render errors are surfaced as a
+/// [`SocketError::UrlTemplate`].
+///
+/// eg/ `PathTemplate::new("/api/v3/orders/{order_id}").param("order_id", order_id)`
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct PathTemplate {
+    template: &'static str,
+    params: BTreeMap<String, String>,
+}
+
+impl PathTemplate {
+    /// Construct a new [`PathTemplate`] from the provided template, without any parameters.
+    pub fn new(template: &'static str) -> Self {
+        Self {
+            template,
+            params: BTreeMap::new(),
+        }
+    }
+
+    /// Substitute the provided value into the `{param}` placeholder with the provided name.
+    pub fn param<Value>(mut self, name: &str, value: Value) -> Self
+    where
+        Value: Display,
+    {
+        self.params.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Raw template, including `{param}` placeholders.
+    ///
+    /// eg/ Used to key per-endpoint state independently of the parameter values.
+    pub fn template(&self) -> &'static str {
+        self.template
+    }
+
+    /// Render the template, substituting every `{param}` with its percent-encoded value.
+    ///
+    /// Fails if a placeholder is unclosed, or has no corresponding parameter (or vice versa).
+    pub fn render(&self) -> Result<String, SocketError> {
+        Template::parse(self.template)?.render(&self.params)
+    }
+}
+
+/// Render the path of the provided [`RestRequest`], using its
+/// [`path_template`](RestRequest::path_template) if provided, falling back to its
+/// [`path`](RestRequest::path).
+pub fn render_path<Request>(request: &Request) -> Result<Cow<'static, str>, SocketError>
+where
+    Request: RestRequest,
+{
+    match request.path_template() {
+        Some(template) => template.render().map(Cow::Owned),
+        None => Ok(request.path()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_template_render() {
+        let template = PathTemplate::new("/api/v3/{symbol}/orders/{order_id}")
+            .param("symbol", "BTC/USDT ?")
+            .param("order_id", 42);
+        assert_eq!(
+            template.render().unwrap(),
+            "/api/v3/BTC%2FUSDT%20%3F/orders/42"
+        );
+        assert_eq!(template.template(), "/api/v3/{symbol}/orders/{order_id}");
+
+        let missing = PathTemplate::new("/api/v3/{symbol}/orders/{order_id}").param("symbol", "x");
+        assert!(matches!(missing.render(), Err(SocketError::UrlTemplate(_))));
+
+        assert!(PathTemplate::new("/orders/{order_id").render().is_err());
+        assert_eq!(
+            PathTemplate::new("/api/v3/time").render().unwrap(),
+            "/api/v3/time"
+        );
+    }
+}
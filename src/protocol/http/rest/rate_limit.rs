@@ -0,0 +1,384 @@
+use super::usage::RateLimitUsage;
+use crate::{
+    error::SocketError,
+    runtime::{Runtime, TokioRuntime},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Token bucket rate limit configuration, allowing `capacity` request weight per `interval`.
+///
+/// eg/ `RateLimit::new(1200, Duration::from_secs(60))` for 1200 weight per minute.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct RateLimit {
+    /// Maximum request weight that can be consumed in a burst.
+    pub capacity: u32,
+    /// Interval over which the full `capacity` is replenished.
+    pub interval: Duration,
+}
+
+impl RateLimit {
+    pub fn new(capacity: u32, interval: Duration) -> Self {
+        Self { capacity, interval }
+    }
+}
+
+/// Priority of a request awaiting [`RateLimiter`] capacity.
+///
+/// When capacity is scarce, waiting requests of a higher priority are granted capacity before
+/// any request of a lower priority. eg/ Order management preempting market data & maintenance.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum RequestPriority {
+    /// eg/ Maintenance, reference data refreshes.
+    Low,
+    /// eg/ Market data.
+    #[default]
+    Normal,
+    /// eg/ Order management.
+    High,
+}
+
+impl RequestPriority {
+    const COUNT: usize = 3;
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Token bucket rate limiter used by a [`RestClient`](super::client::RestClient) to
+/// transparently await capacity before sending requests, preventing 429 bans.
+///
+/// Supports a client-wide [`RateLimit`], plus per-endpoint overrides keyed by
+/// [`RestRequest::rate_limit_key`](super::RestRequest::rate_limit_key). Requests to an endpoint
+/// with an override must acquire capacity from both the endpoint & client-wide buckets.
+///
+/// When capacity is scarce, requests of a higher [`RequestPriority`] (see
+/// [`RestRequest::priority`](super::RestRequest::priority)) preempt waiting lower priority
+/// requests.
+///
+/// Cheaply cloneable, with every clone sharing the same buckets.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    global: Option<Arc<TokenBucket>>,
+    endpoints: HashMap<&'static str, Arc<TokenBucket>>,
+    runtime: Arc<dyn Runtime>,
+}
+
+impl RateLimiter {
+    /// Construct a new [`RateLimiter`] applying the provided client-wide [`RateLimit`].
+    ///
+    /// Fails if the [`RateLimit`] has a capacity of zero, since it could never be satisfied.
+    pub fn new(limit: RateLimit) -> Result<Self, SocketError> {
+        Ok(Self {
+            global: Some(Arc::new(TokenBucket::new(limit)?)),
+            endpoints: HashMap::new(),
+            runtime: Arc::new(TokioRuntime),
+        })
+    }
+
+    /// Construct a new [`RateLimiter`] with no client-wide [`RateLimit`], only endpoint
+    /// overrides.
+    pub fn endpoints_only() -> Self {
+        Self {
+            global: None,
+            endpoints: HashMap::new(),
+            runtime: Arc::new(TokioRuntime),
+        }
+    }
+
+    /// Apply a [`RateLimit`] override to requests with the provided
+    /// [`RestRequest::rate_limit_key`](super::RestRequest::rate_limit_key).
+    ///
+    /// Fails if the [`RateLimit`] has a capacity of zero, since it could never be satisfied.
+    pub fn with_endpoint(
+        mut self,
+        key: &'static str,
+        limit: RateLimit,
+    ) -> Result<Self, SocketError> {
+        self.endpoints
+            .insert(key, Arc::new(TokenBucket::new(limit)?));
+        Ok(self)
+    }
+
+    /// Use the provided [`Runtime`] timer whilst awaiting capacity, rather than tokio.
+    pub fn with_runtime<R>(self, runtime: R) -> Self
+    where
+        R: Runtime + 'static,
+    {
+        Self {
+            runtime: Arc::new(runtime),
+            ..self
+        }
+    }
+
+    /// Wait until the provided request weight can be consumed for the endpoint key.
+    pub async fn acquire(&self, key: Option<&str>, weight: u32) {
+        self.acquire_with_priority(key, weight, RequestPriority::default())
+            .await
+    }
+
+    /// Wait until the provided request weight can be consumed for the endpoint key, yielding
+    /// capacity to any waiting requests of a higher [`RequestPriority`].
+    pub async fn acquire_with_priority(
+        &self,
+        key: Option<&str>,
+        weight: u32,
+        priority: RequestPriority,
+    ) {
+        if let Some(endpoint) = key.and_then(|key| self.endpoints.get(key)) {
+            endpoint
+                .acquire(weight, priority, self.runtime.as_ref())
+                .await;
+        }
+        if let Some(global) = &self.global {
+            global
+                .acquire(weight, priority, self.runtime.as_ref())
+                .await;
+        }
+    }
+
+    /// Synchronise with the [`RateLimitUsage`] communicated by the server, such that usage by
+    /// other clients sharing the same limit, and server imposed `Retry-After` delays, are
+    /// respected.
+    ///
+    /// Applied to the client-wide bucket if configured, otherwise the endpoint bucket.
+    pub fn observe(&self, key: Option<&str>, usage: &RateLimitUsage) {
+        let bucket = self
+            .global
+            .as_ref()
+            .or_else(|| key.and_then(|key| self.endpoints.get(key)));
+
+        if let Some(bucket) = bucket {
+            bucket.observe(usage);
+        }
+    }
+}
+
+/// Single token bucket, refilled continuously at `capacity / interval`.
+#[derive(Debug)]
+struct TokenBucket {
+    limit: RateLimit,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+    /// Number of requests of each [`RequestPriority`] waiting for capacity.
+    waiting: [usize; RequestPriority::COUNT],
+}
+
+/// Registration of a request waiting for [`TokenBucket`] capacity, deregistered on drop such
+/// that cancelled requests no longer preempt lower priorities.
+struct Waiting<'a> {
+    bucket: &'a TokenBucket,
+    priority: RequestPriority,
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        let mut state = self.bucket.lock();
+        state.waiting[self.priority.index()] -= 1;
+    }
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit) -> Result<Self, SocketError> {
+        if limit.capacity == 0 {
+            return Err(SocketError::InvalidConfig(format!(
+                "RateLimit capacity must be non-zero: {limit:?}"
+            )));
+        }
+
+        Ok(Self {
+            limit,
+            state: Mutex::new(BucketState {
+                tokens: f64::from(limit.capacity),
+                last_refill: Instant::now(),
+                waiting: [0; RequestPriority::COUNT],
+            }),
+        })
+    }
+
+    fn refill_per_sec(&self) -> f64 {
+        f64::from(self.limit.capacity) / self.limit.interval.as_secs_f64().max(f64::EPSILON)
+    }
+
+    fn observe(&self, usage: &RateLimitUsage) {
+        let mut state = self.lock();
+
+        if let Some(remaining) = usage.remaining() {
+            state.tokens = state.tokens.min(f64::from(remaining));
+        }
+
+        // Negative tokens delay the next acquisition until the Retry-After has elapsed
+        if let Some(retry_after) = usage.retry_after {
+            state.tokens = state
+                .tokens
+                .min(-retry_after.as_secs_f64() * self.refill_per_sec());
+        }
+    }
+
+    async fn acquire(&self, weight: u32, priority: RequestPriority, runtime: &dyn Runtime) {
+        // Weights exceeding capacity could never be satisfied, so clamp to a full bucket
+        let weight = f64::from(weight.min(self.limit.capacity));
+        let capacity = f64::from(self.limit.capacity);
+        let refill_per_sec = self.refill_per_sec();
+        let mut waiting = None;
+
+        loop {
+            let wait = {
+                let mut state = self.lock();
+                let now = Instant::now();
+                let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+                state.tokens = (state.tokens + elapsed * refill_per_sec).min(capacity);
+                state.last_refill = now;
+
+                // Yield capacity to any waiting requests of a higher priority
+                let preempted = state.waiting[priority.index() + 1..]
+                    .iter()
+                    .any(|waiting| *waiting > 0);
+
+                if !preempted && state.tokens >= weight {
+                    state.tokens -= weight;
+                    if let Some(waiting) = waiting.take() {
+                        // Deregister whilst the lock is held, rather than on drop
+                        state.waiting[priority.index()] -= 1;
+                        std::mem::forget::<Waiting<'_>>(waiting);
+                    }
+                    return;
+                }
+
+                if waiting.is_none() {
+                    state.waiting[priority.index()] += 1;
+                    waiting = Some(Waiting {
+                        bucket: self,
+                        priority,
+                    });
+                }
+
+                let required = if preempted {
+                    weight
+                } else {
+                    weight - state.tokens
+                };
+                Duration::from_secs_f64(required / refill_per_sec)
+            };
+
+            runtime.sleep(wait).await;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BucketState> {
+        self.state.lock().expect("TokenBucket Mutex poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rate_limiter_awaits_capacity() {
+        let limiter = RateLimiter::new(RateLimit::new(10, Duration::from_secs(60)))
+            .unwrap()
+            .with_endpoint("order", RateLimit::new(2, Duration::from_millis(50)))
+            .unwrap();
+
+        // Burst capacity is available immediately
+        let start = Instant::now();
+        limiter.acquire(Some("order"), 1).await;
+        limiter.clone().acquire(Some("order"), 1).await;
+        assert!(start.elapsed() < Duration::from_millis(20));
+
+        // Endpoint bucket is exhausted, so the next request waits for a refill
+        limiter.acquire(Some("order"), 1).await;
+        assert!(start.elapsed() >= Duration::from_millis(20));
+
+        // Client-wide bucket still has 7 of 10 tokens remaining
+        let start = Instant::now();
+        limiter.acquire(None, 7).await;
+        assert!(start.elapsed() < Duration::from_millis(20));
+    }
+
+    #[test]
+    fn test_rate_limiter_rejects_zero_capacity() {
+        let zero = RateLimit::new(0, Duration::from_secs(1));
+        assert!(matches!(
+            RateLimiter::new(zero),
+            Err(SocketError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            RateLimiter::endpoints_only().with_endpoint("order", zero),
+            Err(SocketError::InvalidConfig(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_priority() {
+        let limiter = RateLimiter::new(RateLimit::new(1, Duration::from_millis(40))).unwrap();
+        limiter.acquire(None, 1).await;
+
+        // Capacity is exhausted, so the High priority request is granted the next refill first,
+        // despite the Low priority request waiting first
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let low = {
+            let (limiter, order) = (limiter.clone(), Arc::clone(&order));
+            tokio::spawn(async move {
+                limiter
+                    .acquire_with_priority(None, 1, RequestPriority::Low)
+                    .await;
+                order.lock().unwrap().push(RequestPriority::Low);
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        limiter
+            .acquire_with_priority(None, 1, RequestPriority::High)
+            .await;
+        order.lock().unwrap().push(RequestPriority::High);
+        low.await.unwrap();
+
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec![RequestPriority::High, RequestPriority::Low]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_observe_usage() {
+        let limiter = RateLimiter::new(RateLimit::new(10, Duration::from_millis(100))).unwrap();
+
+        // Server reports capacity used by other clients, so the next request waits for a refill
+        limiter.observe(
+            None,
+            &RateLimitUsage {
+                remaining: Some(0),
+                ..RateLimitUsage::default()
+            },
+        );
+        let start = Instant::now();
+        limiter.acquire(None, 1).await;
+        assert!(start.elapsed() >= Duration::from_millis(5));
+
+        // Server imposed Retry-After delays the next request
+        limiter.observe(
+            None,
+            &RateLimitUsage {
+                retry_after: Some(Duration::from_millis(30)),
+                ..RateLimitUsage::default()
+            },
+        );
+        let start = Instant::now();
+        limiter.acquire(None, 1).await;
+        assert!(start.elapsed() >= Duration::from_millis(30));
+    }
+}
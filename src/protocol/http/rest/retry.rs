@@ -0,0 +1,267 @@
+use crate::error::SocketError;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::{header::HeaderMap, Method, StatusCode};
+use std::{
+    fmt::{Debug, Formatter},
+    time::Duration,
+};
+
+/// Outcome of a single Http request attempt, used to determine if it should be retried.
+#[derive(Debug)]
+pub enum Attempt<'a> {
+    /// Response was received with the provided [`StatusCode`].
+    Status(StatusCode),
+    /// Request failed before a response was received.
+    Error(&'a SocketError),
+}
+
+/// Configurable retry policy applied by a [`RestClient`](super::client::RestClient) to transient
+/// failures (eg/ timeouts, 5xx responses, connection resets), with exponential backoff & jitter.
+///
+/// `429 Too Many Requests` responses are retried after the server communicated `Retry-After`
+/// delay, within the same retry budget.
+///
+/// Non-idempotent requests (eg/ POST order) are never retried unless they carry a
+/// [`RestRequest::idempotency_key`](super::RestRequest::idempotency_key) (eg/ client order id) the
+/// server deduplicates by, that the [`BuildStrategy`](crate::protocol::http::BuildStrategy)
+/// [`injects`](crate::protocol::http::BuildStrategy::injects_idempotency_key), or retries are explicitly allowed via
+/// [`retry_non_idempotent`](Self::retry_non_idempotent), since the original request may have been
+/// actioned by the server.
+#[derive(Copy, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the initial request.
+    pub max_attempts: u32,
+
+    /// Backoff applied before the first retry.
+    pub initial_backoff: Duration,
+
+    /// Maximum backoff applied before any retry.
+    pub max_backoff: Duration,
+
+    /// Factor the backoff is multiplied by after each retry.
+    pub multiplier: f64,
+
+    /// Fraction of the backoff randomly added or subtracted. eg/ 0.2 for +/- 20%.
+    pub jitter: f64,
+
+    /// Retry non-idempotent requests (eg/ POST, PATCH).
+    pub retry_non_idempotent: bool,
+
+    /// Predicate determining if an [`Attempt`] should be retried.
+    pub retry_on: fn(&Attempt<'_>) -> bool,
+
+    /// Retry `429 Too Many Requests` responses after the server communicated
+    /// [`retry_after`](Self::retry_after) delay, falling back to the exponential backoff if no
+    /// delay is communicated.
+    pub retry_rate_limited: bool,
+
+    /// Response headers that communicate the delay before a rate limited request may be retried,
+    /// in order of preference. eg/ `&["retry-after", "x-ratelimit-reset-after"]`
+    ///
+    /// Values may be delay seconds, or a Http date.
+    pub retry_after_headers: &'static [&'static str],
+
+    /// Maximum server communicated delay that is waited for, beyond which the rate limited
+    /// response is surfaced instead.
+    pub max_retry_after: Duration,
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .field("retry_rate_limited", &self.retry_rate_limited)
+            .field("retry_after_headers", &self.retry_after_headers)
+            .field("max_retry_after", &self.max_retry_after)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+            multiplier: 2.0,
+            jitter: 0.2,
+            retry_non_idempotent: false,
+            retry_on: is_transient,
+            retry_rate_limited: true,
+            retry_after_headers: &["retry-after"],
+            max_retry_after: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Determine if requests with the provided Http [`Method`] & optional idempotency key may be
+    /// retried.
+    ///
+    /// Non-idempotent requests carrying an idempotency key are retried, since the server
+    /// deduplicates them by the key. The key must only be provided if it is sent to the server
+    /// (see [`BuildStrategy::injects_idempotency_key`](crate::protocol::http::BuildStrategy)).
+    pub fn allows(&self, method: &Method, idempotency_key: Option<&str>) -> bool {
+        self.retry_non_idempotent || idempotency_key.is_some() || is_idempotent(method)
+    }
+
+    /// Backoff to apply before the provided retry (starting at 1), including jitter.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = i32::try_from(retry.saturating_sub(1)).unwrap_or(i32::MAX);
+        let backoff = self
+            .initial_backoff
+            .mul_f64(
+                self.multiplier
+                    .max(1.0)
+                    .powi(exponent)
+                    .min(f64::from(u32::MAX)),
+            )
+            .min(self.max_backoff);
+
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return backoff;
+        }
+
+        backoff.mul_f64(1.0 + rand::thread_rng().gen_range(-jitter..=jitter))
+    }
+
+    /// Server communicated delay before a rate limited request may be retried, parsed from the
+    /// first present [`retry_after_headers`](Self::retry_after_headers) value.
+    pub fn retry_after(&self, headers: &HeaderMap) -> Option<Duration> {
+        self.retry_after_headers
+            .iter()
+            .filter_map(|name| headers.get(*name)?.to_str().ok())
+            .find_map(|value| {
+                let value = value.trim();
+                match value.parse::<f64>() {
+                    Ok(seconds) => Duration::try_from_secs_f64(seconds.max(0.0)).ok(),
+                    Err(_) => DateTime::parse_from_rfc2822(value)
+                        .ok()?
+                        .with_timezone(&Utc)
+                        .signed_duration_since(Utc::now())
+                        .to_std()
+                        .ok()
+                        .or(Some(Duration::ZERO)),
+                }
+            })
+    }
+
+    /// Delay to wait before retrying the provided rate limited response (with the provided
+    /// retry number, starting at 1), or `None` if it should not be retried.
+    pub fn rate_limited_backoff(&self, retry: u32, headers: &HeaderMap) -> Option<Duration> {
+        if !self.retry_rate_limited {
+            return None;
+        }
+
+        match self.retry_after(headers) {
+            Some(retry_after) if retry_after > self.max_retry_after => None,
+            Some(retry_after) => Some(retry_after),
+            None => Some(self.backoff(retry)),
+        }
+    }
+}
+
+/// Default [`RetryPolicy`] predicate, retrying timeouts, connection failures & 5xx responses.
+pub fn is_transient(attempt: &Attempt<'_>) -> bool {
+    match attempt {
+        Attempt::Status(status) => status.is_server_error(),
+        Attempt::Error(SocketError::HttpTimeout(_)) => true,
+        Attempt::Error(SocketError::Http(error)) => error.is_connect() || error.is_request(),
+        Attempt::Error(_) => false,
+    }
+}
+
+/// Determine if the Http [`Method`] is idempotent, and therefore safe to retry.
+pub fn is_idempotent(method: &Method) -> bool {
+    matches!(
+        *method,
+        Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE | Method::PUT | Method::DELETE
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        let policy = RetryPolicy {
+            jitter: 0.0,
+            ..RetryPolicy::default()
+        };
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(100), Duration::from_secs(5));
+
+        let jittered = RetryPolicy::default().backoff(1);
+        assert!(jittered >= Duration::from_millis(80) && jittered <= Duration::from_millis(120));
+    }
+
+    #[test]
+    fn test_retry_policy_idempotency() {
+        let policy = RetryPolicy::default();
+        assert!(policy.allows(&Method::GET, None));
+        assert!(policy.allows(&Method::DELETE, None));
+        assert!(!policy.allows(&Method::POST, None));
+        assert!(policy.allows(&Method::POST, Some("order-1")));
+
+        let policy = RetryPolicy {
+            retry_non_idempotent: true,
+            ..policy
+        };
+        assert!(policy.allows(&Method::POST, None));
+    }
+
+    #[test]
+    fn test_retry_policy_retry_after() {
+        let policy = RetryPolicy {
+            jitter: 0.0,
+            retry_after_headers: &["retry-after", "x-ratelimit-reset-after"],
+            ..RetryPolicy::default()
+        };
+
+        let mut headers = HeaderMap::new();
+        assert_eq!(policy.retry_after(&headers), None);
+        assert_eq!(
+            policy.rate_limited_backoff(1, &headers),
+            Some(Duration::from_millis(100))
+        );
+
+        headers.insert("x-ratelimit-reset-after", "1.5".parse().unwrap());
+        assert_eq!(
+            policy.rate_limited_backoff(1, &headers),
+            Some(Duration::from_millis(1500))
+        );
+
+        headers.insert(
+            "retry-after",
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(policy.retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert("retry-after", "120".parse().unwrap());
+        assert_eq!(policy.rate_limited_backoff(1, &headers), None);
+
+        let policy = RetryPolicy {
+            retry_rate_limited: false,
+            ..policy
+        };
+        assert_eq!(policy.rate_limited_backoff(1, &HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(&Attempt::Status(StatusCode::BAD_GATEWAY)));
+        assert!(!is_transient(&Attempt::Status(StatusCode::BAD_REQUEST)));
+        assert!(!is_transient(&Attempt::Error(&SocketError::Sink)));
+    }
+}
@@ -0,0 +1,187 @@
+use reqwest::{
+    header::{HeaderMap, RETRY_AFTER},
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, time::Duration};
+
+/// Rate limit usage state communicated by the server via response headers.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct RateLimitUsage {
+    /// Request weight used in the current window.
+    pub used: Option<u32>,
+    /// Request weight allowed in the current window.
+    pub limit: Option<u32>,
+    /// Request weight remaining in the current window.
+    pub remaining: Option<u32>,
+    /// Duration the server requires the client to wait before sending another request.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitUsage {
+    /// Request weight remaining in the current window, derived from the `limit` & `used` if the
+    /// server does not communicate it directly.
+    pub fn remaining(&self) -> Option<u32> {
+        self.remaining.or_else(|| {
+            self.limit
+                .zip(self.used)
+                .map(|(limit, used)| limit.saturating_sub(used))
+        })
+    }
+
+    /// Merge with another [`RateLimitUsage`], preferring the values of `self`.
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            used: self.used.or(other.used),
+            limit: self.limit.or(other.limit),
+            remaining: self.remaining.or(other.remaining),
+            retry_after: self.retry_after.or(other.retry_after),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Parses the [`RateLimitUsage`] communicated by a server via response headers, such that a
+/// [`RestClient`](super::client::RestClient) can surface it per response and feed it into its
+/// [`RateLimiter`](super::rate_limit::RateLimiter).
+///
+/// Parsers can be combined using a tuple, eg/ `(MbxUsedWeight::new(6000), RetryAfter)`.
+pub trait RateLimitParser: Debug + Send + Sync {
+    fn parse(&self, status: StatusCode, headers: &HeaderMap) -> Option<RateLimitUsage>;
+}
+
+impl<A, B> RateLimitParser for (A, B)
+where
+    A: RateLimitParser,
+    B: RateLimitParser,
+{
+    fn parse(&self, status: StatusCode, headers: &HeaderMap) -> Option<RateLimitUsage> {
+        match (self.0.parse(status, headers), self.1.parse(status, headers)) {
+            (Some(a), Some(b)) => Some(a.or(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+/// [`RateLimitParser`] for the standard `Retry-After` header (delay in seconds), sent with 429 &
+/// 503 responses.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct RetryAfter;
+
+impl RateLimitParser for RetryAfter {
+    fn parse(&self, _: StatusCode, headers: &HeaderMap) -> Option<RateLimitUsage> {
+        let seconds = header_u64(headers, RETRY_AFTER.as_str())?;
+        Some(RateLimitUsage {
+            retry_after: Some(Duration::from_secs(seconds)),
+            ..RateLimitUsage::default()
+        })
+    }
+}
+
+/// [`RateLimitParser`] for the common `X-RateLimit-Limit` & `X-RateLimit-Remaining` headers.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct XRateLimit;
+
+impl RateLimitParser for XRateLimit {
+    fn parse(&self, _: StatusCode, headers: &HeaderMap) -> Option<RateLimitUsage> {
+        let usage = RateLimitUsage {
+            limit: header_u32(headers, "x-ratelimit-limit"),
+            remaining: header_u32(headers, "x-ratelimit-remaining"),
+            ..RateLimitUsage::default()
+        };
+        (!usage.is_empty()).then_some(usage)
+    }
+}
+
+/// [`RateLimitParser`] for Binance `X-MBX-USED-WEIGHT-{interval}` headers, using the provided
+/// weight limit to derive the remaining weight.
+///
+/// If many intervals are present, the highest used weight is reported.
+#[derive(Debug, Copy, Clone)]
+pub struct MbxUsedWeight {
+    pub limit: u32,
+}
+
+impl MbxUsedWeight {
+    pub fn new(limit: u32) -> Self {
+        Self { limit }
+    }
+}
+
+impl RateLimitParser for MbxUsedWeight {
+    fn parse(&self, _: StatusCode, headers: &HeaderMap) -> Option<RateLimitUsage> {
+        let used = headers
+            .iter()
+            .filter(|(name, _)| name.as_str().starts_with("x-mbx-used-weight"))
+            .filter_map(|(_, value)| value.to_str().ok()?.trim().parse::<u32>().ok())
+            .max()?;
+
+        Some(RateLimitUsage {
+            used: Some(used),
+            limit: Some(self.limit),
+            ..RateLimitUsage::default()
+        })
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+fn header_u32(headers: &HeaderMap, name: &str) -> Option<u32> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| {
+                (
+                    reqwest::header::HeaderName::from_static(name),
+                    HeaderValue::from_static(value),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rate_limit_parsers() {
+        let parser = (MbxUsedWeight::new(6000), RetryAfter);
+
+        let actual = parser.parse(
+            StatusCode::TOO_MANY_REQUESTS,
+            &headers(&[
+                ("x-mbx-used-weight", "5990"),
+                ("x-mbx-used-weight-1m", "5995"),
+                ("retry-after", "7"),
+            ]),
+        );
+        let expected = RateLimitUsage {
+            used: Some(5995),
+            limit: Some(6000),
+            remaining: None,
+            retry_after: Some(Duration::from_secs(7)),
+        };
+        assert_eq!(actual, Some(expected));
+        assert_eq!(expected.remaining(), Some(5));
+
+        let actual = XRateLimit.parse(
+            StatusCode::OK,
+            &headers(&[
+                ("x-ratelimit-limit", "100"),
+                ("x-ratelimit-remaining", "99"),
+            ]),
+        );
+        assert_eq!(actual.and_then(|usage| usage.remaining()), Some(99));
+
+        assert_eq!(parser.parse(StatusCode::OK, &HeaderMap::new()), None);
+    }
+}
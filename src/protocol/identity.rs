@@ -0,0 +1,104 @@
+use crate::error::SocketError;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use serde::{Deserialize, Serialize};
+use tokio_tungstenite::tungstenite::{client::IntoClientRequest, handshake::client::Request};
+
+/// Client identification applied to both [`WebSocket`](super::websocket::WebSocket) handshakes
+/// (via [`ws_request`](Self::ws_request)) and [`RestClient`](super::http::rest::client::RestClient)
+/// requests (via [`RestClientBuilder::identity`](super::http::rest::builder::RestClientBuilder::identity)),
+/// configured in one place.
+///
+/// Some venues require a registered client identifier (eg/ a broker id or partner User-Agent)
+/// in order to grant elevated rate limits.
+///
+/// eg/ `ClientIdentity::new("barter/1.0").with_header("X-Broker-Id", "barter")`
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct ClientIdentity {
+    /// Optional `User-Agent` header value.
+    pub user_agent: Option<String>,
+
+    /// Additional identification headers, applied in order.
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+}
+
+impl ClientIdentity {
+    /// Construct a new [`ClientIdentity`] with the provided `User-Agent`.
+    pub fn new<S>(user_agent: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            user_agent: Some(user_agent.into()),
+            headers: Vec::new(),
+        }
+    }
+
+    /// Add an additional identification header.
+    pub fn with_header<Name, Value>(mut self, name: Name, value: Value) -> Self
+    where
+        Name: Into<String>,
+        Value: Into<String>,
+    {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Validate and construct the [`HeaderMap`] of every identification header, including the
+    /// `User-Agent`.
+    pub fn header_map(&self) -> Result<HeaderMap, SocketError> {
+        let user_agent = self
+            .user_agent
+            .as_deref()
+            .map(|user_agent| (USER_AGENT.as_str(), user_agent));
+
+        user_agent
+            .into_iter()
+            .chain(
+                self.headers
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.as_str())),
+            )
+            .try_fold(HeaderMap::new(), |mut headers, (name, value)| {
+                let name = HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|error| SocketError::InvalidHeader(format!("{name}: {error}")))?;
+                let value = HeaderValue::from_str(value)
+                    .map_err(|error| SocketError::InvalidHeader(format!("{name}: {error}")))?;
+                headers.insert(name, value);
+                Ok(headers)
+            })
+    }
+
+    /// Construct a [`WebSocket`](super::websocket::WebSocket) handshake [`Request`] that includes
+    /// the identification headers, ready to be passed to
+    /// [`connect`](super::websocket::connect).
+    pub fn ws_request<R>(&self, request: R) -> Result<Request, SocketError>
+    where
+        R: IntoClientRequest,
+    {
+        let mut request = request.into_client_request()?;
+        request.headers_mut().extend(self.header_map()?);
+        Ok(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_client_identity() {
+        let identity = ClientIdentity::new("barter/1.0").with_header("X-Broker-Id", "barter");
+
+        let request = identity.ws_request("wss://stream.x.com/ws").unwrap();
+        assert_eq!(request.headers()[USER_AGENT], "barter/1.0");
+        assert_eq!(request.headers()["x-broker-id"], "barter");
+        assert!(request.headers().contains_key("sec-websocket-key"));
+
+        let invalid = ClientIdentity::default().with_header("X Broker", "barter");
+        assert!(matches!(
+            invalid.header_map(),
+            Err(SocketError::InvalidHeader(_))
+        ));
+    }
+}
@@ -10,6 +10,15 @@ pub mod websocket;
 /// exchange oriented HTTP request.
 pub mod http;
 
+/// Named `{parameter}` placeholder templates used to construct validated Urls & paths.
+pub mod template;
+
+/// Client identification (eg/ User-Agent) applied to both WebSocket handshakes & Http requests.
+pub mod identity;
+
+/// Proxy configuration used to route connections through eg/ regional egress.
+pub mod proxy;
+
 /// `StreamParser`s are capable of parsing the input messages from a given stream protocol
 /// (eg/ WebSocket, Financial Information eXchange (FIX), etc.) and deserialising into an `Output`.
 pub trait StreamParser {
@@ -22,4 +31,11 @@ pub trait StreamParser {
     ) -> Option<Result<Output, SocketError>>
     where
         Output: DeserializeOwned;
+
+    /// Size of the provided protocol message in bytes, used for throughput statistics.
+    ///
+    /// Defaults to 0 for protocols that cannot cheaply determine message size.
+    fn message_size(_: &Self::Message) -> usize {
+        0
+    }
 }
@@ -0,0 +1,93 @@
+use crate::{error::SocketError, protocol::http::rest::logging::REDACTED};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Formatter};
+
+/// Proxy that connections are routed through, with optional basic authentication.
+///
+/// The scheme of the Url determines the kind of proxy, eg/ `http://`, `https://` or `socks5://`.
+/// SOCKS proxies require the `socks` feature of `reqwest` to be enabled by the application.
+///
+/// eg/ Routing requests through regional egress:
+/// `ProxyConfig::new("http://eu-egress:3128").with_auth("user", "pass")`
+///
+/// The password is redacted from the [`Debug`] representation & never serialised.
+#[derive(Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ProxyConfig {
+    /// Proxy Url, including the scheme & port.
+    pub url: String,
+
+    /// Optional basic authentication username.
+    pub username: Option<String>,
+
+    /// Optional basic authentication password.
+    #[serde(skip_serializing)]
+    pub password: Option<String>,
+}
+
+impl Debug for ProxyConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .finish()
+    }
+}
+
+impl ProxyConfig {
+    /// Construct a new unauthenticated [`ProxyConfig`] using the provided Url.
+    pub fn new<S>(url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            url: url.into(),
+            username: None,
+            password: None,
+        }
+    }
+
+    /// Authenticate with the proxy using the provided basic authentication credentials.
+    pub fn with_auth<User, Pass>(self, username: User, password: Pass) -> Self
+    where
+        User: Into<String>,
+        Pass: Into<String>,
+    {
+        Self {
+            username: Some(username.into()),
+            password: Some(password.into()),
+            ..self
+        }
+    }
+
+    /// Construct a [`reqwest::Proxy`] that routes every Http request through this proxy.
+    pub fn reqwest_proxy(&self) -> Result<reqwest::Proxy, SocketError> {
+        let proxy = reqwest::Proxy::all(self.url.as_str()).map_err(SocketError::Http)?;
+
+        Ok(match &self.username {
+            Some(username) => {
+                proxy.basic_auth(username, self.password.as_deref().unwrap_or_default())
+            }
+            None => proxy,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proxy_config() {
+        let proxy = ProxyConfig::new("http://eu-egress:3128").with_auth("user", "pass");
+        assert!(proxy.reqwest_proxy().is_ok());
+        assert!(!format!("{proxy:?}").contains("\"pass\""));
+        assert_eq!(
+            serde_json::to_string(&proxy).unwrap(),
+            r#"{"url":"http://eu-egress:3128","username":"user"}"#
+        );
+
+        let invalid = ProxyConfig::new("not a url");
+        assert!(matches!(invalid.reqwest_proxy(), Err(SocketError::Http(_))));
+    }
+}
@@ -0,0 +1,187 @@
+use crate::error::SocketError;
+use std::{collections::BTreeMap, fmt::Display};
+
+/// Parsed template containing named `{parameter}` placeholders.
+///
+/// eg/ "wss://stream.exchange.com/ws/{listen_key}"
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Template {
+    template: String,
+    segments: Vec<Segment>,
+}
+
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+enum Segment {
+    Literal(String),
+    Param(String),
+}
+
+impl Template {
+    /// Parse the provided template, validating that every placeholder is closed and named.
+    pub fn parse<S>(template: S) -> Result<Self, SocketError>
+    where
+        S: Into<String>,
+    {
+        let template = template.into();
+        let mut segments = Vec::new();
+        let mut rest = template.as_str();
+
+        while let Some(open) = rest.find(['{', '}']) {
+            if rest[open..].starts_with('}') {
+                return Err(template_error(&template, "unmatched '}'"));
+            }
+
+            let Some(close) = rest[open..].find('}').map(|close| open + close) else {
+                return Err(template_error(&template, "unclosed '{'"));
+            };
+
+            let name = &rest[open + 1..close];
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|char| char.is_ascii_alphanumeric() || char == '_')
+            {
+                return Err(template_error(
+                    &template,
+                    format!("invalid parameter name '{name}'"),
+                ));
+            }
+
+            if open > 0 {
+                segments.push(Segment::Literal(rest[..open].to_string()));
+            }
+            segments.push(Segment::Param(name.to_string()));
+            rest = &rest[close + 1..];
+        }
+
+        if !rest.is_empty() {
+            segments.push(Segment::Literal(rest.to_string()));
+        }
+
+        Ok(Self { template, segments })
+    }
+
+    /// Raw template this [`Template`] was parsed from.
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    /// Names of every parameter placeholder in the template, in order of appearance.
+    pub fn params(&self) -> impl Iterator<Item = &str> {
+        self.segments.iter().filter_map(|segment| match segment {
+            Segment::Param(name) => Some(name.as_str()),
+            Segment::Literal(_) => None,
+        })
+    }
+
+    /// Substitute every parameter placeholder with its percent-encoded value.
+    ///
+    /// Fails if a placeholder has no value, or a value has no matching placeholder.
+    pub fn render(&self, values: &BTreeMap<String, String>) -> Result<String, SocketError> {
+        if let Some(unknown) = values
+            .keys()
+            .find(|name| !self.params().any(|p| p == *name))
+        {
+            return Err(template_error(
+                &self.template,
+                format!("unknown parameter '{unknown}'"),
+            ));
+        }
+
+        self.segments.iter().try_fold(
+            String::with_capacity(self.template.len()),
+            |mut output, segment| {
+                match segment {
+                    Segment::Literal(literal) => output.push_str(literal),
+                    Segment::Param(name) => {
+                        let value = values.get(name).ok_or_else(|| {
+                            template_error(&self.template, format!("missing parameter '{name}'"))
+                        })?;
+                        output.push_str(&encode_component(value));
+                    }
+                }
+                Ok(output)
+            },
+        )
+    }
+}
+
+/// Percent-encode every byte of the value that is not an unreserved URL character.
+pub fn encode_component(value: &str) -> String {
+    value
+        .bytes()
+        .fold(String::with_capacity(value.len()), |mut encoded, byte| {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    encoded.push(byte as char)
+                }
+                byte => encoded.push_str(&format!("%{byte:02X}")),
+            }
+            encoded
+        })
+}
+
+fn template_error<Reason>(template: &str, reason: Reason) -> SocketError
+where
+    Reason: Display,
+{
+    SocketError::UrlTemplate(format!("{reason} in template: {template}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_render() {
+        struct TestCase {
+            template: &'static str,
+            values: Vec<(&'static str, &'static str)>,
+            expected: Result<&'static str, ()>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: all parameters substituted & encoded
+                template: "wss://x.com/ws/{listen_key}?s={symbol}",
+                values: vec![("listen_key", "abc"), ("symbol", "btc/usdt")],
+                expected: Ok("wss://x.com/ws/abc?s=btc%2Fusdt"),
+            },
+            TestCase {
+                // TC1: missing parameter
+                template: "wss://x.com/ws/{listen_key}",
+                values: vec![],
+                expected: Err(()),
+            },
+            TestCase {
+                // TC2: unknown parameter
+                template: "wss://x.com/ws",
+                values: vec![("listen_key", "abc")],
+                expected: Err(()),
+            },
+            TestCase {
+                // TC3: unclosed placeholder
+                template: "wss://x.com/ws/{listen_key",
+                values: vec![],
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let values = test
+                .values
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+
+            let actual = Template::parse(test.template).and_then(|t| t.render(&values));
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => assert_eq!(actual, expected, "TC{index} failed"),
+                (Err(_), Err(_)) => {}
+                (actual, expected) => {
+                    panic!("TC{index} failed: actual: {actual:?}, expected: {expected:?}")
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use super::{connect, WebSocket, WsError, WsMessage};
+use crate::{
+    error::SocketError,
+    protocol::http::private::{algorithm::SignatureAlgorithm, encoder::Encoder},
+    runtime::{self, Runtime, TokioRuntime},
+};
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use std::{fmt::Debug, time::Duration};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tracing::debug;
+
+/// Default time to wait for the server to acknowledge a [`WsAuthenticator::auth_message`].
+pub const DEFAULT_AUTH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Generates the signed authentication frame required by private [`WebSocket`] channels (eg/
+/// OKX `login` op, Bybit `auth` op), & recognises the server acknowledgement.
+///
+/// The frame is regenerated for every (re)connection, such that the signed timestamp is always
+/// fresh. See [`authenticate`] & [`connect_authenticated`].
+pub trait WsAuthenticator {
+    /// Generate the signed authentication frame, typically signed via a [`WsAuthSigner`].
+    fn auth_message(&self) -> Result<WsMessage, SocketError>;
+
+    /// Determine if the provided frame is the server response to the
+    /// [`auth_message`](Self::auth_message), returning `None` for unrelated frames (eg/ pings).
+    ///
+    /// eg/ OKX: `{"event":"login","code":"0"}` => `Some(Ok(()))`
+    fn auth_response(&self, message: &WsMessage) -> Option<Result<(), SocketError>>;
+}
+
+/// Signs [`WsAuthenticator`] payloads using the same [`SignatureAlgorithm`] & signature
+/// [`Encoder`] machinery as a [`RequestSigner`](crate::protocol::http::private::RequestSigner).
+///
+/// eg/ OKX: `WsAuthSigner::new(Hmac::<Sha256>::new_from_slice(secret)?, Base64Encoder)`
+#[derive(Debug, Copy, Clone)]
+pub struct WsAuthSigner<Algorithm, SigEncoder> {
+    algorithm: Algorithm,
+    encoder: SigEncoder,
+}
+
+impl<Algorithm, SigEncoder> WsAuthSigner<Algorithm, SigEncoder>
+where
+    Algorithm: SignatureAlgorithm,
+    SigEncoder: Encoder,
+{
+    /// Construct a new [`WsAuthSigner`] using the provided [`SignatureAlgorithm`] & [`Encoder`].
+    pub fn new(algorithm: Algorithm, encoder: SigEncoder) -> Self {
+        Self { algorithm, encoder }
+    }
+
+    /// Sign the provided payload, returning the encoded signature.
+    ///
+    /// eg/ OKX: `signer.sign(format!("{timestamp}GET/users/self/verify"))`
+    pub fn sign<Payload>(&self, payload: Payload) -> Result<String, SocketError>
+    where
+        Payload: AsRef<[u8]>,
+    {
+        self.algorithm
+            .sign_bytes(payload.as_ref())
+            .map(|signature| self.encoder.encode(signature))
+    }
+}
+
+/// Send the [`WsAuthenticator::auth_message`] over the provided connection, then await the
+/// server response to it.
+///
+/// Unrelated frames received before the response are discarded.
+///
+/// Must be called from within a Tokio runtime, see
+/// [`authenticate_with_runtime`] for alternative executors.
+pub async fn authenticate<Socket, Auth>(
+    socket: &mut Socket,
+    authenticator: &Auth,
+    timeout: Duration,
+) -> Result<(), SocketError>
+where
+    Socket: Sink<WsMessage, Error = WsError> + Stream<Item = Result<WsMessage, WsError>> + Unpin,
+    Auth: WsAuthenticator + ?Sized,
+{
+    authenticate_with_runtime(socket, authenticator, timeout, &TokioRuntime).await
+}
+
+/// [`authenticate`] the provided connection, timing the server response using the provided
+/// [`Runtime`].
+pub async fn authenticate_with_runtime<Socket, Auth>(
+    socket: &mut Socket,
+    authenticator: &Auth,
+    timeout: Duration,
+    runtime: &dyn Runtime,
+) -> Result<(), SocketError>
+where
+    Socket: Sink<WsMessage, Error = WsError> + Stream<Item = Result<WsMessage, WsError>> + Unpin,
+    Auth: WsAuthenticator + ?Sized,
+{
+    socket.send(authenticator.auth_message()?).await?;
+
+    let response = async {
+        while let Some(message) = socket.next().await {
+            let message = message?;
+            match authenticator.auth_response(&message) {
+                Some(result) => return result,
+                None => debug!(payload = ?message, "discarding frame awaiting auth response"),
+            }
+        }
+        Err(SocketError::Terminated(
+            "connection closed awaiting auth response".to_string(),
+        ))
+    };
+
+    runtime::timeout(runtime, timeout, response)
+        .await
+        .ok_or(SocketError::ReadTimeout(timeout))?
+}
+
+/// Connect asynchronously to a private [`WebSocket`] server, authenticating the connection
+/// using the provided [`WsAuthenticator`] before returning it.
+pub async fn connect_authenticated<R, Auth>(
+    request: R,
+    authenticator: &Auth,
+) -> Result<WebSocket, SocketError>
+where
+    R: IntoClientRequest + Unpin + Debug,
+    Auth: WsAuthenticator + ?Sized,
+{
+    let mut websocket = connect(request).await?;
+    authenticate(&mut websocket, authenticator, DEFAULT_AUTH_TIMEOUT).await?;
+    Ok(websocket)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::http::private::encoder::Base64Encoder;
+    use hmac::{Hmac, Mac};
+    use serde_json::{json, Value};
+    use sha2::Sha256;
+    use tokio::net::TcpListener;
+
+    /// OKX style `login` op [`WsAuthenticator`].
+    struct Login {
+        api_key: &'static str,
+        signer: WsAuthSigner<Hmac<Sha256>, Base64Encoder>,
+    }
+
+    impl WsAuthenticator for Login {
+        fn auth_message(&self) -> Result<WsMessage, SocketError> {
+            let timestamp = "1700000000";
+            let sign = self
+                .signer
+                .sign(format!("{timestamp}GET/users/self/verify"))?;
+            Ok(WsMessage::text(
+                json!({
+                    "op": "login",
+                    "args": [{ "apiKey": self.api_key, "timestamp": timestamp, "sign": sign }],
+                })
+                .to_string(),
+            ))
+        }
+
+        fn auth_response(&self, message: &WsMessage) -> Option<Result<(), SocketError>> {
+            let WsMessage::Text(text) = message else {
+                return None;
+            };
+            let response = serde_json::from_str::<Value>(text).ok()?;
+            match (response["event"].as_str()?, response["code"].as_str()) {
+                ("login", Some("0")) => Some(Ok(())),
+                ("error", _) => Some(Err(SocketError::Exchange(text.clone()))),
+                _ => None,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_connect_authenticated() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        // Server accepts logins from "valid" api keys, publishing a ping beforehand
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+                let Some(Ok(WsMessage::Text(login))) = websocket.next().await else {
+                    panic!("expected login frame");
+                };
+                let login = serde_json::from_str::<Value>(&login).unwrap();
+                assert!(login["args"][0]["sign"].is_string());
+
+                let response = match login["args"][0]["apiKey"].as_str() {
+                    Some("valid") => json!({"event": "login", "code": "0"}),
+                    _ => json!({"event": "error", "code": "60009", "msg": "Login failed."}),
+                };
+                websocket.send(WsMessage::text("ping")).await.unwrap();
+                websocket
+                    .send(WsMessage::text(response.to_string()))
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let login = |api_key| Login {
+            api_key,
+            signer: WsAuthSigner::new(
+                Hmac::<Sha256>::new_from_slice(b"secret").unwrap(),
+                Base64Encoder,
+            ),
+        };
+
+        assert!(connect_authenticated(url.as_str(), &login("valid"))
+            .await
+            .is_ok());
+        assert!(matches!(
+            connect_authenticated(url.as_str(), &login("invalid")).await,
+            Err(SocketError::Exchange(_))
+        ));
+    }
+}
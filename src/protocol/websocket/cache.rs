@@ -0,0 +1,147 @@
+use super::{WsError, WsMessage};
+use crate::error::SocketError;
+use futures::{Sink, SinkExt};
+use serde::Serialize;
+use std::{
+    borrow::Borrow,
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// Cache of pre-serialised [`WsMessage`] frames for payloads that are re-sent many times over the
+/// lifetime of a connection (eg/ pings, keepalive messages, resubscribe payloads).
+///
+/// Payloads are serialised once on insertion, rather than every time they are sent.
+#[derive(Debug, Clone)]
+pub struct FrameCache<Key> {
+    frames: HashMap<Key, WsMessage>,
+}
+
+impl<Key> Default for FrameCache<Key> {
+    fn default() -> Self {
+        Self {
+            frames: HashMap::new(),
+        }
+    }
+}
+
+impl<Key> FrameCache<Key>
+where
+    Key: Hash + Eq,
+{
+    /// Construct a new empty [`FrameCache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cache the provided [`WsMessage`] frame.
+    pub fn insert(&mut self, key: Key, frame: WsMessage) {
+        self.frames.insert(key, frame);
+    }
+
+    /// Serialise the provided payload into a JSON text [`WsMessage`] frame, and cache it.
+    pub fn insert_json<Payload>(&mut self, key: Key, payload: &Payload) -> Result<(), SocketError>
+    where
+        Payload: Serialize,
+    {
+        let frame = serde_json::to_string(payload)
+            .map(WsMessage::Text)
+            .map_err(SocketError::Serialise)?;
+        self.insert(key, frame);
+        Ok(())
+    }
+
+    /// Retrieve a copy of the cached [`WsMessage`] frame, ready to be sent.
+    pub fn get<Q>(&self, key: &Q) -> Option<WsMessage>
+    where
+        Key: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.frames.get(key).cloned()
+    }
+
+    /// Retrieve a copy of the cached [`WsMessage`] frame, serialising & caching it via the
+    /// provided closure if it is not yet present.
+    pub fn get_or_try_insert_with<F>(
+        &mut self,
+        key: Key,
+        frame: F,
+    ) -> Result<WsMessage, SocketError>
+    where
+        F: FnOnce() -> Result<WsMessage, SocketError>,
+    {
+        match self.frames.entry(key) {
+            Entry::Occupied(entry) => Ok(entry.get().clone()),
+            Entry::Vacant(entry) => Ok(entry.insert(frame()?).clone()),
+        }
+    }
+
+    /// Remove the cached [`WsMessage`] frame, returning it if present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<WsMessage>
+    where
+        Key: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.frames.remove(key)
+    }
+
+    /// Remove every cached [`WsMessage`] frame (eg/ after a resubscribe payload changes).
+    pub fn clear(&mut self) {
+        self.frames.clear()
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+/// Send many [`WsMessage`] frames, buffering them in the [`Sink`] and flushing once at the end.
+///
+/// The underlying connection is therefore written to in as few writes as possible, rather than
+/// once per frame.
+pub async fn send_batch<WsSink, Frames>(
+    sink: &mut WsSink,
+    frames: Frames,
+) -> Result<(), SocketError>
+where
+    WsSink: Sink<WsMessage, Error = WsError> + Unpin,
+    Frames: IntoIterator<Item = WsMessage>,
+{
+    for frame in frames {
+        sink.feed(frame).await?;
+    }
+    sink.flush().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_frame_cache_serialises_once() {
+        let mut cache = FrameCache::new();
+        cache.insert_json("ping", &json!({"op": "ping"})).unwrap();
+
+        assert_eq!(cache.get("ping"), Some(WsMessage::text(r#"{"op":"ping"}"#)));
+        assert_eq!(cache.get("missing"), None);
+
+        let mut calls = 0;
+        for _ in 0..3 {
+            let frame = cache
+                .get_or_try_insert_with("resubscribe", || {
+                    calls += 1;
+                    Ok(WsMessage::text("resubscribe"))
+                })
+                .unwrap();
+            assert_eq!(frame, WsMessage::text("resubscribe"));
+        }
+        assert_eq!(calls, 1);
+        assert_eq!(cache.len(), 2);
+    }
+}
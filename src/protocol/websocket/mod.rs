@@ -0,0 +1,341 @@
+use crate::{error::SocketError, protocol::StreamParser};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fmt::Debug;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{
+        client::IntoClientRequest,
+        error::ProtocolError,
+        protocol::{frame::Frame, CloseFrame},
+    },
+    MaybeTlsStream,
+};
+use tracing::debug;
+
+/// Pre-serialised [`WsMessage`] frame cache & batched sends for frequently re-sent payloads.
+pub mod cache;
+
+/// Typed [`WebSocket`] Url templating with validated parameter substitution.
+pub mod url;
+
+/// Warm standby [`WebSocket`] connection that can be promoted to take over from a failed primary.
+pub mod standby;
+
+/// [`WsRpcClient`](rpc::WsRpcClient) that sends request frames with correlation ids & awaits the
+/// matching responses. eg/ WebSocket order placement.
+pub mod rpc;
+
+/// [`WsAuthenticator`](auth::WsAuthenticator) signed login frames for authenticating private
+/// [`WebSocket`] channels on every (re)connection.
+pub mod auth;
+
+/// Convenient type alias for a tungstenite `WebSocketStream`.
+pub type WebSocket = tokio_tungstenite::WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Convenient type alias for the `Sink` half of a tungstenite [`WebSocket`].
+pub type WsSink = futures::stream::SplitSink<WebSocket, WsMessage>;
+
+/// Convenient type alias for the `Stream` half of a tungstenite [`WebSocket`].
+pub type WsStream = futures::stream::SplitStream<WebSocket>;
+
+/// Communicative type alias for a tungstenite [`WebSocket`] `Message`.
+pub type WsMessage = tokio_tungstenite::tungstenite::Message;
+
+/// Communicative type alias for a tungstenite [`WebSocket`] `Error`.
+pub type WsError = tokio_tungstenite::tungstenite::Error;
+
+/// Default [`StreamParser`] implementation for a [`WebSocket`].
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct WebSocketParser;
+
+impl StreamParser for WebSocketParser {
+    type Stream = WebSocket;
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        match input {
+            Ok(ws_message) => match ws_message {
+                WsMessage::Text(text) => process_text(text),
+                WsMessage::Binary(binary) => process_binary(binary),
+                WsMessage::Ping(ping) => process_ping(ping),
+                WsMessage::Pong(pong) => process_pong(pong),
+                WsMessage::Close(close_frame) => process_close_frame(close_frame),
+                WsMessage::Frame(frame) => process_frame(frame),
+            },
+            Err(ws_err) => Some(Err(SocketError::from(ws_err))),
+        }
+    }
+
+    fn message_size(message: &Self::Message) -> usize {
+        message.len()
+    }
+}
+
+/// Diagnostic [`StreamParser`] implementation for a [`WebSocket`].
+///
+/// Behaves identically to the [`WebSocketParser`], but upon deserialisation failure the payload is
+/// re-parsed to determine the precise path of the field that failed to deserialise. This is slower
+/// on the error path only, and dramatically shortens debugging of exchange schema drift.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct DiagnosticWebSocketParser;
+
+impl StreamParser for DiagnosticWebSocketParser {
+    type Stream = WebSocket;
+    type Message = WsMessage;
+    type Error = WsError;
+
+    fn parse<Output>(
+        input: Result<Self::Message, Self::Error>,
+    ) -> Option<Result<Output, SocketError>>
+    where
+        Output: DeserializeOwned,
+    {
+        match input {
+            Ok(WsMessage::Text(text)) => process_text_diagnostic(text),
+            Ok(WsMessage::Binary(binary)) => process_binary_diagnostic(binary),
+            input => WebSocketParser::parse(input),
+        }
+    }
+
+    fn message_size(message: &Self::Message) -> usize {
+        message.len()
+    }
+}
+
+/// Process a payload of `String` by deserialising into an `ExchangeMessage`.
+pub fn process_text<ExchangeMessage>(
+    payload: String,
+) -> Option<Result<ExchangeMessage, SocketError>>
+where
+    ExchangeMessage: DeserializeOwned,
+{
+    Some(
+        serde_json::from_str::<ExchangeMessage>(&payload).map_err(|error| {
+            debug!(
+                ?error,
+                ?payload,
+                action = "returning Some(Err(err))",
+                "failed to deserialize WebSocket Message into domain specific Message"
+            );
+            SocketError::Deserialise { error, payload }
+        }),
+    )
+}
+
+/// Process a payload of `Vec<u8>` bytes by deserialising into an `ExchangeMessage`.
+pub fn process_binary<ExchangeMessage>(
+    payload: Vec<u8>,
+) -> Option<Result<ExchangeMessage, SocketError>>
+where
+    ExchangeMessage: DeserializeOwned,
+{
+    Some(
+        serde_json::from_slice::<ExchangeMessage>(&payload).map_err(|error| {
+            debug!(
+                ?error,
+                ?payload,
+                action = "returning Some(Err(err))",
+                "failed to deserialize WebSocket Message into domain specific Message"
+            );
+            SocketError::Deserialise {
+                error,
+                payload: String::from_utf8(payload).unwrap_or_else(|x| x.to_string()),
+            }
+        }),
+    )
+}
+
+/// Process a payload of `String` by deserialising into an `ExchangeMessage`, diagnosing the path
+/// of the field that failed to deserialise upon failure.
+pub fn process_text_diagnostic<ExchangeMessage>(
+    payload: String,
+) -> Option<Result<ExchangeMessage, SocketError>>
+where
+    ExchangeMessage: DeserializeOwned,
+{
+    Some(
+        serde_json::from_str::<ExchangeMessage>(&payload)
+            .map_err(|error| diagnose_deserialise_error::<ExchangeMessage>(error, payload)),
+    )
+}
+
+/// Process a payload of `Vec<u8>` bytes by deserialising into an `ExchangeMessage`, diagnosing
+/// the path of the field that failed to deserialise upon failure.
+pub fn process_binary_diagnostic<ExchangeMessage>(
+    payload: Vec<u8>,
+) -> Option<Result<ExchangeMessage, SocketError>>
+where
+    ExchangeMessage: DeserializeOwned,
+{
+    Some(
+        serde_json::from_slice::<ExchangeMessage>(&payload).map_err(|error| {
+            let payload = String::from_utf8(payload).unwrap_or_else(|x| x.to_string());
+            diagnose_deserialise_error::<ExchangeMessage>(error, payload)
+        }),
+    )
+}
+
+/// Re-parse a payload that failed to deserialise into an `ExchangeMessage`, determining the
+/// path of the field that caused the failure.
+///
+/// If the payload is not valid JSON, the original error is already precise and is returned as a
+/// [`SocketError::Deserialise`].
+pub fn diagnose_deserialise_error<ExchangeMessage>(
+    error: serde_json::Error,
+    payload: String,
+) -> SocketError
+where
+    ExchangeMessage: DeserializeOwned,
+{
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(&payload) else {
+        return SocketError::Deserialise { error, payload };
+    };
+
+    let error = match serde_path_to_error::deserialize::<_, ExchangeMessage>(value) {
+        Ok(_) => return SocketError::Deserialise { error, payload },
+        Err(error) => error,
+    };
+
+    let path = error.path().to_string();
+    debug!(
+        %path,
+        ?error,
+        ?payload,
+        action = "returning Some(Err(err))",
+        "failed to deserialize WebSocket Message into domain specific Message"
+    );
+
+    SocketError::DeserialisePath {
+        path,
+        error: error.into_inner(),
+        payload,
+    }
+}
+
+/// Basic process for a [`WebSocket`] ping message. Logs the payload at `trace` level.
+pub fn process_ping<ExchangeMessage>(
+    ping: Vec<u8>,
+) -> Option<Result<ExchangeMessage, SocketError>> {
+    debug!(payload = ?ping, "received Ping WebSocket message");
+    None
+}
+
+/// Basic process for a [`WebSocket`] pong message. Logs the payload at `trace` level.
+pub fn process_pong<ExchangeMessage>(
+    pong: Vec<u8>,
+) -> Option<Result<ExchangeMessage, SocketError>> {
+    debug!(payload = ?pong, "received Pong WebSocket message");
+    None
+}
+
+/// Basic process for a [`WebSocket`] CloseFrame message. Logs the payload at `trace` level.
+pub fn process_close_frame<ExchangeMessage>(
+    close_frame: Option<CloseFrame<'_>>,
+) -> Option<Result<ExchangeMessage, SocketError>> {
+    let close_frame = format!("{:?}", close_frame);
+    debug!(payload = %close_frame, "received CloseFrame WebSocket message");
+    Some(Err(SocketError::Terminated(close_frame)))
+}
+
+/// Basic process for a [`WebSocket`] Frame message. Logs the payload at `trace` level.
+pub fn process_frame<ExchangeMessage>(
+    frame: Frame,
+) -> Option<Result<ExchangeMessage, SocketError>> {
+    let frame = format!("{:?}", frame);
+    debug!(payload = %frame, "received unexpected Frame WebSocket message");
+    None
+}
+
+/// Connect asynchronously to a [`WebSocket`] server.
+pub async fn connect<R>(request: R) -> Result<WebSocket, SocketError>
+where
+    R: IntoClientRequest + Unpin + Debug,
+{
+    debug!(?request, "attempting to establish WebSocket connection");
+    connect_async(request)
+        .await
+        .map(|(websocket, _)| websocket)
+        .map_err(SocketError::from)
+}
+
+/// Determine whether a [`WsError`] indicates the [`WebSocket`] has disconnected.
+pub fn is_websocket_disconnected(error: &WsError) -> bool {
+    matches!(
+        error,
+        WsError::ConnectionClosed
+            | WsError::AlreadyClosed
+            | WsError::Io(_)
+            | WsError::Protocol(ProtocolError::SendAfterClosing)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Trade {
+        price: f64,
+        fills: Vec<Fill>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Fill {
+        quantity: f64,
+    }
+
+    #[test]
+    fn test_diagnostic_websocket_parser() {
+        struct TestCase {
+            input: &'static str,
+            expected_path: Option<&'static str>,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Valid Trade
+                input: r#"{"price": 1.0, "fills": [{"quantity": 2.0}]}"#,
+                expected_path: None,
+            },
+            TestCase {
+                // TC1: Invalid nested field type
+                input: r#"{"price": 1.0, "fills": [{"quantity": 2.0}, {"quantity": "2.0"}]}"#,
+                expected_path: Some("fills[1].quantity"),
+            },
+            TestCase {
+                // TC2: Missing field
+                input: r#"{"fills": []}"#,
+                expected_path: Some("."),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = DiagnosticWebSocketParser::parse::<Trade>(Ok(WsMessage::Text(
+                test.input.to_string(),
+            )))
+            .unwrap();
+
+            match (actual, test.expected_path) {
+                (Ok(_), None) => {
+                    // Test passed
+                }
+                (Err(SocketError::DeserialisePath { path, .. }), Some(expected)) => {
+                    assert_eq!(path, expected, "TC{} failed", index)
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,497 @@
+use crate::{
+    error::SocketError,
+    protocol::{
+        http::private::{algorithm::SignatureAlgorithm, encoder::Encoder, time_sync::TimeSync},
+        websocket::{auth::WsAuthSigner, WsError, WsMessage},
+    },
+    runtime::{self, Runtime, TokioRuntime},
+};
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::{mpsc, oneshot};
+use tracing::{debug, warn};
+
+/// Default [`WsRpcClient`] response timeout.
+pub const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(10);
+
+type Pending = Arc<Mutex<Waiters>>;
+
+/// Response waiters of in-flight [`WsRpcClient`] requests, keyed by correlation id.
+#[derive(Debug, Default)]
+struct Waiters {
+    waiters: HashMap<u64, oneshot::Sender<Result<Value, SocketError>>>,
+    /// Connection tasks have ended, so no further responses will be routed.
+    closed: bool,
+}
+
+impl Waiters {
+    /// Mark the connection as closed, failing every in-flight request.
+    fn close(pending: &Pending) {
+        let mut pending = pending.lock().expect("WsRpcClient Mutex poisoned");
+        pending.closed = true;
+        pending.waiters.clear();
+    }
+}
+
+/// Exchange specific framing of [`WsRpcClient`] requests & responses.
+pub trait RpcCodec: Send + Sync + 'static {
+    /// Encode a request frame with the provided correlation id, method & params.
+    fn encode(&self, id: u64, method: &str, params: Value) -> Result<WsMessage, SocketError>;
+
+    /// Decode the correlation id & outcome of a response frame.
+    ///
+    /// Returns `None` if the frame is not an RPC response (eg/ a stream event).
+    fn decode(&self, message: &WsMessage) -> Option<(u64, Result<Value, SocketError>)>;
+}
+
+/// [`RpcCodec`] for JSON request frames of the form `{"id", "method", "params"}`, and response
+/// frames containing the `id` and either a `result` or an `error`.
+///
+/// eg/ Binance WebSocket API & JSON-RPC 2.0.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct JsonRpcCodec;
+
+impl RpcCodec for JsonRpcCodec {
+    fn encode(&self, id: u64, method: &str, params: Value) -> Result<WsMessage, SocketError> {
+        serde_json::to_string(&serde_json::json!({
+            "id": id,
+            "method": method,
+            "params": params,
+        }))
+        .map(WsMessage::Text)
+        .map_err(SocketError::Serialise)
+    }
+
+    fn decode(&self, message: &WsMessage) -> Option<(u64, Result<Value, SocketError>)> {
+        let WsMessage::Text(text) = message else {
+            return None;
+        };
+
+        let mut response = serde_json::from_str::<Map<String, Value>>(text).ok()?;
+        let id = match response.get("id")? {
+            Value::Number(id) => id.as_u64()?,
+            Value::String(id) => id.parse().ok()?,
+            _ => return None,
+        };
+
+        let outcome = match response.remove("error") {
+            Some(error) if !error.is_null() => Err(SocketError::Exchange(error.to_string())),
+            _ => Ok(response.remove("result").unwrap_or(Value::Null)),
+        };
+
+        Some((id, outcome))
+    }
+}
+
+/// Signs the params of [`WsRpcClient`] requests using API specific logic, typically generating
+/// the signature via the shared [`SignatureAlgorithm`] & [`Encoder`] machinery (see
+/// [`QuerySigner`]).
+pub trait RpcSigner: Send + Sync + 'static {
+    fn sign(&self, method: &str, params: &mut Map<String, Value>) -> Result<(), SocketError>;
+}
+
+/// [`RpcSigner`] that adds the API key & a [`TimeSync`] timestamp to the params, then signs the
+/// params sorted & encoded as a query string using the provided [`SignatureAlgorithm`] (eg/ a
+/// [`Mac`](hmac::Mac) or Ed25519 key) & signature [`Encoder`], via a [`WsAuthSigner`].
+///
+/// eg/ Binance WebSocket API: `QuerySigner::new(api_key, Hmac::<Sha256>::new_from_slice(secret)?, HexEncoder)`
+pub struct QuerySigner<Algorithm, SigEncoder> {
+    api_key: String,
+    signer: WsAuthSigner<Algorithm, SigEncoder>,
+    time: TimeSync,
+}
+
+impl<Algorithm, SigEncoder> Debug for QuerySigner<Algorithm, SigEncoder> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QuerySigner")
+            .field("time", &self.time)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Algorithm, SigEncoder> QuerySigner<Algorithm, SigEncoder>
+where
+    Algorithm: SignatureAlgorithm,
+    SigEncoder: Encoder,
+{
+    /// Construct a new [`QuerySigner`] using the provided API key, [`SignatureAlgorithm`] &
+    /// [`Encoder`].
+    pub fn new<S>(api_key: S, algorithm: Algorithm, encoder: SigEncoder) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            api_key: api_key.into(),
+            signer: WsAuthSigner::new(algorithm, encoder),
+            time: TimeSync::default(),
+        }
+    }
+
+    /// Generate timestamps using the provided [`TimeSync`], such that they remain valid when the
+    /// local clock drifts.
+    pub fn with_time_sync(self, time: TimeSync) -> Self {
+        Self { time, ..self }
+    }
+}
+
+impl<Algorithm, SigEncoder> RpcSigner for QuerySigner<Algorithm, SigEncoder>
+where
+    Algorithm: SignatureAlgorithm + Send + Sync + 'static,
+    SigEncoder: Encoder + Send + Sync + 'static,
+{
+    fn sign(&self, _: &str, params: &mut Map<String, Value>) -> Result<(), SocketError> {
+        params.insert("apiKey".to_string(), Value::from(self.api_key.as_str()));
+        params.insert(
+            "timestamp".to_string(),
+            Value::from(self.time.now().timestamp_millis()),
+        );
+
+        // serde_json::Map is sorted by key
+        let payload = params
+            .iter()
+            .map(|(key, value)| match value {
+                Value::String(value) => format!("{key}={value}"),
+                value => format!("{key}={value}"),
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let signature = self.signer.sign(payload)?;
+        params.insert("signature".to_string(), Value::from(signature));
+        Ok(())
+    }
+}
+
+/// Request/response RPC client over a [`WebSocket`](super::WebSocket) (eg/ order placement via
+/// the Binance WebSocket API or OKX).
+///
+/// Each request frame is sent with a unique correlation id, and the response with the matching
+/// id is awaited with a timeout. Once the connection closes, in-flight & subsequent requests
+/// fail immediately with a [`SocketError::Terminated`]. Frames that are not RPC responses (eg/ stream events) are
+/// forwarded to the events channel returned upon construction.
+///
+/// Cheaply cloneable, with every clone sharing the same connection.
+#[derive(Clone)]
+pub struct WsRpcClient {
+    tx: mpsc::UnboundedSender<WsMessage>,
+    pending: Pending,
+    next_id: Arc<AtomicU64>,
+    codec: Arc<dyn RpcCodec>,
+    signer: Option<Arc<dyn RpcSigner>>,
+    timeout: Duration,
+    runtime: Arc<dyn Runtime>,
+}
+
+impl Debug for WsRpcClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WsRpcClient")
+            .field("next_id", &self.next_id)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WsRpcClient {
+    /// Construct a new [`WsRpcClient`] using the provided split [`WebSocket`](super::WebSocket)
+    /// sink & stream, and exchange specific [`RpcCodec`].
+    ///
+    /// Spawns tasks that write request frames & route response frames. Returns the client
+    /// alongside a channel of every received frame that is not an RPC response.
+    ///
+    /// Must be called from within a Tokio runtime, see
+    /// [`new_with_runtime`](Self::new_with_runtime) for alternative executors.
+    pub fn new<WsSink, WsStream, Codec>(
+        sink: WsSink,
+        stream: WsStream,
+        codec: Codec,
+    ) -> (Self, mpsc::UnboundedReceiver<WsMessage>)
+    where
+        WsSink: Sink<WsMessage, Error = WsError> + Send + Unpin + 'static,
+        WsStream: Stream<Item = Result<WsMessage, WsError>> + Send + Unpin + 'static,
+        Codec: RpcCodec,
+    {
+        Self::new_with_runtime(sink, stream, codec, TokioRuntime)
+    }
+
+    /// Construct a new [`WsRpcClient`], using the provided [`Runtime`] to spawn the writer &
+    /// router tasks and to time out requests.
+    pub fn new_with_runtime<WsSink, WsStream, Codec, R>(
+        mut sink: WsSink,
+        mut stream: WsStream,
+        codec: Codec,
+        runtime: R,
+    ) -> (Self, mpsc::UnboundedReceiver<WsMessage>)
+    where
+        WsSink: Sink<WsMessage, Error = WsError> + Send + Unpin + 'static,
+        WsStream: Stream<Item = Result<WsMessage, WsError>> + Send + Unpin + 'static,
+        Codec: RpcCodec,
+        R: Runtime + 'static,
+    {
+        let runtime: Arc<dyn Runtime> = Arc::new(runtime);
+        let (tx, mut rx) = mpsc::unbounded_channel::<WsMessage>();
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let pending = Pending::default();
+        let codec: Arc<dyn RpcCodec> = Arc::new(codec);
+
+        let writer_pending = Arc::clone(&pending);
+        runtime.spawn(Box::pin(async move {
+            while let Some(frame) = rx.recv().await {
+                if let Err(error) = sink.send(frame).await {
+                    warn!(?error, "WsRpcClient failed to send request frame");
+                    break;
+                }
+            }
+
+            // Connection closed, so fail every pending request
+            Waiters::close(&writer_pending);
+        }));
+
+        let router_pending = Arc::clone(&pending);
+        let router_codec = Arc::clone(&codec);
+        runtime.spawn(Box::pin(async move {
+            while let Some(message) = stream.next().await {
+                let message = match message {
+                    Ok(message) => message,
+                    Err(error) => {
+                        warn!(?error, "WsRpcClient connection failed");
+                        break;
+                    }
+                };
+
+                match router_codec.decode(&message) {
+                    Some((id, outcome)) => {
+                        let waiter = router_pending
+                            .lock()
+                            .expect("WsRpcClient Mutex poisoned")
+                            .waiters
+                            .remove(&id);
+                        match waiter {
+                            Some(waiter) => {
+                                let _ = waiter.send(outcome);
+                            }
+                            None => debug!(id, "WsRpcClient received unexpected response id"),
+                        }
+                    }
+                    None => {
+                        let _ = event_tx.send(message);
+                    }
+                }
+            }
+
+            // Connection closed, so fail every pending request
+            Waiters::close(&router_pending);
+        }));
+
+        let client = Self {
+            tx,
+            pending,
+            next_id: Arc::new(AtomicU64::new(1)),
+            codec,
+            signer: None,
+            timeout: DEFAULT_RPC_TIMEOUT,
+            runtime,
+        };
+
+        (client, event_rx)
+    }
+
+    /// Sign the params of every request using the provided [`RpcSigner`].
+    pub fn with_signer<Signer>(self, signer: Signer) -> Self
+    where
+        Signer: RpcSigner,
+    {
+        Self {
+            signer: Some(Arc::new(signer)),
+            ..self
+        }
+    }
+
+    /// Maximum [`Duration`] to await each response. Defaults to 10 seconds.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    /// Send a request with the provided method & params, awaiting the response with the
+    /// matching correlation id.
+    pub async fn request<Params, Response>(
+        &self,
+        method: &str,
+        params: Params,
+    ) -> Result<Response, SocketError>
+    where
+        Params: Serialize,
+        Response: DeserializeOwned,
+    {
+        let mut params = match serde_json::to_value(params).map_err(SocketError::Serialise)? {
+            Value::Object(params) => params,
+            Value::Null => Map::new(),
+            other => {
+                return Err(SocketError::Unsupported {
+                    entity: "WsRpcClient",
+                    item: format!("non-object params: {other}"),
+                })
+            }
+        };
+
+        if let Some(signer) = &self.signer {
+            signer.sign(method, &mut params)?;
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let frame = self.codec.encode(id, method, Value::Object(params))?;
+
+        let (waiter_tx, waiter_rx) = oneshot::channel();
+        {
+            let mut pending = self.lock();
+            if pending.closed {
+                return Err(SocketError::Terminated(
+                    "WsRpcClient connection closed".to_string(),
+                ));
+            }
+            pending.waiters.insert(id, waiter_tx);
+        }
+
+        if self.tx.send(frame).is_err() {
+            self.lock().waiters.remove(&id);
+            return Err(SocketError::Sink);
+        }
+
+        let result = match runtime::timeout(&*self.runtime, self.timeout, waiter_rx).await {
+            Some(Ok(outcome)) => outcome?,
+            Some(Err(_)) => {
+                return Err(SocketError::Terminated(
+                    "WsRpcClient connection closed".to_string(),
+                ))
+            }
+            None => {
+                self.lock().waiters.remove(&id);
+                return Err(SocketError::ReadTimeout(self.timeout));
+            }
+        };
+
+        serde_json::from_value(result.clone()).map_err(|error| SocketError::Deserialise {
+            error,
+            payload: result.to_string(),
+        })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Waiters> {
+        self.pending.lock().expect("WsRpcClient Mutex poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::http::private::encoder::HexEncoder;
+    use hmac::{Hmac, Mac};
+    use serde::Deserialize;
+    use sha2::Sha256;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct OrderAck {
+        #[serde(rename = "orderId")]
+        order_id: u64,
+    }
+
+    #[tokio::test]
+    async fn test_ws_rpc_client() {
+        // In-memory WebSocket connection
+        let (sink_tx, mut server_rx) = futures::channel::mpsc::unbounded::<WsMessage>();
+        let (server_tx, stream_rx) = futures::channel::mpsc::unbounded::<WsMessage>();
+        let sink = sink_tx.sink_map_err(|_| WsError::ConnectionClosed);
+        let stream = stream_rx.map(Ok);
+
+        // Server echoes the order id of each request, and publishes an unrelated event
+        tokio::spawn(async move {
+            while let Some(WsMessage::Text(request)) = server_rx.next().await {
+                let request = serde_json::from_str::<Value>(&request).unwrap();
+                assert!(request["params"]["signature"].is_string());
+
+                let response = match request["params"]["symbol"].as_str() {
+                    Some("BTCUSDT") => serde_json::json!({
+                        "id": request["id"],
+                        "status": 200,
+                        "result": { "orderId": request["id"] },
+                    }),
+                    _ => serde_json::json!({
+                        "id": request["id"],
+                        "status": 400,
+                        "error": { "code": -1121, "msg": "Invalid symbol." },
+                    }),
+                };
+                server_tx
+                    .unbounded_send(WsMessage::Text(r#"{"e":"trade"}"#.to_string()))
+                    .unwrap();
+                server_tx
+                    .unbounded_send(WsMessage::Text(response.to_string()))
+                    .unwrap();
+            }
+        });
+
+        let signer = QuerySigner::new(
+            "api_key",
+            Hmac::<Sha256>::new_from_slice(b"secret").unwrap(),
+            HexEncoder,
+        );
+        let (client, mut events) = WsRpcClient::new(sink, stream, JsonRpcCodec);
+        let client = client.with_signer(signer);
+
+        let other = client.clone();
+        let (a, b) = tokio::join!(
+            client.request::<_, OrderAck>("order.place", serde_json::json!({"symbol": "BTCUSDT"})),
+            other.request::<_, OrderAck>("order.place", serde_json::json!({"symbol": "BTCUSDT"})),
+        );
+        let mut order_ids = vec![a.unwrap().order_id, b.unwrap().order_id];
+        order_ids.sort();
+        assert_eq!(order_ids, vec![1, 2]);
+
+        let invalid = client
+            .request::<_, OrderAck>("order.place", serde_json::json!({"symbol": "BAD"}))
+            .await;
+        assert!(matches!(invalid, Err(SocketError::Exchange(_))));
+
+        assert_eq!(
+            events.recv().await.unwrap(),
+            WsMessage::Text(r#"{"e":"trade"}"#.to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ws_rpc_client_fails_fast_when_closed() {
+        let (sink_tx, _server_rx) = futures::channel::mpsc::unbounded::<WsMessage>();
+        let (server_tx, stream_rx) = futures::channel::mpsc::unbounded::<WsMessage>();
+        let sink = sink_tx.sink_map_err(|_| WsError::ConnectionClosed);
+
+        let (client, _events) = WsRpcClient::new(sink, stream_rx.map(Ok), JsonRpcCodec);
+        let client = client.with_timeout(std::time::Duration::from_secs(60));
+
+        // In-flight request fails once the connection closes, rather than timing out
+        let in_flight = tokio::spawn({
+            let client = client.clone();
+            async move { client.request::<_, Value>("ping", ()).await }
+        });
+        tokio::task::yield_now().await;
+        drop(server_tx);
+        assert!(matches!(
+            in_flight.await.unwrap(),
+            Err(SocketError::Terminated(_))
+        ));
+
+        // Subsequent requests fail immediately
+        assert!(matches!(
+            client.request::<_, Value>("ping", ()).await,
+            Err(SocketError::Terminated(_))
+        ));
+    }
+}
@@ -0,0 +1,364 @@
+use super::{
+    auth::{authenticate_with_runtime, WsAuthenticator, DEFAULT_AUTH_TIMEOUT},
+    cache::send_batch,
+    connect, WebSocket, WsMessage,
+};
+use crate::{
+    error::SocketError,
+    runtime::{Runtime, TokioRuntime},
+};
+use futures::{
+    future::{AbortHandle, Either},
+    StreamExt,
+};
+use std::{
+    fmt::{Debug, Formatter},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::oneshot;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tracing::{debug, warn};
+
+/// Maintains a connected-but-unsubscribed standby [`WebSocket`] that can be promoted to replace a
+/// failed primary connection without waiting for a fresh handshake.
+///
+/// The standby handshake is driven in a background task, so once it has completed
+/// [`promote`](Self::promote) only has to send the subscription messages. Until promoted, the
+/// background task keeps reading the idle standby, such that pings are answered & a closed
+/// standby is detected. A replacement standby is spawned immediately after every promotion.
+///
+/// Background tasks are spawned using the configured [`Runtime`], defaulting to
+/// [`TokioRuntime`].
+///
+/// Note: some exchanges close connections that have not subscribed to anything after a period of
+/// time. Use [`refresh`](Self::refresh) to periodically recycle the standby if required.
+pub struct WarmStandby<Request> {
+    request: Request,
+    standby: Option<Standby>,
+    authenticator: Option<Box<dyn WsAuthenticator + Send + Sync>>,
+    runtime: Arc<dyn Runtime>,
+}
+
+/// Background task establishing & servicing a standby [`WebSocket`] until it is promoted.
+#[derive(Debug)]
+struct Standby {
+    task: AbortHandle,
+    result: oneshot::Receiver<Result<WebSocket, SocketError>>,
+    promote: oneshot::Sender<()>,
+    connected: Arc<AtomicBool>,
+    finished: Arc<AtomicBool>,
+}
+
+impl Standby {
+    fn spawn<Request>(runtime: &dyn Runtime, request: Request) -> Self
+    where
+        Request: IntoClientRequest + Debug + Unpin + Send + 'static,
+    {
+        let (promote, promoted) = oneshot::channel();
+        let (result_tx, result) = oneshot::channel();
+        let connected = Arc::new(AtomicBool::new(false));
+        let finished = Arc::new(AtomicBool::new(false));
+
+        let task = runtime.spawn_abortable(Box::pin({
+            let connected = Arc::clone(&connected);
+            let finished = Arc::clone(&finished);
+            async move {
+                let output = serve_standby(request, promoted, connected).await;
+                finished.store(true, Ordering::Release);
+                let _ = result_tx.send(output);
+            }
+        }));
+
+        Self {
+            task,
+            result,
+            promote,
+            connected,
+            finished,
+        }
+    }
+
+    /// Determine if the background task has finished (ie/ the standby failed or closed).
+    fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Acquire)
+    }
+
+    /// Determine if the standby [`WebSocket`] is connected & healthy.
+    fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Acquire) && !self.is_finished()
+    }
+}
+
+/// Establish a standby [`WebSocket`], reading (& discarding) any idle messages such that pings
+/// are answered, until it is promoted or closed.
+async fn serve_standby<Request>(
+    request: Request,
+    mut promoted: oneshot::Receiver<()>,
+    connected: Arc<AtomicBool>,
+) -> Result<WebSocket, SocketError>
+where
+    Request: IntoClientRequest + Debug + Unpin,
+{
+    let mut websocket = connect(request).await?;
+    connected.store(true, Ordering::Release);
+
+    let error = loop {
+        let message = match futures::future::select(&mut promoted, websocket.next()).await {
+            Either::Left(_) => None,
+            Either::Right((message, _)) => Some(message),
+        };
+
+        match message {
+            None => return Ok(websocket),
+            Some(Some(Ok(message))) => {
+                debug!(payload = ?message, "standby WebSocket received idle message")
+            }
+            Some(Some(Err(error))) => break SocketError::from(error),
+            Some(None) => break SocketError::Terminated("standby WebSocket closed".to_string()),
+        }
+    };
+
+    connected.store(false, Ordering::Release);
+    Err(error)
+}
+
+impl<Request> Debug for WarmStandby<Request>
+where
+    Request: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WarmStandby")
+            .field("request", &self.request)
+            .field("standby", &self.standby)
+            .field("authenticated", &self.authenticator.is_some())
+            .field("runtime", &self.runtime)
+            .finish()
+    }
+}
+
+impl<Request> WarmStandby<Request>
+where
+    Request: IntoClientRequest + Clone + Debug + Unpin + Send + 'static,
+{
+    /// Construct a new [`WarmStandby`] and begin establishing the first standby [`WebSocket`].
+    ///
+    /// Must be called from within a Tokio runtime, see
+    /// [`new_with_runtime`](Self::new_with_runtime) for alternative executors.
+    pub fn new(request: Request) -> Self {
+        Self::new_with_runtime(request, TokioRuntime)
+    }
+
+    /// Construct a new [`WarmStandby`] using the provided [`Runtime`] to spawn background tasks,
+    /// and begin establishing the first standby [`WebSocket`].
+    pub fn new_with_runtime<R>(request: Request, runtime: R) -> Self
+    where
+        R: Runtime + 'static,
+    {
+        let mut standby = Self {
+            request,
+            standby: None,
+            authenticator: None,
+            runtime: Arc::new(runtime),
+        };
+        standby.replenish();
+        standby
+    }
+
+    /// Authenticate every promoted [`WebSocket`] using the provided [`WsAuthenticator`] before
+    /// sending the subscription messages, such that private streams re-authenticate
+    /// automatically after failing over.
+    pub fn with_authenticator<Auth>(mut self, authenticator: Auth) -> Self
+    where
+        Auth: WsAuthenticator + Send + Sync + 'static,
+    {
+        self.authenticator = Some(Box::new(authenticator));
+        self
+    }
+
+    /// Determine if the standby [`WebSocket`] handshake has completed successfully, and the
+    /// standby is still connected.
+    pub fn is_ready(&self) -> bool {
+        self.standby.as_ref().is_some_and(Standby::is_connected)
+    }
+
+    /// Begin establishing a standby [`WebSocket`] if one is not already pending or connected,
+    /// replacing any standby that failed to connect or has since closed.
+    pub fn replenish(&mut self) {
+        if self.standby.as_ref().is_some_and(Standby::is_finished) {
+            self.refresh();
+        } else if self.standby.is_none() {
+            self.standby = Some(Standby::spawn(&*self.runtime, self.request.clone()));
+        }
+    }
+
+    /// Drop the current standby [`WebSocket`] and begin establishing a new one.
+    pub fn refresh(&mut self) {
+        if let Some(standby) = self.standby.take() {
+            standby.task.abort();
+        }
+        self.replenish();
+    }
+
+    /// Promote the standby [`WebSocket`] to be the primary connection by sending the provided
+    /// subscription messages over it.
+    ///
+    /// If the standby is unavailable (eg/ the background handshake failed, or the standby has
+    /// since closed), a new connection is established before subscribing. If configured, the
+    /// connection is authenticated first. A replacement standby is spawned before returning.
+    pub async fn promote<Subscriptions>(
+        &mut self,
+        subscriptions: Subscriptions,
+    ) -> Result<WebSocket, SocketError>
+    where
+        Subscriptions: IntoIterator<Item = WsMessage>,
+    {
+        let standby = match self.standby.take() {
+            Some(standby) => {
+                // Stop servicing the idle standby, handing it over for promotion
+                let _ = standby.promote.send(());
+                match standby.result.await {
+                    Ok(Ok(websocket)) => Some(websocket),
+                    Ok(Err(error)) => {
+                        warn!(
+                            ?error,
+                            "standby WebSocket unavailable, connecting on demand"
+                        );
+                        None
+                    }
+                    Err(error) => {
+                        warn!(
+                            ?error,
+                            "standby WebSocket task failed, connecting on demand"
+                        );
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
+        let mut websocket = match standby {
+            Some(websocket) => websocket,
+            None => connect(self.request.clone()).await?,
+        };
+
+        // Begin establishing the next standby before sending subscriptions
+        self.replenish();
+
+        // Authenticate with a freshly signed auth message, since private channels require it
+        if let Some(authenticator) = &self.authenticator {
+            authenticate_with_runtime(
+                &mut websocket,
+                authenticator.as_ref(),
+                DEFAULT_AUTH_TIMEOUT,
+                &*self.runtime,
+            )
+            .await?;
+        }
+
+        let subscriptions = subscriptions.into_iter().inspect(|subscription| {
+            debug!(payload = ?subscription, "sending subscription over promoted WebSocket")
+        });
+        send_batch(&mut websocket, subscriptions).await?;
+
+        Ok(websocket)
+    }
+}
+
+impl<Request> Drop for WarmStandby<Request> {
+    fn drop(&mut self) {
+        if let Some(standby) = self.standby.take() {
+            standby.task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::SinkExt;
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+
+    async fn wait_until<Condition>(mut condition: Condition)
+    where
+        Condition: FnMut() -> bool,
+    {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !condition() {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("condition not met within 5 seconds");
+    }
+
+    #[tokio::test]
+    async fn test_warm_standby_promote() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (pong_tx, pong_rx) = oneshot::channel();
+        let (subscription_tx, subscription_rx) = oneshot::channel();
+
+        // Server pings the idle standby, then awaits the subscription sent upon promotion
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+            websocket
+                .send(WsMessage::Ping(b"hb".to_vec()))
+                .await
+                .unwrap();
+            let Some(Ok(WsMessage::Pong(payload))) = websocket.next().await else {
+                panic!("expected pong frame");
+            };
+            pong_tx.send(payload).unwrap();
+
+            let Some(Ok(WsMessage::Text(subscription))) = websocket.next().await else {
+                panic!("expected subscription frame");
+            };
+            subscription_tx.send(subscription).unwrap();
+        });
+
+        let mut standby = WarmStandby::new(url);
+        wait_until(|| standby.is_ready()).await;
+
+        // Idle standby answers pings before being promoted
+        assert_eq!(pong_rx.await.unwrap(), b"hb");
+
+        standby
+            .promote([WsMessage::text("subscribe")])
+            .await
+            .unwrap();
+        assert_eq!(subscription_rx.await.unwrap(), "subscribe");
+    }
+
+    #[tokio::test]
+    async fn test_warm_standby_not_ready_after_failure() {
+        // Nothing listening, so the standby handshake fails
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let mut standby = WarmStandby::new(url.clone());
+        wait_until(|| standby.standby.as_ref().is_some_and(Standby::is_finished)).await;
+        assert!(!standby.is_ready());
+        assert!(standby.promote([]).await.is_err());
+
+        // Standby that closes whilst idle is no longer ready
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            websocket.close(None).await.unwrap();
+        });
+
+        let standby = WarmStandby::new(url);
+        wait_until(|| standby.standby.as_ref().is_some_and(Standby::is_finished)).await;
+        assert!(!standby.is_ready());
+    }
+}
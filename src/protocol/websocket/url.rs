@@ -0,0 +1,78 @@
+use crate::{error::SocketError, protocol::template::Template};
+use std::{collections::BTreeMap, fmt::Display};
+use url::Url;
+
+/// Typed [`WebSocket`](super::WebSocket) Url template with named `{parameter}` placeholders.
+///
+/// The template is validated on construction, and [`build`](Self::build) validates that every
+/// placeholder has been given a value and the resulting Url is a well formed `ws` or `wss` Url,
+/// so malformed Urls are caught before attempting to connect.
+///
+/// eg/ `WsUrl::new("wss://stream.x.com/ws/{listen_key}")?.param("listen_key", key).build()?`
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct WsUrl {
+    template: Template,
+    values: BTreeMap<String, String>,
+}
+
+impl WsUrl {
+    /// Construct a new [`WsUrl`] from the provided template.
+    pub fn new<S>(template: S) -> Result<Self, SocketError>
+    where
+        S: Into<String>,
+    {
+        Ok(Self {
+            template: Template::parse(template)?,
+            values: BTreeMap::new(),
+        })
+    }
+
+    /// Provide the value of a `{parameter}` placeholder. Values are percent-encoded.
+    pub fn param<Value>(mut self, name: &str, value: Value) -> Self
+    where
+        Value: Display,
+    {
+        self.values.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Names of every parameter placeholder in the template.
+    pub fn params(&self) -> impl Iterator<Item = &str> {
+        self.template.params()
+    }
+
+    /// Substitute every parameter and validate the resulting `ws` or `wss` [`Url`].
+    pub fn build(&self) -> Result<Url, SocketError> {
+        let url = Url::parse(&self.template.render(&self.values)?)?;
+
+        match url.scheme() {
+            "ws" | "wss" => Ok(url),
+            scheme => Err(SocketError::UrlTemplate(format!(
+                "unsupported WebSocket scheme '{scheme}' in Url: {url}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ws_url_build() {
+        let url = WsUrl::new("wss://stream.x.com/ws/{listen_key}")
+            .unwrap()
+            .param("listen_key", "key 1")
+            .build()
+            .unwrap();
+        assert_eq!(url.as_str(), "wss://stream.x.com/ws/key%201");
+
+        let https = WsUrl::new("https://api.x.com").unwrap().build();
+        assert!(matches!(https, Err(SocketError::UrlTemplate(_))));
+
+        let missing = WsUrl::new("wss://stream.x.com/ws/{listen_key}")
+            .unwrap()
+            .build();
+        assert!(matches!(missing, Err(SocketError::UrlTemplate(_))));
+    }
+}
@@ -0,0 +1,231 @@
+use futures::{
+    channel::oneshot::{self, Canceled},
+    future::{AbortHandle, BoxFuture, Either},
+};
+use std::{fmt::Debug, future::Future, time::Duration};
+
+/// Executor abstraction used for task spawning & timers by components such as the
+/// [`RestClient`](crate::protocol::http::rest::client::RestClient),
+/// [`RateLimiter`](crate::protocol::http::rest::rate_limit::RateLimiter),
+/// [`WarmStandby`](crate::protocol::websocket::standby::WarmStandby) and
+/// [`Shutdown`](crate::shutdown::Shutdown), enabling the crate to be embedded in environments
+/// where tokio is not the executor.
+///
+/// [`TokioRuntime`] is used by default. Enable the `async-std` or `smol` features for
+/// [`AsyncStdRuntime`] or [`SmolRuntime`] respectively.
+///
+/// Every task, timer & blocking operation performed by the crate itself goes through the
+/// [`Runtime`]. Note that establishing WebSocket connections & sending Http requests is delegated
+/// to tokio based clients (tokio-tungstenite & reqwest), which still require a tokio reactor.
+pub trait Runtime: Debug + Send + Sync {
+    /// Spawn the provided future as a detached background task.
+    fn spawn(&self, task: BoxFuture<'static, ()>);
+
+    /// Future that completes after the provided [`Duration`] has elapsed.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    /// Spawn the provided future as a background task, returning an [`AbortHandle`] that
+    /// cancels it.
+    fn spawn_abortable(&self, task: BoxFuture<'static, ()>) -> AbortHandle {
+        let (task, handle) = futures::future::abortable(task);
+        self.spawn(Box::pin(async move {
+            let _ = task.await;
+        }));
+        handle
+    }
+
+    /// Run the provided blocking closure on a thread where blocking is acceptable, returning a
+    /// future that completes once it has run.
+    ///
+    /// Defaults to running the closure on a newly spawned thread.
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>) -> BoxFuture<'static, ()> {
+        let (tx, rx) = oneshot::channel();
+        std::thread::spawn(move || {
+            task();
+            let _ = tx.send(());
+        });
+        Box::pin(async move {
+            let _ = rx.await;
+        })
+    }
+}
+
+/// Run the provided blocking closure using [`Runtime::spawn_blocking`], returning its output.
+///
+/// Fails with [`Canceled`] if the closure panicked.
+pub async fn spawn_blocking<F, T>(runtime: &dyn Runtime, task: F) -> Result<T, Canceled>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+    runtime
+        .spawn_blocking(Box::new(move || {
+            let _ = tx.send(task());
+        }))
+        .await;
+    rx.await
+}
+
+/// Await the provided future, returning `None` if the provided [`Duration`] elapses first
+/// according to the [`Runtime`] timer.
+pub async fn timeout<Fut>(
+    runtime: &dyn Runtime,
+    duration: Duration,
+    future: Fut,
+) -> Option<Fut::Output>
+where
+    Fut: Future,
+{
+    let future = std::pin::pin!(future);
+    match futures::future::select(future, runtime.sleep(duration)).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+/// Default tokio [`Runtime`]. Must be used from within a tokio runtime.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct TokioRuntime;
+
+impl Runtime for TokioRuntime {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        tokio::spawn(task);
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>) -> BoxFuture<'static, ()> {
+        let handle = tokio::task::spawn_blocking(task);
+        Box::pin(async move {
+            let _ = handle.await;
+        })
+    }
+}
+
+/// async-std [`Runtime`].
+#[cfg(feature = "async-std")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AsyncStdRuntime;
+
+#[cfg(feature = "async-std")]
+impl Runtime for AsyncStdRuntime {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        async_std::task::spawn(task);
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(async_std::task::sleep(duration))
+    }
+
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>) -> BoxFuture<'static, ()> {
+        Box::pin(async_std::task::spawn_blocking(task))
+    }
+}
+
+/// smol [`Runtime`], spawning onto the global smol executor.
+#[cfg(feature = "smol")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SmolRuntime;
+
+#[cfg(feature = "smol")]
+impl Runtime for SmolRuntime {
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        smol::spawn(task).detach();
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(async move {
+            smol::Timer::after(duration).await;
+        })
+    }
+
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>) -> BoxFuture<'static, ()> {
+        Box::pin(smol::unblock(task))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[tokio::test]
+    async fn test_tokio_runtime() {
+        let runtime = TokioRuntime;
+        let (tx, rx) = tokio::sync::oneshot::channel();
+
+        let start = Instant::now();
+        runtime.spawn(Box::pin(async move {
+            TokioRuntime.sleep(Duration::from_millis(10)).await;
+            tx.send(()).unwrap();
+        }));
+
+        rx.await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(10));
+    }
+
+    #[tokio::test]
+    async fn test_runtime_abortable_timeout() {
+        let runtime = TokioRuntime;
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+
+        // Aborted task is dropped, dropping the Sender it owns
+        let handle = runtime.spawn_abortable(Box::pin(async move {
+            let _tx = tx;
+            std::future::pending::<()>().await;
+        }));
+        handle.abort();
+        assert!(rx.await.is_err());
+
+        assert_eq!(
+            timeout(&runtime, Duration::from_secs(1), async { 1 }).await,
+            Some(1)
+        );
+        assert_eq!(
+            timeout(
+                &runtime,
+                Duration::from_millis(1),
+                std::future::pending::<()>()
+            )
+            .await,
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_runtime_spawn_blocking() {
+        assert_eq!(spawn_blocking(&TokioRuntime, || 1).await, Ok(1));
+
+        // Default implementation
+        #[derive(Debug)]
+        struct Threaded;
+
+        impl Runtime for Threaded {
+            fn spawn(&self, task: BoxFuture<'static, ()>) {
+                tokio::spawn(task);
+            }
+
+            fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+                Box::pin(tokio::time::sleep(duration))
+            }
+        }
+
+        assert_eq!(spawn_blocking(&Threaded, || 2).await, Ok(2));
+        let panicked = spawn_blocking::<_, ()>(&Threaded, || panic!("blocking task panicked"));
+        assert!(panicked.await.is_err());
+    }
+
+    #[cfg(feature = "smol")]
+    #[test]
+    fn test_smol_runtime() {
+        let start = Instant::now();
+        smol::block_on(SmolRuntime.sleep(Duration::from_millis(10)));
+        assert!(start.elapsed() >= Duration::from_millis(10));
+
+        let output = smol::block_on(spawn_blocking(&SmolRuntime, || 1));
+        assert_eq!(output, Ok(1));
+    }
+}
@@ -0,0 +1,205 @@
+use crate::runtime::{self, Runtime, TokioRuntime};
+use futures::future::{join_all, BoxFuture};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt::{Debug, Formatter},
+    future::Future,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tracing::{info, warn};
+
+/// Default maximum [`Duration`] each [`ShutdownStage`] is given to complete.
+pub const DEFAULT_SHUTDOWN_STAGE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Stage of an orderly [`Shutdown`]. Stages are actioned in ascending order, such that components
+/// are torn down in dependency order.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShutdownStage {
+    /// Stop consuming public market data.
+    MarketData,
+    /// Cancel in-flight orders (eg/ via REST).
+    Orders,
+    /// Close private account streams.
+    PrivateStreams,
+}
+
+/// Orchestrates the teardown of many components in [`ShutdownStage`] order.
+///
+/// All tasks registered to the same [`ShutdownStage`] are run concurrently, and each stage is
+/// bounded by its own timeout. The next stage only begins once every task in the previous stage
+/// has completed or timed out.
+///
+/// Stage timeouts are timed using the configured [`Runtime`], defaulting to [`TokioRuntime`].
+///
+/// eg/ Stop consuming market data -> cancel in-flight orders -> close private streams.
+pub struct Shutdown {
+    stages: BTreeMap<ShutdownStage, Stage>,
+    runtime: Arc<dyn Runtime>,
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self {
+            stages: BTreeMap::default(),
+            runtime: Arc::new(TokioRuntime),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Stage {
+    timeout: Option<Duration>,
+    tasks: Vec<(&'static str, BoxFuture<'static, ()>)>,
+}
+
+impl Debug for Shutdown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.stages.iter().map(|(stage, tasks)| {
+                let names = tasks
+                    .tasks
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>();
+                (stage, names)
+            }))
+            .finish()
+    }
+}
+
+impl Shutdown {
+    /// Construct a new empty [`Shutdown`] sequence.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use the provided [`Runtime`] to time each [`ShutdownStage`].
+    pub fn with_runtime<R>(self, runtime: R) -> Self
+    where
+        R: Runtime + 'static,
+    {
+        Self {
+            runtime: Arc::new(runtime),
+            ..self
+        }
+    }
+
+    /// Set the maximum [`Duration`] the provided [`ShutdownStage`] is given to complete.
+    pub fn with_timeout(mut self, stage: ShutdownStage, timeout: Duration) -> Self {
+        self.stages.entry(stage).or_default().timeout = Some(timeout);
+        self
+    }
+
+    /// Register a named teardown task to be run during the provided [`ShutdownStage`].
+    pub fn register<Task>(&mut self, stage: ShutdownStage, name: &'static str, task: Task)
+    where
+        Task: Future<Output = ()> + Send + 'static,
+    {
+        self.stages
+            .entry(stage)
+            .or_default()
+            .tasks
+            .push((name, Box::pin(task)));
+    }
+
+    /// Run every registered teardown task in [`ShutdownStage`] order.
+    pub async fn run(self) -> ShutdownReport {
+        let mut report = ShutdownReport::default();
+        let runtime = &*self.runtime;
+
+        for (stage, Stage { timeout, tasks }) in self.stages {
+            let timeout = timeout.unwrap_or(DEFAULT_SHUTDOWN_STAGE_TIMEOUT);
+            let start = Instant::now();
+
+            info!(
+                ?stage,
+                tasks = tasks.len(),
+                ?timeout,
+                "running shutdown stage"
+            );
+
+            let timed_out = join_all(tasks.into_iter().map(|(name, task)| async move {
+                runtime::timeout(runtime, timeout, task)
+                    .await
+                    .is_none()
+                    .then_some(name)
+            }))
+            .await
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+            if !timed_out.is_empty() {
+                warn!(?stage, ?timed_out, "shutdown stage tasks timed out");
+            }
+
+            report.stages.push(StageReport {
+                stage,
+                duration: start.elapsed(),
+                timed_out,
+            });
+        }
+
+        report
+    }
+}
+
+/// Outcome of running a [`Shutdown`] sequence.
+#[derive(Clone, Eq, PartialEq, Debug, Default)]
+pub struct ShutdownReport {
+    pub stages: Vec<StageReport>,
+}
+
+impl ShutdownReport {
+    /// Determine if every registered teardown task completed within its stage timeout.
+    pub fn is_clean(&self) -> bool {
+        self.stages.iter().all(|stage| stage.timed_out.is_empty())
+    }
+}
+
+/// Outcome of running a single [`ShutdownStage`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct StageReport {
+    pub stage: ShutdownStage,
+    pub duration: Duration,
+    /// Names of the teardown tasks that did not complete before the stage timeout.
+    pub timed_out: Vec<&'static str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    #[tokio::test]
+    async fn test_shutdown_runs_stages_in_order() {
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut shutdown =
+            Shutdown::new().with_timeout(ShutdownStage::Orders, Duration::from_millis(10));
+
+        let stages = [
+            (ShutdownStage::PrivateStreams, "private"),
+            (ShutdownStage::MarketData, "market_data"),
+            (ShutdownStage::Orders, "orders"),
+        ];
+        for (stage, name) in stages {
+            let order = Arc::clone(&order);
+            shutdown.register(stage, name, async move { order.lock().unwrap().push(name) });
+        }
+        shutdown.register(ShutdownStage::Orders, "stuck", futures::future::pending());
+
+        let report = shutdown.run().await;
+
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec!["market_data", "orders", "private"]
+        );
+        assert!(!report.is_clean());
+        assert_eq!(report.stages[1].stage, ShutdownStage::Orders);
+        assert_eq!(report.stages[1].timed_out, vec!["stuck"]);
+        assert!(report.stages[2].timed_out.is_empty());
+    }
+}
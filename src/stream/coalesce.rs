@@ -0,0 +1,238 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::{Debug, Formatter},
+    hash::Hash,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::mpsc;
+
+/// Action the caller of [`Coalescer::subscribe`] must take on the wire.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum CoalesceAction {
+    /// First requester of the key, so the subscription must be actioned on the wire.
+    Subscribe,
+    /// Key is already subscribed on the wire, so the requester joins the existing subscription.
+    Coalesced,
+}
+
+/// Coalesces duplicate subscriptions requested by many components (eg/ the same exchange,
+/// instrument & kind), such that each key is subscribed once on the wire and the results are
+/// fanned out to every requester.
+///
+/// Each requester receives an independent channel with its own lifecycle. Dropping a receiver
+/// unsubscribes only that requester, and once a key has no remaining requesters it is reported
+/// by [`publish`](Self::publish) & [`prune`](Self::prune) so it can be unsubscribed on the wire.
+///
+/// A key is considered subscribed on the wire from the first [`CoalesceAction::Subscribe`] until
+/// it is reported by [`publish`](Self::publish), [`prune`](Self::prune) or
+/// [`fail`](Self::fail), independently of whether it currently has live requesters. Requesters
+/// of a key whose requesters have all dropped, but has not yet been reported, rejoin the existing
+/// wire subscription.
+///
+/// Cheaply cloneable, with every clone sharing the same subscriptions.
+pub struct Coalescer<Key, T> {
+    subscriptions: Arc<Mutex<HashMap<Key, Vec<mpsc::UnboundedSender<T>>>>>,
+}
+
+impl<Key, T> Clone for Coalescer<Key, T> {
+    fn clone(&self) -> Self {
+        Self {
+            subscriptions: Arc::clone(&self.subscriptions),
+        }
+    }
+}
+
+impl<Key, T> Default for Coalescer<Key, T> {
+    fn default() -> Self {
+        Self {
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+impl<Key, T> Debug for Coalescer<Key, T>
+where
+    Key: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let subscriptions = self.lock();
+        f.debug_map()
+            .entries(
+                subscriptions
+                    .iter()
+                    .map(|(key, requesters)| (key, requesters.len())),
+            )
+            .finish()
+    }
+}
+
+impl<Key, T> Coalescer<Key, T>
+where
+    Key: Hash + Eq + Clone,
+    T: Clone,
+{
+    /// Construct a new empty [`Coalescer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a requester of the provided key, returning the [`CoalesceAction`] the caller
+    /// must take on the wire, and the requester's independent channel of results.
+    pub fn subscribe(&self, key: Key) -> (CoalesceAction, mpsc::UnboundedReceiver<T>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let action = match self.lock().entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(vec![tx]);
+                CoalesceAction::Subscribe
+            }
+            Entry::Occupied(mut entry) => {
+                let requesters = entry.get_mut();
+                requesters.retain(|requester| !requester.is_closed());
+                requesters.push(tx);
+                CoalesceAction::Coalesced
+            }
+        };
+
+        (action, rx)
+    }
+
+    /// Fail the wire subscription of the provided key (eg/ the exchange rejected it), fanning
+    /// out the provided terminal item (eg/ an error event) to every requester, including
+    /// [`CoalesceAction::Coalesced`] joiners, before closing their channels.
+    ///
+    /// The key is removed, such that the next requester re-subscribes on the wire. Returns the
+    /// number of live requesters notified.
+    pub fn fail(&self, key: &Key, item: T) -> usize {
+        let Some(requesters) = self.lock().remove(key) else {
+            return 0;
+        };
+
+        requesters
+            .into_iter()
+            .filter(|requester| requester.send(item.clone()).is_ok())
+            .count()
+    }
+
+    /// Fan out an item received for the provided key to every live requester.
+    ///
+    /// Returns `false` if the key has no remaining requesters, in which case it is removed and
+    /// should be unsubscribed on the wire.
+    pub fn publish(&self, key: &Key, item: T) -> bool {
+        let mut subscriptions = self.lock();
+        let Some(requesters) = subscriptions.get_mut(key) else {
+            return false;
+        };
+
+        requesters.retain(|requester| requester.send(item.clone()).is_ok());
+        if requesters.is_empty() {
+            subscriptions.remove(key);
+            return false;
+        }
+
+        true
+    }
+
+    /// Number of live requesters of the provided key.
+    pub fn requesters(&self, key: &Key) -> usize {
+        self.lock().get(key).map_or(0, |requesters| {
+            requesters
+                .iter()
+                .filter(|requester| !requester.is_closed())
+                .count()
+        })
+    }
+
+    /// Remove & return every key that no longer has any live requesters, such that they can be
+    /// unsubscribed on the wire.
+    pub fn prune(&self) -> Vec<Key> {
+        let mut subscriptions = self.lock();
+        let mut pruned = Vec::new();
+        subscriptions.retain(|key, requesters| {
+            requesters.retain(|requester| !requester.is_closed());
+            if requesters.is_empty() {
+                pruned.push(key.clone());
+                false
+            } else {
+                true
+            }
+        });
+        pruned
+    }
+}
+
+impl<Key, T> Coalescer<Key, T> {
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<Key, Vec<mpsc::UnboundedSender<T>>>> {
+        self.subscriptions.lock().expect("Coalescer Mutex poisoned")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coalescer() {
+        let coalescer = Coalescer::<&str, u64>::new();
+
+        let (action_a, mut rx_a) = coalescer.subscribe("btc_trades");
+        let (action_b, mut rx_b) = coalescer.clone().subscribe("btc_trades");
+        assert_eq!(action_a, CoalesceAction::Subscribe);
+        assert_eq!(action_b, CoalesceAction::Coalesced);
+        assert_eq!(coalescer.requesters(&"btc_trades"), 2);
+
+        // Results are fanned out to every requester
+        assert!(coalescer.publish(&"btc_trades", 1));
+        assert_eq!(rx_a.try_recv().unwrap(), 1);
+        assert_eq!(rx_b.try_recv().unwrap(), 1);
+
+        // Requesters have independent lifecycles
+        drop(rx_a);
+        assert!(coalescer.publish(&"btc_trades", 2));
+        assert_eq!(rx_b.try_recv().unwrap(), 2);
+
+        // Once every requester has gone the key should be unsubscribed on the wire
+        drop(rx_b);
+        assert_eq!(coalescer.requesters(&"btc_trades"), 0);
+
+        // Wire subscription remains until reported, so a new requester rejoins it
+        let (action_c, rx_c) = coalescer.subscribe("btc_trades");
+        assert_eq!(action_c, CoalesceAction::Coalesced);
+        assert!(coalescer.prune().is_empty());
+
+        drop(rx_c);
+        assert_eq!(coalescer.prune(), vec!["btc_trades"]);
+        assert!(!coalescer.publish(&"btc_trades", 3));
+        assert_eq!(
+            coalescer.subscribe("btc_trades").0,
+            CoalesceAction::Subscribe
+        );
+    }
+
+    #[test]
+    fn test_coalescer_fail() {
+        let coalescer = Coalescer::<&str, Result<u64, String>>::new();
+
+        let (_, mut rx_a) = coalescer.subscribe("btc_trades");
+        let (action_b, mut rx_b) = coalescer.subscribe("btc_trades");
+        assert_eq!(action_b, CoalesceAction::Coalesced);
+
+        // Failed wire subscription is propagated to every requester, including joiners
+        let error = Err("invalid symbol".to_string());
+        assert_eq!(coalescer.fail(&"btc_trades", error.clone()), 2);
+        for rx in [&mut rx_a, &mut rx_b] {
+            assert_eq!(rx.try_recv().unwrap(), error);
+            assert!(matches!(
+                rx.try_recv(),
+                Err(mpsc::error::TryRecvError::Disconnected)
+            ));
+        }
+
+        // Next requester re-subscribes on the wire
+        assert_eq!(
+            coalescer.subscribe("btc_trades").0,
+            CoalesceAction::Subscribe
+        );
+        assert_eq!(coalescer.fail(&"eth_trades", Ok(1)), 0);
+    }
+}
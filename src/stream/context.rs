@@ -0,0 +1,161 @@
+use crate::model::{ExchangeId, SubscriptionId};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Cheaply cloneable flag used to cooperatively cancel in-progress
+/// [`Transformer`](crate::Transformer) work, eg/ during shutdown.
+///
+/// Every clone shares the same flag, so one flag can be shared by many
+/// [`ExchangeStream`](crate::ExchangeStream)s.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationFlag {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationFlag {
+    /// Signal cancellation to every holder of this flag.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Determine if cancellation has been signalled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
+
+/// Metadata describing the connection an [`ExchangeStream`](crate::ExchangeStream) is consuming.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ConnectionMeta {
+    pub exchange: Option<ExchangeId>,
+    pub url: Option<String>,
+    pub connected: DateTime<Utc>,
+}
+
+impl Default for ConnectionMeta {
+    fn default() -> Self {
+        Self {
+            exchange: None,
+            url: None,
+            connected: Utc::now(),
+        }
+    }
+}
+
+/// Context provided to [`Transformer::transform_with_context`](crate::Transformer) for each input
+/// message.
+///
+/// Enables long-running transforms to cooperatively yield once their time budget is spent, or
+/// abort upon cancellation, rather than blocking the poll loop.
+#[derive(Debug, Copy, Clone)]
+pub struct TransformContext<'a> {
+    received: Instant,
+    budget: Option<Duration>,
+    cancellation: &'a CancellationFlag,
+    connection: &'a ConnectionMeta,
+    span: Option<&'a tracing::Span>,
+}
+
+impl<'a> TransformContext<'a> {
+    /// Construct a new [`TransformContext`] for an input message received at the provided
+    /// [`Instant`].
+    pub fn new(
+        received: Instant,
+        budget: Option<Duration>,
+        cancellation: &'a CancellationFlag,
+        connection: &'a ConnectionMeta,
+    ) -> Self {
+        Self {
+            received,
+            budget,
+            cancellation,
+            connection,
+            span: None,
+        }
+    }
+
+    /// Set the tracing span that [`Self::record_subscription_id`] records onto.
+    pub fn with_span(self, span: &'a tracing::Span) -> Self {
+        Self {
+            span: Some(span),
+            ..self
+        }
+    }
+
+    /// [`Duration`] elapsed since the input message was received.
+    pub fn elapsed(&self) -> Duration {
+        self.received.elapsed()
+    }
+
+    /// Remaining time budget for processing the input message, if a budget is configured.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.budget
+            .map(|budget| budget.saturating_sub(self.elapsed()))
+    }
+
+    /// Determine if the configured time budget has been spent.
+    pub fn is_over_budget(&self) -> bool {
+        self.remaining()
+            .is_some_and(|remaining| remaining.is_zero())
+    }
+
+    /// Determine if cancellation has been signalled (eg/ during shutdown).
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+
+    /// Determine if the transform should stop work as soon as possible, either because it has
+    /// been cancelled or has spent its time budget.
+    pub fn should_yield(&self) -> bool {
+        self.is_cancelled() || self.is_over_budget()
+    }
+
+    /// [`ConnectionMeta`] of the connection the input message was received on.
+    pub fn connection(&self) -> &'a ConnectionMeta {
+        self.connection
+    }
+
+    /// Record the [`SubscriptionId`] the input message is associated with on the tracing span,
+    /// if one is set.
+    ///
+    /// eg/ The `exchange_stream_transform` span if the `tracing-spans` feature is enabled.
+    pub fn record_subscription_id(&self, subscription_id: &SubscriptionId) {
+        if let Some(span) = self.span {
+            span.record("subscription_id", tracing::field::display(subscription_id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_context() {
+        let cancellation = CancellationFlag::default();
+        let connection = ConnectionMeta::default();
+
+        let unbounded = TransformContext::new(Instant::now(), None, &cancellation, &connection);
+        assert_eq!(unbounded.remaining(), None);
+        assert!(!unbounded.should_yield());
+
+        let spent = TransformContext::new(
+            Instant::now() - Duration::from_millis(20),
+            Some(Duration::from_millis(10)),
+            &cancellation,
+            &connection,
+        );
+        assert_eq!(spent.remaining(), Some(Duration::ZERO));
+        assert!(spent.is_over_budget() && spent.should_yield());
+
+        cancellation.clone().cancel();
+        assert!(unbounded.is_cancelled() && unbounded.should_yield());
+    }
+}
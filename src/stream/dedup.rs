@@ -0,0 +1,151 @@
+use futures::Stream;
+use pin_project::pin_project;
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{Debug, Formatter},
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tracing::debug;
+
+/// [`Stream`] adapter that filters duplicate items (eg/ trades or updates that exchanges re-send
+/// after reconnecting), keyed by an id extracted via the user provided closure.
+///
+/// Only the most recently seen `capacity` ids are remembered, with the least recently seen id
+/// evicted first. Errors are passed through untouched.
+#[pin_project]
+pub struct Dedup<St, F, Id> {
+    #[pin]
+    stream: St,
+    id: F,
+    window: LruWindow<Id>,
+}
+
+impl<St, F, Id> Debug for Dedup<St, F, Id>
+where
+    St: Debug,
+    Id: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Dedup")
+            .field("stream", &self.stream)
+            .field("window", &self.window)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<St, T, E, F, Id> Dedup<St, F, Id>
+where
+    St: Stream<Item = Result<T, E>>,
+    F: FnMut(&T) -> Id,
+    Id: Hash + Eq + Clone,
+{
+    /// Construct a new [`Dedup`] that remembers the most recent `capacity` item ids.
+    pub fn new(stream: St, capacity: usize, id: F) -> Self {
+        Self {
+            stream,
+            id,
+            window: LruWindow::new(capacity),
+        }
+    }
+}
+
+impl<St, T, E, F, Id> Stream for Dedup<St, F, Id>
+where
+    St: Stream<Item = Result<T, E>>,
+    F: FnMut(&T) -> Id,
+    Id: Hash + Eq + Clone + Debug,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let item = match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(item))) => item,
+                other => return other,
+            };
+
+            let id = (this.id)(&item);
+            if this.window.observe(id.clone()) {
+                debug!(?id, "Dedup filtered duplicate item");
+                continue;
+            }
+
+            return Poll::Ready(Some(Ok(item)));
+        }
+    }
+}
+
+/// Bounded window of recently seen ids, evicting the least recently seen id first.
+#[derive(Debug)]
+struct LruWindow<Id> {
+    capacity: usize,
+    stamp: u64,
+    seen: HashMap<Id, u64>,
+    order: VecDeque<(Id, u64)>,
+}
+
+impl<Id> LruWindow<Id>
+where
+    Id: Hash + Eq + Clone,
+{
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            stamp: 0,
+            seen: HashMap::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Mark the id as most recently seen, returning true if it was already in the window.
+    fn observe(&mut self, id: Id) -> bool {
+        self.stamp += 1;
+        let duplicate = self.seen.insert(id.clone(), self.stamp).is_some();
+        self.order.push_back((id, self.stamp));
+
+        // Evict least recently seen ids, skipping order entries superseded by a later observation
+        while self.seen.len() > self.capacity {
+            let Some((id, stamp)) = self.order.pop_front() else {
+                break;
+            };
+            if self.seen.get(&id) == Some(&stamp) {
+                self.seen.remove(&id);
+            }
+        }
+
+        // Compact superseded order entries so repeated observations cannot grow it unbounded
+        if self.order.len() > self.capacity * 2 {
+            let seen = &self.seen;
+            self.order.retain(|(id, stamp)| seen.get(id) == Some(stamp));
+        }
+
+        duplicate
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+
+    #[tokio::test]
+    async fn test_dedup_lru_window() {
+        // Window of 2: "a" is refreshed by its duplicate, so "b" is evicted when "c" arrives
+        let inputs = vec!["a", "b", "a", "c", "b", "a", "c"];
+
+        let actual = Dedup::new(
+            stream::iter(inputs.into_iter().map(Ok::<_, ()>)),
+            2,
+            |id: &&str| *id,
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+        let expected = vec![Ok("a"), Ok("b"), Ok("c"), Ok("b"), Ok("a"), Ok("c")];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,395 @@
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use pin_project::pin_project;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// Normalised outputs that can be sanity checked by a [`SanityGuard`].
+pub trait SanityCheck {
+    /// Key the [`SanityLimits`] & last accepted price are tracked by. eg/ Instrument.
+    type Key: Hash + Eq + Clone;
+
+    fn key(&self) -> Self::Key;
+
+    fn price(&self) -> Option<f64> {
+        None
+    }
+
+    fn quantity(&self) -> Option<f64> {
+        None
+    }
+
+    fn time(&self) -> Option<DateTime<Utc>> {
+        None
+    }
+}
+
+/// Determines how a [`SanityGuard`] handles events that violate their [`SanityLimits`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Deserialize, Serialize)]
+pub enum GuardAction {
+    /// Yield the event downstream, tagged with its [`Violation`]s.
+    #[default]
+    Tag,
+    /// Log & drop the event.
+    Reject,
+}
+
+/// Sanity limits applied to the events of an instrument.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct SanityLimits {
+    /// Reject negative & non-finite quantities.
+    pub non_negative_quantity: bool,
+
+    /// Maximum fractional deviation from the last accepted price. eg/ 0.1 for 10%.
+    pub max_price_deviation: Option<f64>,
+
+    /// Number of consecutive price deviating events after which the latest deviating price is
+    /// accepted as the new reference price, such that a genuine gap move is not flagged forever.
+    pub reanchor_after: Option<usize>,
+
+    /// Maximum age of the last accepted price, after which the next valid price is accepted as
+    /// the new reference price rather than being measured against a stale one.
+    pub max_anchor_age: Option<Duration>,
+
+    /// Maximum difference between the event time & the local time.
+    pub max_time_drift: Option<Duration>,
+
+    pub action: GuardAction,
+}
+
+impl Default for SanityLimits {
+    fn default() -> Self {
+        Self {
+            non_negative_quantity: true,
+            max_price_deviation: None,
+            reanchor_after: Some(5),
+            max_anchor_age: None,
+            max_time_drift: None,
+            action: GuardAction::default(),
+        }
+    }
+}
+
+/// Sanity limit violated by an event.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Violation {
+    InvalidQuantity(f64),
+    InvalidPrice(f64),
+    PriceDeviation { price: f64, last: f64 },
+    TimestampDrift(Duration),
+}
+
+/// Event yielded by a [`SanityGuard`], tagged with any [`Violation`]s of its [`SanityLimits`].
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Checked<T> {
+    pub event: T,
+    pub violations: Vec<Violation>,
+}
+
+impl<T> Checked<T> {
+    /// Determine if the event violated none of its [`SanityLimits`].
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+impl SanityLimits {
+    /// Determine the [`Violation`]s of the provided event, given the last accepted price.
+    pub fn check<T>(&self, event: &T, last_price: Option<f64>, now: DateTime<Utc>) -> Vec<Violation>
+    where
+        T: SanityCheck,
+    {
+        let mut violations = Vec::new();
+
+        if let Some(quantity) = event.quantity() {
+            if self.non_negative_quantity && !(quantity.is_finite() && quantity >= 0.0) {
+                violations.push(Violation::InvalidQuantity(quantity));
+            }
+        }
+
+        if let Some(price) = event.price() {
+            if !price.is_finite() || price <= 0.0 {
+                violations.push(Violation::InvalidPrice(price));
+            } else if let (Some(max), Some(last)) = (self.max_price_deviation, last_price) {
+                if ((price - last) / last).abs() > max {
+                    violations.push(Violation::PriceDeviation { price, last });
+                }
+            }
+        }
+
+        if let (Some(max), Some(time)) = (self.max_time_drift, event.time()) {
+            let drift = (now - time).abs().to_std().unwrap_or_default();
+            if drift > max {
+                violations.push(Violation::TimestampDrift(drift));
+            }
+        }
+
+        violations
+    }
+}
+
+/// [`Stream`] adapter that sanity checks normalised outputs against configurable
+/// [`SanityLimits`], tagging or rejecting obviously corrupt events (eg/ exchange glitches,
+/// fat-finger prints) before they reach trading logic.
+///
+/// Price deviations are measured against the last accepted price of each instrument, so corrupt
+/// prints do not poison subsequent checks. The reference price is re-anchored after
+/// [`SanityLimits::reanchor_after`] consecutive deviating prints, or once older than
+/// [`SanityLimits::max_anchor_age`]. Errors are passed through untouched.
+#[pin_project]
+pub struct SanityGuard<St, Key> {
+    #[pin]
+    stream: St,
+    default: SanityLimits,
+    instruments: HashMap<Key, SanityLimits>,
+    last_price: HashMap<Key, Anchor>,
+}
+
+/// Reference price of an instrument that price deviations are measured against.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct Anchor {
+    price: f64,
+    accepted: Instant,
+    deviations: usize,
+}
+
+impl Anchor {
+    fn new(price: f64) -> Self {
+        Self {
+            price,
+            accepted: Instant::now(),
+            deviations: 0,
+        }
+    }
+}
+
+impl<St, Key> Debug for SanityGuard<St, Key>
+where
+    St: Debug,
+    Key: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SanityGuard")
+            .field("stream", &self.stream)
+            .field("default", &self.default)
+            .field("instruments", &self.instruments)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<St, T, E> SanityGuard<St, T::Key>
+where
+    St: Stream<Item = Result<T, E>>,
+    T: SanityCheck,
+{
+    /// Construct a new [`SanityGuard`] applying the provided default [`SanityLimits`].
+    pub fn new(stream: St, default: SanityLimits) -> Self {
+        Self {
+            stream,
+            default,
+            instruments: HashMap::new(),
+            last_price: HashMap::new(),
+        }
+    }
+
+    /// Apply the provided [`SanityLimits`] to the instrument, overriding the default.
+    pub fn with_instrument(mut self, key: T::Key, limits: SanityLimits) -> Self {
+        self.instruments.insert(key, limits);
+        self
+    }
+}
+
+impl<St, T, E> Stream for SanityGuard<St, T::Key>
+where
+    St: Stream<Item = Result<T, E>>,
+    T: SanityCheck + Debug,
+{
+    type Item = Result<Checked<T>, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let event = match this.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(event))) => event,
+                Poll::Ready(Some(Err(error))) => return Poll::Ready(Some(Err(error))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let key = event.key();
+            let limits = this.instruments.get(&key).unwrap_or(this.default);
+
+            // Ignore a reference price that is older than the SanityLimits::max_anchor_age
+            let anchor = this.last_price.get(&key).copied().filter(|anchor| {
+                limits
+                    .max_anchor_age
+                    .is_none_or(|max| anchor.accepted.elapsed() <= max)
+            });
+            let violations = limits.check(&event, anchor.map(|anchor| anchor.price), Utc::now());
+
+            let deviated = violations
+                .iter()
+                .any(|violation| matches!(violation, Violation::PriceDeviation { .. }));
+
+            match (event.price(), anchor) {
+                (Some(price), Some(mut anchor)) if deviated => {
+                    // Re-anchor after consecutive deviations, since the move is likely genuine
+                    anchor.deviations += 1;
+                    if limits
+                        .reanchor_after
+                        .is_some_and(|reanchor| anchor.deviations >= reanchor)
+                    {
+                        anchor = Anchor::new(price);
+                    }
+                    this.last_price.insert(key, anchor);
+                }
+                (Some(price), _) if violations.is_empty() => {
+                    this.last_price.insert(key, Anchor::new(price));
+                }
+                _ => {}
+            }
+
+            if !violations.is_empty() && limits.action == GuardAction::Reject {
+                warn!(?event, ?violations, "SanityGuard rejecting event");
+                continue;
+            }
+
+            return Poll::Ready(Some(Ok(Checked { event, violations })));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Trade {
+        instrument: &'static str,
+        price: f64,
+        quantity: f64,
+    }
+
+    impl SanityCheck for Trade {
+        type Key = &'static str;
+
+        fn key(&self) -> Self::Key {
+            self.instrument
+        }
+
+        fn price(&self) -> Option<f64> {
+            Some(self.price)
+        }
+
+        fn quantity(&self) -> Option<f64> {
+            Some(self.quantity)
+        }
+    }
+
+    fn trade(instrument: &'static str, price: f64, quantity: f64) -> Result<Trade, ()> {
+        Ok(Trade {
+            instrument,
+            price,
+            quantity,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_sanity_guard() {
+        let inputs = vec![
+            trade("btc", 100.0, 1.0),
+            trade("btc", 1000.0, 1.0),
+            trade("btc", 105.0, -1.0),
+            trade("btc", 108.0, 1.0),
+            trade("eth", 10.0, 1.0),
+            trade("eth", 1.0, 1.0),
+        ];
+
+        let limits = SanityLimits {
+            max_price_deviation: Some(0.1),
+            ..SanityLimits::default()
+        };
+
+        let actual = SanityGuard::new(stream::iter(inputs), limits)
+            .with_instrument(
+                "eth",
+                SanityLimits {
+                    action: GuardAction::Reject,
+                    ..limits
+                },
+            )
+            .map(|checked| {
+                let checked = checked.unwrap();
+                (checked.event.price, checked.violations)
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        // Corrupt btc prints are tagged without poisoning the last price, corrupt eth dropped
+        let expected = vec![
+            (100.0, vec![]),
+            (
+                1000.0,
+                vec![Violation::PriceDeviation {
+                    price: 1000.0,
+                    last: 100.0,
+                }],
+            ),
+            (105.0, vec![Violation::InvalidQuantity(-1.0)]),
+            (108.0, vec![]),
+            (10.0, vec![]),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_sanity_guard_reanchor() {
+        let limits = SanityLimits {
+            max_price_deviation: Some(0.1),
+            reanchor_after: Some(2),
+            ..SanityLimits::default()
+        };
+
+        // Genuine gap move from 100 to 200 is accepted as the reference after 2 deviating prints
+        let inputs = vec![
+            trade("btc", 100.0, 1.0),
+            trade("btc", 200.0, 1.0),
+            trade("btc", 201.0, 1.0),
+            trade("btc", 202.0, 1.0),
+        ];
+        let actual = SanityGuard::new(stream::iter(inputs), limits)
+            .map(|checked| checked.unwrap().is_valid())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual, vec![true, false, false, true]);
+
+        // Stale reference price is not measured against
+        let limits = SanityLimits {
+            reanchor_after: None,
+            max_anchor_age: Some(Duration::from_millis(10)),
+            ..limits
+        };
+        let (tx, rx) = futures::channel::mpsc::unbounded();
+        let mut guard = SanityGuard::new(rx, limits);
+
+        tx.unbounded_send(trade("btc", 100.0, 1.0)).unwrap();
+        tx.unbounded_send(trade("btc", 200.0, 1.0)).unwrap();
+        assert!(guard.next().await.unwrap().unwrap().is_valid());
+        assert!(!guard.next().await.unwrap().unwrap().is_valid());
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        tx.unbounded_send(trade("btc", 200.0, 1.0)).unwrap();
+        tx.unbounded_send(trade("btc", 201.0, 1.0)).unwrap();
+        assert!(guard.next().await.unwrap().unwrap().is_valid());
+        assert!(guard.next().await.unwrap().unwrap().is_valid());
+    }
+}
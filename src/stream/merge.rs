@@ -0,0 +1,129 @@
+use futures::{Stream, StreamExt};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Merges many labelled [`Stream`]s (eg/ [`ExchangeStream`](crate::ExchangeStream)s of different
+/// exchanges) into one output [`Stream`] of `(Label, Item)`.
+///
+/// Inner [`Stream`]s are polled in round-robin order, starting after the [`Stream`] that most
+/// recently yielded an item. This ensures one chatty or faulty [`Stream`] cannot starve the
+/// others. Exhausted inner [`Stream`]s are removed, and the [`StreamMerge`] ends once every inner
+/// [`Stream`] has ended.
+#[derive(Debug)]
+pub struct StreamMerge<Label, St> {
+    streams: Vec<(Label, St)>,
+    next: usize,
+}
+
+impl<Label, St> Default for StreamMerge<Label, St> {
+    fn default() -> Self {
+        Self {
+            streams: Vec::new(),
+            next: 0,
+        }
+    }
+}
+
+impl<Label, St> FromIterator<(Label, St)> for StreamMerge<Label, St> {
+    fn from_iter<Iter: IntoIterator<Item = (Label, St)>>(iter: Iter) -> Self {
+        Self {
+            streams: iter.into_iter().collect(),
+            next: 0,
+        }
+    }
+}
+
+impl<Label, St> StreamMerge<Label, St> {
+    /// Construct a new empty [`StreamMerge`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a labelled [`Stream`] to the [`StreamMerge`].
+    pub fn push(&mut self, label: Label, stream: St) {
+        self.streams.push((label, stream));
+    }
+
+    /// Number of inner [`Stream`]s that have not yet ended.
+    pub fn len(&self) -> usize {
+        self.streams.len()
+    }
+
+    /// Determine if every inner [`Stream`] has ended.
+    pub fn is_empty(&self) -> bool {
+        self.streams.is_empty()
+    }
+}
+
+// Inner Streams are required to be Unpin, and are never pinned in place
+impl<Label, St> Unpin for StreamMerge<Label, St> {}
+
+impl<Label, St> Stream for StreamMerge<Label, St>
+where
+    Label: Clone,
+    St: Stream + Unpin,
+{
+    type Item = (Label, St::Item);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut pending = 0;
+
+        while pending < self.streams.len() {
+            let index = self.next % self.streams.len();
+
+            match self.streams[index].1.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => {
+                    self.next = index + 1;
+                    return Poll::Ready(Some((self.streams[index].0.clone(), item)));
+                }
+                Poll::Ready(None) => {
+                    // Remove ended Stream, next index now refers to the following Stream
+                    self.streams.remove(index);
+                    self.next = index;
+                }
+                Poll::Pending => {
+                    self.next = index + 1;
+                    pending += 1;
+                }
+            }
+        }
+
+        if self.streams.is_empty() {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::stream;
+
+    #[tokio::test]
+    async fn test_stream_merge_round_robin() {
+        let merged = StreamMerge::from_iter([
+            ("chatty", stream::iter(vec![1, 2, 3, 4, 5])),
+            ("quiet", stream::iter(vec![10])),
+            ("medium", stream::iter(vec![20, 21])),
+        ]);
+
+        let actual = merged.collect::<Vec<_>>().await;
+
+        let expected = vec![
+            ("chatty", 1),
+            ("quiet", 10),
+            ("medium", 20),
+            ("chatty", 2),
+            ("medium", 21),
+            ("chatty", 3),
+            ("chatty", 4),
+            ("chatty", 5),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,44 @@
+/// Fair round-robin [`Stream`](futures::Stream) combinator that merges many labelled streams.
+pub mod merge;
+
+/// [`Stream`](futures::Stream) adapter that detects sequence gaps for each
+/// [`SubscriptionId`](crate::model::SubscriptionId).
+pub mod sequenced;
+
+/// [`Stream`](futures::Stream) adapter that filters duplicate items using a bounded LRU window.
+pub mod dedup;
+
+/// Opt-in throughput & latency statistics for an [`ExchangeStream`](crate::ExchangeStream).
+pub mod stats;
+
+/// [`Stream`](futures::Stream) adapter that yields a read timeout error when no item arrives in
+/// time.
+pub mod timeout;
+
+/// [`Stream`](futures::Stream) adapter that tees every raw protocol message to a pluggable writer
+/// before parsing, enabling production sessions to be captured for debugging & replay.
+pub mod record;
+
+/// Replays previously recorded raw frames through the same parsing & transformation pipeline as a
+/// live session, enabling backtesting.
+pub mod replay;
+
+/// [`Stream`](futures::Stream) adapter that maintains message counters & last update timestamps
+/// for each [`SubscriptionId`](crate::model::SubscriptionId), optionally resubscribing individually
+/// dead channels.
+pub mod subscription;
+
+/// Cloneable handle used to pause & resume an [`ExchangeStream`](crate::ExchangeStream).
+pub mod pause;
+
+/// Context (time budget, cancellation & connection metadata) provided to each
+/// [`Transformer`](crate::Transformer) invocation.
+pub mod context;
+
+/// [`Stream`](futures::Stream) adapter that sanity checks normalised outputs, tagging or
+/// rejecting obviously corrupt events before they reach trading logic.
+pub mod guard;
+
+/// Coalesces duplicate subscriptions into a single wire subscription, fanning results out to
+/// each requester.
+pub mod coalesce;
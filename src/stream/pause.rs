@@ -0,0 +1,55 @@
+use futures::task::AtomicWaker;
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::Context,
+};
+
+/// Cheaply cloneable handle used to pause & resume an [`ExchangeStream`](crate::ExchangeStream)
+/// from any task.
+///
+/// Whilst paused, the [`ExchangeStream`](crate::ExchangeStream) stops pulling messages from its
+/// inner stream, such that they are left unread (applying TCP backpressure) & yielded in order
+/// once resumed.
+#[derive(Debug, Clone, Default)]
+pub struct PauseHandle {
+    inner: Arc<PauseState>,
+}
+
+#[derive(Debug, Default)]
+struct PauseState {
+    paused: AtomicBool,
+    waker: AtomicWaker,
+}
+
+impl PauseHandle {
+    /// Stop pulling messages from the inner stream.
+    pub fn pause(&self) {
+        self.inner.paused.store(true, Ordering::Release);
+    }
+
+    /// Resume pulling messages from the inner stream, waking the paused consumer.
+    pub fn resume(&self) {
+        self.inner.paused.store(false, Ordering::Release);
+        self.inner.waker.wake();
+    }
+
+    /// Determine if the stream is currently paused.
+    pub fn is_paused(&self) -> bool {
+        self.inner.paused.load(Ordering::Acquire)
+    }
+
+    /// Determine if the stream is paused, registering the [`Context`] waker to be woken upon
+    /// [`resume`](Self::resume) if so.
+    pub(crate) fn poll_paused(&self, cx: &mut Context<'_>) -> bool {
+        if !self.is_paused() {
+            return false;
+        }
+
+        // Register before re-checking to avoid missing a concurrent resume
+        self.inner.waker.register(cx.waker());
+        self.is_paused()
+    }
+}
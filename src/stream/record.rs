@@ -0,0 +1,176 @@
+use crate::{error::SocketError, protocol::websocket::WsMessage};
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use pin_project::pin_project;
+use serde::{Deserialize, Serialize};
+use std::{
+    io::Write,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Raw protocol message captured by a [`Recorder`], along with the time it was received.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RecordedFrame {
+    pub received: DateTime<Utc>,
+    pub payload: RecordedPayload,
+}
+
+/// Raw payload of a [`RecordedFrame`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum RecordedPayload {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// Raw protocol messages that can be captured by a [`Recorder`].
+pub trait Recordable {
+    /// [`RecordedPayload`] of this message, or `None` if it should not be recorded
+    /// (eg/ WebSocket pings).
+    fn to_recorded(&self) -> Option<RecordedPayload>;
+}
+
+impl Recordable for WsMessage {
+    fn to_recorded(&self) -> Option<RecordedPayload> {
+        match self {
+            WsMessage::Text(text) => Some(RecordedPayload::Text(text.clone())),
+            WsMessage::Binary(binary) => Some(RecordedPayload::Binary(binary.clone())),
+            _ => None,
+        }
+    }
+}
+
+impl Recordable for String {
+    fn to_recorded(&self) -> Option<RecordedPayload> {
+        Some(RecordedPayload::Text(self.clone()))
+    }
+}
+
+/// Pluggable destination for the [`RecordedFrame`]s captured by a [`Recorder`].
+pub trait FrameWriter {
+    fn write(&mut self, frame: RecordedFrame) -> Result<(), SocketError>;
+}
+
+impl FrameWriter for mpsc::UnboundedSender<RecordedFrame> {
+    fn write(&mut self, frame: RecordedFrame) -> Result<(), SocketError> {
+        self.send(frame).map_err(|_| SocketError::Sink)
+    }
+}
+
+/// [`FrameWriter`] that writes each [`RecordedFrame`] as a line of JSON.
+///
+/// eg/ Writing to a `BufWriter<File>` to capture a production session for later replay.
+#[derive(Debug)]
+pub struct JsonLinesWriter<W> {
+    pub writer: W,
+}
+
+impl<W> JsonLinesWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+}
+
+impl<W> FrameWriter for JsonLinesWriter<W>
+where
+    W: Write,
+{
+    fn write(&mut self, frame: RecordedFrame) -> Result<(), SocketError> {
+        serde_json::to_writer(&mut self.writer, &frame).map_err(SocketError::Serialise)?;
+        self.writer.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+/// [`Stream`] adapter that tees every raw protocol message, with its receive timestamp, to a
+/// pluggable [`FrameWriter`] before it is parsed.
+///
+/// Wrap the inner protocol [`Stream`] (eg/ a [`WebSocket`](crate::protocol::websocket::WebSocket))
+/// before constructing an [`ExchangeStream`](crate::ExchangeStream), so production sessions can be
+/// captured for debugging & backtesting. Failure to write a frame is logged, and does not
+/// interrupt the inner [`Stream`].
+#[derive(Debug)]
+#[pin_project]
+pub struct Recorder<St, W> {
+    #[pin]
+    stream: St,
+    writer: W,
+}
+
+impl<St, W> Recorder<St, W> {
+    /// Construct a new [`Recorder`] that tees every raw protocol message to the provided
+    /// [`FrameWriter`].
+    pub fn new(stream: St, writer: W) -> Self {
+        Self { stream, writer }
+    }
+
+    /// Consume the [`Recorder`], returning the inner [`Stream`] & [`FrameWriter`].
+    pub fn into_inner(self) -> (St, W) {
+        (self.stream, self.writer)
+    }
+}
+
+impl<St, Message, E, W> Stream for Recorder<St, W>
+where
+    St: Stream<Item = Result<Message, E>>,
+    Message: Recordable,
+    W: FrameWriter,
+{
+    type Item = Result<Message, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let next = this.stream.poll_next(cx);
+
+        if let Poll::Ready(Some(Ok(message))) = &next {
+            if let Some(payload) = message.to_recorded() {
+                let frame = RecordedFrame {
+                    received: Utc::now(),
+                    payload,
+                };
+                if let Err(error) = this.writer.write(frame) {
+                    warn!(?error, "Recorder failed to write RecordedFrame");
+                }
+            }
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+
+    #[tokio::test]
+    async fn test_recorder_tees_raw_frames() {
+        let inputs = vec![
+            Ok(WsMessage::text("trade")),
+            Ok(WsMessage::Ping(vec![1])),
+            Err(()),
+            Ok(WsMessage::Binary(vec![1, 2])),
+        ];
+
+        let (frame_tx, mut frame_rx) = mpsc::unbounded_channel();
+        let outputs = Recorder::new(stream::iter(inputs.clone()), frame_tx)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(outputs, inputs);
+
+        let mut payloads = Vec::new();
+        while let Ok(frame) = frame_rx.try_recv() {
+            payloads.push(frame.payload);
+        }
+        assert_eq!(
+            payloads,
+            vec![
+                RecordedPayload::Text("trade".to_string()),
+                RecordedPayload::Binary(vec![1, 2])
+            ]
+        );
+    }
+}
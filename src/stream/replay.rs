@@ -0,0 +1,286 @@
+use crate::{
+    error::SocketError,
+    protocol::websocket::WsMessage,
+    runtime::{Runtime, TokioRuntime},
+    stream::record::{RecordedFrame, RecordedPayload},
+};
+use chrono::{DateTime, Utc};
+use futures::{stream::BoxStream, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Formatter},
+    path::Path,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, BufReader};
+
+/// Determines the pace at which a [`ReplayStream`] yields [`RecordedFrame`]s.
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub enum ReplayTiming {
+    /// Yield every frame as fast as possible.
+    #[default]
+    AsFastAsPossible,
+    /// Honour the original inter-message timing, scaled by the speed multiplier.
+    ///
+    /// eg/ A speed of 2.0 replays the session twice as fast as it was recorded.
+    ///
+    /// The speed must be finite & greater than zero.
+    Original { speed: f64 },
+}
+
+impl ReplayTiming {
+    /// Construct a validated [`ReplayTiming::Original`] with the provided speed multiplier.
+    pub fn original(speed: f64) -> Result<Self, SocketError> {
+        Self::Original { speed }.validate()
+    }
+
+    /// Validate the [`ReplayTiming`], rejecting a non-finite or non-positive speed.
+    pub fn validate(self) -> Result<Self, SocketError> {
+        match self {
+            Self::Original { speed } if !speed.is_finite() || speed <= 0.0 => {
+                Err(SocketError::InvalidConfig(format!(
+                    "ReplayTiming speed must be finite & greater than zero, got {speed}"
+                )))
+            }
+            timing => Ok(timing),
+        }
+    }
+}
+
+/// Protocol messages that can be reconstructed from a [`RecordedPayload`].
+pub trait Replayable {
+    fn from_recorded(payload: RecordedPayload) -> Self;
+}
+
+impl Replayable for WsMessage {
+    fn from_recorded(payload: RecordedPayload) -> Self {
+        match payload {
+            RecordedPayload::Text(text) => WsMessage::Text(text),
+            RecordedPayload::Binary(binary) => WsMessage::Binary(binary),
+        }
+    }
+}
+
+impl Replayable for String {
+    fn from_recorded(payload: RecordedPayload) -> Self {
+        match payload {
+            RecordedPayload::Text(text) => text,
+            RecordedPayload::Binary(binary) => {
+                String::from_utf8(binary).unwrap_or_else(|error| error.to_string())
+            }
+        }
+    }
+}
+
+/// [`Stream`] of protocol messages replayed from [`RecordedFrame`]s previously captured by a
+/// [`Recorder`](super::record::Recorder) using a
+/// [`JsonLinesWriter`](super::record::JsonLinesWriter).
+///
+/// Used as the inner [`Stream`] of an [`ExchangeStream`](crate::ExchangeStream), replayed frames
+/// are fed through the same [`StreamParser`](crate::protocol::StreamParser) &
+/// [`Transformer`](crate::Transformer) pipeline as a live session, enabling backtesting.
+///
+/// eg/ `ExchangeStream<WebSocketParser, ReplayStream<WsMessage, WsError>, Transformer>`
+pub struct ReplayStream<Message, Error> {
+    inner: BoxStream<'static, Result<Message, Error>>,
+}
+
+impl<Message, Error> Debug for ReplayStream<Message, Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReplayStream").finish_non_exhaustive()
+    }
+}
+
+impl<Message, Error> ReplayStream<Message, Error>
+where
+    Message: Replayable + Send + 'static,
+    Error: From<std::io::Error> + Send + 'static,
+{
+    /// Open a file of [`RecordedFrame`] JSON lines for replay.
+    ///
+    /// The file is read via tokio, so must be called from within a Tokio runtime.
+    pub async fn open<P>(path: P, timing: ReplayTiming) -> Result<Self, SocketError>
+    where
+        P: AsRef<Path>,
+    {
+        let timing = timing.validate()?;
+        let file = tokio::fs::File::open(path).await?;
+        Self::from_reader(BufReader::new(file), timing)
+    }
+
+    /// Construct a new [`ReplayStream`] from a reader of [`RecordedFrame`] JSON lines.
+    ///
+    /// Fails if the [`ReplayTiming`] is invalid (see [`ReplayTiming::validate`]).
+    ///
+    /// Must be called from within a Tokio runtime, see
+    /// [`from_reader_with_runtime`](Self::from_reader_with_runtime) for alternative executors.
+    pub fn from_reader<Reader>(reader: Reader, timing: ReplayTiming) -> Result<Self, SocketError>
+    where
+        Reader: AsyncBufRead + Unpin + Send + 'static,
+    {
+        Self::from_reader_with_runtime(reader, timing, TokioRuntime)
+    }
+
+    /// Construct a new [`ReplayStream`] from a reader of [`RecordedFrame`] JSON lines, using the
+    /// provided [`Runtime`] to honour [`ReplayTiming::Original`] inter-message gaps.
+    ///
+    /// Fails if the [`ReplayTiming`] is invalid (see [`ReplayTiming::validate`]).
+    pub fn from_reader_with_runtime<Reader, R>(
+        reader: Reader,
+        timing: ReplayTiming,
+        runtime: R,
+    ) -> Result<Self, SocketError>
+    where
+        Reader: AsyncBufRead + Unpin + Send + 'static,
+        R: Runtime + 'static,
+    {
+        let timing = timing.validate()?;
+        let runtime: Arc<dyn Runtime> = Arc::new(runtime);
+        let state = (reader.lines(), None::<DateTime<Utc>>);
+
+        let inner = futures::stream::unfold(state, move |(mut lines, previous)| {
+            let runtime = Arc::clone(&runtime);
+            async move {
+                let line = loop {
+                    match lines.next_line().await {
+                        Ok(Some(line)) if line.trim().is_empty() => continue,
+                        Ok(Some(line)) => break line,
+                        Ok(None) => return None,
+                        Err(error) => return Some((Err(Error::from(error)), (lines, previous))),
+                    }
+                };
+
+                let frame = match serde_json::from_str::<RecordedFrame>(&line) {
+                    Ok(frame) => frame,
+                    Err(error) => {
+                        let error = std::io::Error::new(std::io::ErrorKind::InvalidData, error);
+                        return Some((Err(Error::from(error)), (lines, previous)));
+                    }
+                };
+
+                if let (ReplayTiming::Original { speed }, Some(previous)) = (timing, previous) {
+                    if let Ok(gap) = (frame.received - previous).to_std() {
+                        runtime.sleep(gap.div_f64(speed)).await;
+                    }
+                }
+
+                let message = Message::from_recorded(frame.payload);
+                Some((Ok(message), (lines, Some(frame.received))))
+            }
+        });
+
+        Ok(Self {
+            inner: inner.boxed(),
+        })
+    }
+}
+
+impl<Message, Error> Stream for ReplayStream<Message, Error> {
+    type Item = Result<Message, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, Instant};
+
+    fn recorded(lines: &[(i64, &str)]) -> Vec<u8> {
+        let start = Utc::now();
+        lines
+            .iter()
+            .map(|(offset_ms, text)| {
+                let frame = RecordedFrame {
+                    received: start + chrono::Duration::milliseconds(*offset_ms),
+                    payload: RecordedPayload::Text(text.to_string()),
+                };
+                serde_json::to_string(&frame).unwrap() + "\n"
+            })
+            .collect::<String>()
+            .into_bytes()
+    }
+
+    #[tokio::test]
+    async fn test_replay_stream() {
+        struct TestCase {
+            timing: ReplayTiming,
+            min_duration: Duration,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: as fast as possible
+                timing: ReplayTiming::AsFastAsPossible,
+                min_duration: Duration::ZERO,
+            },
+            TestCase {
+                // TC1: original timing at double speed
+                timing: ReplayTiming::Original { speed: 2.0 },
+                min_duration: Duration::from_millis(20),
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let input = recorded(&[(0, "1"), (20, "2"), (40, "3")]);
+
+            let start = Instant::now();
+            let actual = ReplayStream::<String, SocketError>::from_reader(
+                std::io::Cursor::new(input),
+                test.timing,
+            )
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+
+            assert_eq!(actual, vec!["1", "2", "3"], "TC{index} failed");
+            assert!(start.elapsed() >= test.min_duration, "TC{index} failed");
+        }
+    }
+
+    #[cfg(feature = "smol")]
+    #[test]
+    fn test_replay_stream_smol_runtime() {
+        let input = recorded(&[(0, "1"), (20, "2")]);
+
+        let start = Instant::now();
+        let actual = smol::block_on(
+            ReplayStream::<String, SocketError>::from_reader_with_runtime(
+                std::io::Cursor::new(input),
+                ReplayTiming::Original { speed: 1.0 },
+                crate::runtime::SmolRuntime,
+            )
+            .unwrap()
+            .map(Result::unwrap)
+            .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(actual, vec!["1", "2"]);
+        assert!(start.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn test_replay_timing_validate() {
+        assert_eq!(
+            ReplayTiming::original(2.0).unwrap(),
+            ReplayTiming::Original { speed: 2.0 }
+        );
+
+        for speed in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                ReplayTiming::original(speed),
+                Err(SocketError::InvalidConfig(_))
+            ));
+            assert!(ReplayStream::<String, SocketError>::from_reader(
+                std::io::Cursor::new(Vec::new()),
+                ReplayTiming::Original { speed },
+            )
+            .is_err());
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{error::SocketError, model::SubscriptionId};
+use futures::Stream;
+use pin_project::pin_project;
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// [`Stream`] adapter that tracks a monotonically increasing sequence number for each
+/// [`SubscriptionId`], yielding a [`SocketError::SequenceGap`] when updates are missed.
+///
+/// The sequence number & [`SubscriptionId`] of each item are extracted via the user provided
+/// closure. Items without a sequence number are passed through untouched.
+///
+/// When a gap is detected, the [`SocketError::SequenceGap`] is yielded before the item that
+/// revealed it. Stale items (sequence number less than or equal to the last seen) are passed
+/// through untouched, since exchanges commonly re-send updates after reconnecting.
+///
+/// eg/ Essential for maintaining a correct L2 order book.
+#[pin_project]
+pub struct Sequenced<St, T, F> {
+    #[pin]
+    stream: St,
+    sequence: F,
+    last: HashMap<SubscriptionId, u64>,
+    pending: Option<T>,
+}
+
+impl<St, T, F> Debug for Sequenced<St, T, F>
+where
+    St: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sequenced")
+            .field("stream", &self.stream)
+            .field("last", &self.last)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<St, T, E, F> Sequenced<St, T, F>
+where
+    St: Stream<Item = Result<T, E>>,
+    F: for<'a> FnMut(&'a T) -> Option<(&'a SubscriptionId, u64)>,
+{
+    /// Construct a new [`Sequenced`] that extracts the [`SubscriptionId`] & sequence number of
+    /// each item using the provided closure.
+    pub fn new(stream: St, sequence: F) -> Self {
+        Self {
+            stream,
+            sequence,
+            last: HashMap::new(),
+            pending: None,
+        }
+    }
+
+    /// Last sequence number processed for the provided [`SubscriptionId`].
+    pub fn last_sequence(&self, subscription: &SubscriptionId) -> Option<u64> {
+        self.last.get(subscription).copied()
+    }
+}
+
+impl<St, T, E, F> Stream for Sequenced<St, T, F>
+where
+    St: Stream<Item = Result<T, E>>,
+    E: From<SocketError>,
+    F: for<'a> FnMut(&'a T) -> Option<(&'a SubscriptionId, u64)>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        // Yield the item that revealed the previously yielded SequenceGap
+        if let Some(item) = this.pending.take() {
+            return Poll::Ready(Some(Ok(item)));
+        }
+
+        let item = match this.stream.poll_next(cx) {
+            Poll::Ready(Some(Ok(item))) => item,
+            other => return other,
+        };
+
+        let Some((subscription, received)) = (this.sequence)(&item) else {
+            return Poll::Ready(Some(Ok(item)));
+        };
+
+        match this.last.get_mut(subscription) {
+            None => {
+                this.last.insert(subscription.clone(), received);
+            }
+            Some(last) if received > last.saturating_add(1) => {
+                let expected = *last + 1;
+                *last = received;
+                *this.pending = Some(item);
+                return Poll::Ready(Some(Err(E::from(SocketError::SequenceGap {
+                    expected,
+                    received,
+                }))));
+            }
+            Some(last) if received > *last => *last = received,
+            Some(_) => {}
+        }
+
+        Poll::Ready(Some(Ok(item)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+
+    #[tokio::test]
+    async fn test_sequenced_detects_gaps() {
+        let btc = SubscriptionId::from("btc");
+        let eth = SubscriptionId::from("eth");
+
+        let inputs = vec![
+            (btc.clone(), 1),
+            (eth.clone(), 50),
+            (btc.clone(), 2),
+            (btc.clone(), 5),
+            (eth.clone(), 51),
+            (btc.clone(), 5),
+            (btc.clone(), 6),
+        ];
+
+        let actual = Sequenced::new(
+            stream::iter(inputs.into_iter().map(Ok::<_, SocketError>)),
+            |(id, sequence): &(SubscriptionId, u64)| Some((id, *sequence)),
+        )
+        .map(|result| match result {
+            Ok((id, sequence)) => Ok((id, sequence)),
+            Err(SocketError::SequenceGap { expected, received }) => Err((expected, received)),
+            Err(error) => panic!("unexpected error: {error}"),
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+        let expected = vec![
+            Ok((btc.clone(), 1)),
+            Ok((eth.clone(), 50)),
+            Ok((btc.clone(), 2)),
+            Err((3, 5)),
+            Ok((btc.clone(), 5)),
+            Ok((eth, 51)),
+            Ok((btc.clone(), 5)),
+            Ok((btc, 6)),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::metric::{Field, Metric, MetricSink};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Opt-in throughput & latency statistics for an [`ExchangeStream`](crate::ExchangeStream).
+///
+/// Tracks messages & bytes received, transform latency, and the output buffer depth. Statistics
+/// can be inspected via a [`StreamStatsSnapshot`], and optionally published periodically as
+/// [`Metric`]s.
+#[derive(Debug)]
+pub struct StreamStats {
+    started: Instant,
+    messages: u64,
+    bytes: u64,
+    outputs: u64,
+    transforms: u64,
+    transform_time: Duration,
+    buffer_depth: usize,
+    publisher: Option<StatsPublisher>,
+}
+
+#[derive(Debug)]
+struct StatsPublisher {
+    sink: Box<dyn MetricSink>,
+    interval: Duration,
+    last_published: Instant,
+}
+
+impl Default for StreamStats {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            messages: 0,
+            bytes: 0,
+            outputs: 0,
+            transforms: 0,
+            transform_time: Duration::ZERO,
+            buffer_depth: 0,
+            publisher: None,
+        }
+    }
+}
+
+impl StreamStats {
+    /// Construct a new [`StreamStats`] with zeroed counters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Periodically publish a [`StreamStatsSnapshot`] [`Metric`] to the provided [`MetricSink`].
+    pub fn with_publisher<Sink>(self, sink: Sink, interval: Duration) -> Self
+    where
+        Sink: MetricSink + 'static,
+    {
+        Self {
+            publisher: Some(StatsPublisher {
+                sink: Box::new(sink),
+                interval,
+                last_published: Instant::now(),
+            }),
+            ..self
+        }
+    }
+
+    /// Record the receipt of a protocol message of the provided size in bytes.
+    pub fn record_message(&mut self, bytes: usize) {
+        self.messages += 1;
+        self.bytes += bytes as u64;
+    }
+
+    /// Record the transformation of a protocol message into outputs.
+    pub fn record_transform(&mut self, duration: Duration, outputs: usize, buffer_depth: usize) {
+        self.transforms += 1;
+        self.transform_time += duration;
+        self.outputs += outputs as u64;
+        self.buffer_depth = buffer_depth;
+        self.publish_if_due();
+    }
+
+    /// Generate a [`StreamStatsSnapshot`] of the current statistics.
+    ///
+    /// Rates are averaged over the entire time elapsed since collection began.
+    pub fn snapshot(&self) -> StreamStatsSnapshot {
+        let elapsed = self.started.elapsed();
+        let elapsed_secs = elapsed.as_secs_f64().max(f64::EPSILON);
+
+        StreamStatsSnapshot {
+            elapsed,
+            messages: self.messages,
+            bytes: self.bytes,
+            outputs: self.outputs,
+            messages_per_sec: self.messages as f64 / elapsed_secs,
+            bytes_per_sec: self.bytes as f64 / elapsed_secs,
+            mean_transform_latency: match self.transforms {
+                0 => Duration::ZERO,
+                transforms => Duration::from_nanos(
+                    (self.transform_time.as_nanos() / transforms as u128) as u64,
+                ),
+            },
+            buffer_depth: self.buffer_depth,
+        }
+    }
+
+    fn publish_if_due(&mut self) {
+        let Some(publisher) = &self.publisher else {
+            return;
+        };
+
+        if publisher.last_published.elapsed() < publisher.interval {
+            return;
+        }
+
+        let metric = Metric::from(&self.snapshot());
+        if let Some(publisher) = &mut self.publisher {
+            publisher.last_published = Instant::now();
+            publisher.sink.send(metric);
+        }
+    }
+}
+
+/// Snapshot of [`StreamStats`] at a point in time.
+///
+/// A persistently high `buffer_depth` indicates the consumer is lagging behind the stream.
+#[derive(Copy, Clone, PartialEq, Debug, Serialize)]
+pub struct StreamStatsSnapshot {
+    /// Time elapsed since statistics collection began.
+    pub elapsed: Duration,
+    pub messages: u64,
+    pub bytes: u64,
+    pub outputs: u64,
+    pub messages_per_sec: f64,
+    pub bytes_per_sec: f64,
+    pub mean_transform_latency: Duration,
+    /// Number of outputs buffered awaiting consumption after the most recent transform.
+    pub buffer_depth: usize,
+}
+
+impl From<&StreamStatsSnapshot> for Metric {
+    fn from(snapshot: &StreamStatsSnapshot) -> Self {
+        Metric {
+            name: "exchange_stream_stats",
+            time: chrono::Utc::now().timestamp_millis() as u64,
+            tags: vec![],
+            fields: vec![
+                Field::new("messages", snapshot.messages),
+                Field::new("bytes", snapshot.bytes),
+                Field::new("outputs", snapshot.outputs),
+                Field::new("messages_per_sec", snapshot.messages_per_sec),
+                Field::new("bytes_per_sec", snapshot.bytes_per_sec),
+                Field::new(
+                    "mean_transform_latency_us",
+                    snapshot.mean_transform_latency.as_micros() as u64,
+                ),
+                Field::new("buffer_depth", snapshot.buffer_depth as u64),
+            ],
+        }
+    }
+}
@@ -0,0 +1,337 @@
+use crate::model::SubscriptionId;
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use pin_project::pin_project;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tracing::warn;
+
+/// Message counters & update timestamps for a single [`SubscriptionId`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct SubscriptionCounters {
+    pub messages: u64,
+    pub first_update: Option<DateTime<Utc>>,
+    pub last_update: Option<DateTime<Utc>>,
+    /// Number of times this subscription has been automatically resubscribed.
+    pub resubscribes: u64,
+    pub last_resubscribe: Option<DateTime<Utc>>,
+}
+
+impl SubscriptionCounters {
+    /// Average messages per second received between the first & last update.
+    pub fn messages_per_sec(&self) -> f64 {
+        let (Some(first), Some(last)) = (self.first_update, self.last_update) else {
+            return 0.0;
+        };
+
+        match (last - first).to_std() {
+            Ok(window) if !window.is_zero() => self.messages as f64 / window.as_secs_f64(),
+            _ => 0.0,
+        }
+    }
+
+    /// Determine if no update has been received within the provided [`Duration`].
+    pub fn is_stale(&self, now: DateTime<Utc>, threshold: Duration) -> bool {
+        match self.last_update {
+            Some(last_update) => (now - last_update)
+                .to_std()
+                .is_ok_and(|elapsed| elapsed > threshold),
+            None => true,
+        }
+    }
+}
+
+/// Policy determining when a [`SubscriptionStats`] automatically resubscribes an individually
+/// dead channel.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Deserialize, Serialize)]
+pub struct ResubscribePolicy {
+    /// Duration a channel must be quiet, while others continue to flow, to be considered dead.
+    pub quiet_threshold: Duration,
+
+    /// Minimum duration between consecutive resubscribes of the same channel.
+    pub cooldown: Duration,
+
+    /// Minimum duration between consecutive checks for dead channels.
+    pub check_interval: Duration,
+}
+
+impl Default for ResubscribePolicy {
+    fn default() -> Self {
+        Self {
+            quiet_threshold: Duration::from_secs(60),
+            cooldown: Duration::from_secs(300),
+            check_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// [`Stream`] adapter that maintains [`SubscriptionCounters`] for each [`SubscriptionId`],
+/// extracted from each item via the user provided closure.
+///
+/// Enables a single dead channel (eg/ one symbol silently dropped by the exchange) to be spotted
+/// among hundreds of healthy ones. Register the expected subscriptions via
+/// [`with_subscriptions`](Self::with_subscriptions) so channels that never produce an update are
+/// also reported as stale.
+///
+/// Optionally, individually dead channels can be automatically resubscribed via
+/// [`with_auto_resubscribe`](Self::with_auto_resubscribe), avoiding full connection resets for
+/// single channel failures.
+#[pin_project]
+pub struct SubscriptionStats<St, F> {
+    #[pin]
+    stream: St,
+    subscription: F,
+    stats: HashMap<SubscriptionId, SubscriptionCounters>,
+    started: DateTime<Utc>,
+    resubscribe: Option<AutoResubscribe>,
+}
+
+struct AutoResubscribe {
+    policy: ResubscribePolicy,
+    action: Box<dyn FnMut(&SubscriptionId) + Send>,
+    last_check: DateTime<Utc>,
+}
+
+impl<St, F> Debug for SubscriptionStats<St, F>
+where
+    St: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SubscriptionStats")
+            .field("stream", &self.stream)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AutoResubscribe {
+    /// Resubscribe every channel that is quiet while at least one other channel is flowing.
+    fn check(
+        &mut self,
+        stats: &mut HashMap<SubscriptionId, SubscriptionCounters>,
+        started: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) {
+        let elapsed = |since: DateTime<Utc>| (now - since).to_std().unwrap_or_default();
+
+        if elapsed(self.last_check) < self.policy.check_interval
+            || elapsed(started) <= self.policy.quiet_threshold
+        {
+            return;
+        }
+        self.last_check = now;
+
+        let threshold = self.policy.quiet_threshold;
+        if stats
+            .values()
+            .all(|counters| counters.is_stale(now, threshold))
+        {
+            // Every channel is quiet, so this is a connection level problem
+            return;
+        }
+
+        for (subscription, counters) in stats.iter_mut() {
+            let cooling_down = counters
+                .last_resubscribe
+                .is_some_and(|last| elapsed(last) < self.policy.cooldown);
+
+            if counters.is_stale(now, threshold) && !cooling_down {
+                warn!(%subscription, "resubscribing individually dead channel");
+                (self.action)(subscription);
+                counters.resubscribes += 1;
+                counters.last_resubscribe = Some(now);
+            }
+        }
+    }
+}
+
+impl<St, T, E, F> SubscriptionStats<St, F>
+where
+    St: Stream<Item = Result<T, E>>,
+    F: for<'a> FnMut(&'a T) -> Option<&'a SubscriptionId>,
+{
+    /// Construct a new [`SubscriptionStats`] that extracts the [`SubscriptionId`] of each item
+    /// using the provided closure.
+    pub fn new(stream: St, subscription: F) -> Self {
+        Self {
+            stream,
+            subscription,
+            stats: HashMap::new(),
+            started: Utc::now(),
+            resubscribe: None,
+        }
+    }
+
+    /// Automatically resubscribe channels that go quiet beyond the [`ResubscribePolicy`]
+    /// threshold while other channels continue to flow.
+    ///
+    /// The provided action is invoked with the dead [`SubscriptionId`], and is expected to send
+    /// the exchange specific unsubscribe & subscribe messages (eg/ via the channel feeding the
+    /// WebSocket sink).
+    pub fn with_auto_resubscribe<Action>(
+        mut self,
+        policy: ResubscribePolicy,
+        action: Action,
+    ) -> Self
+    where
+        Action: FnMut(&SubscriptionId) + Send + 'static,
+    {
+        self.resubscribe = Some(AutoResubscribe {
+            policy,
+            action: Box::new(action),
+            last_check: Utc::now(),
+        });
+        self
+    }
+
+    /// Register the expected [`SubscriptionId`]s, such that those which never produce an update
+    /// are still reported.
+    pub fn with_subscriptions<Ids>(mut self, subscriptions: Ids) -> Self
+    where
+        Ids: IntoIterator<Item = SubscriptionId>,
+    {
+        for subscription in subscriptions {
+            self.stats.entry(subscription).or_default();
+        }
+        self
+    }
+}
+
+impl<St, F> SubscriptionStats<St, F> {
+    /// [`SubscriptionCounters`] for every observed or registered [`SubscriptionId`].
+    pub fn stats_per_subscription(&self) -> &HashMap<SubscriptionId, SubscriptionCounters> {
+        &self.stats
+    }
+
+    /// [`SubscriptionId`]s that have not received an update within the provided [`Duration`].
+    pub fn stale(&self, threshold: Duration) -> Vec<&SubscriptionId> {
+        let now = Utc::now();
+        self.stats
+            .iter()
+            .filter(|(_, counters)| counters.is_stale(now, threshold))
+            .map(|(subscription, _)| subscription)
+            .collect()
+    }
+}
+
+impl<St, T, E, F> Stream for SubscriptionStats<St, F>
+where
+    St: Stream<Item = Result<T, E>>,
+    F: for<'a> FnMut(&'a T) -> Option<&'a SubscriptionId>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let next = this.stream.poll_next(cx);
+
+        if let Poll::Ready(Some(Ok(item))) = &next {
+            if let Some(subscription) = (this.subscription)(item) {
+                let now = Utc::now();
+                let counters = match this.stats.get_mut(subscription) {
+                    Some(counters) => counters,
+                    None => this.stats.entry(subscription.clone()).or_default(),
+                };
+                counters.messages += 1;
+                counters.first_update.get_or_insert(now);
+                counters.last_update = Some(now);
+            }
+
+            if let Some(resubscribe) = this.resubscribe {
+                resubscribe.check(this.stats, *this.started, Utc::now());
+            }
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{stream, StreamExt};
+
+    fn subscription_id(id: &SubscriptionId) -> Option<&SubscriptionId> {
+        Some(id)
+    }
+
+    #[tokio::test]
+    async fn test_subscription_stats() {
+        let inputs = vec![
+            Ok(SubscriptionId::from("btc")),
+            Ok(SubscriptionId::from("eth")),
+            Err(()),
+            Ok(SubscriptionId::from("btc")),
+        ];
+
+        let mut stream = SubscriptionStats::new(stream::iter(inputs), subscription_id)
+            .with_subscriptions([SubscriptionId::from("btc"), SubscriptionId::from("sol")]);
+        while stream.next().await.is_some() {}
+
+        let stats = stream.stats_per_subscription();
+        assert_eq!(stats[&SubscriptionId::from("btc")].messages, 2);
+        assert_eq!(stats[&SubscriptionId::from("eth")].messages, 1);
+        assert_eq!(stats[&SubscriptionId::from("sol")].messages, 0);
+
+        // Only the registered subscription that never updated is stale
+        assert_eq!(
+            stream.stale(Duration::from_secs(60)),
+            vec![&SubscriptionId::from("sol")]
+        );
+    }
+
+    #[test]
+    fn test_auto_resubscribe_dead_channel() {
+        let policy = ResubscribePolicy {
+            quiet_threshold: Duration::from_secs(10),
+            cooldown: Duration::from_secs(60),
+            check_interval: Duration::ZERO,
+        };
+        let now = Utc::now();
+        let ago = |secs| now - chrono::Duration::seconds(secs);
+        let updated = |secs| SubscriptionCounters {
+            messages: 1,
+            first_update: Some(ago(secs)),
+            last_update: Some(ago(secs)),
+            ..Default::default()
+        };
+
+        let resubscribed = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut resubscribe = AutoResubscribe {
+            policy,
+            action: Box::new({
+                let resubscribed = resubscribed.clone();
+                move |id: &SubscriptionId| resubscribed.lock().unwrap().push(id.clone())
+            }),
+            last_check: ago(100),
+        };
+
+        let mut stats = HashMap::from([
+            (SubscriptionId::from("btc"), updated(1)),
+            (SubscriptionId::from("eth"), updated(30)),
+        ]);
+
+        // Quiet "eth" is resubscribed once, then cools down
+        resubscribe.check(&mut stats, ago(100), now);
+        resubscribe.check(&mut stats, ago(100), now);
+        assert_eq!(
+            *resubscribed.lock().unwrap(),
+            vec![SubscriptionId::from("eth")]
+        );
+        assert_eq!(stats[&SubscriptionId::from("eth")].resubscribes, 1);
+
+        // Every channel quiet is a connection level problem, so nothing is resubscribed
+        let mut stats = HashMap::from([
+            (SubscriptionId::from("btc"), updated(30)),
+            (SubscriptionId::from("eth"), updated(30)),
+        ]);
+        resubscribe.check(&mut stats, ago(100), now);
+        assert_eq!(resubscribed.lock().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,101 @@
+use crate::error::SocketError;
+use futures::Stream;
+use pin_project::pin_project;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::time::{Instant, Sleep};
+
+/// [`Stream`] adapter that yields a [`SocketError::ReadTimeout`] whenever no item arrives from
+/// the inner [`Stream`] within the configured [`Duration`].
+///
+/// The deadline is reset after every yielded item (including timeouts), so consumers can drive
+/// their own restart logic (eg/ reconnecting after N consecutive timeouts) without wrapping every
+/// poll in `tokio::time::timeout`.
+#[derive(Debug)]
+#[pin_project]
+pub struct Timeout<St> {
+    #[pin]
+    stream: St,
+    #[pin]
+    deadline: Sleep,
+    duration: Duration,
+}
+
+impl<St> Timeout<St> {
+    /// Construct a new [`Timeout`] that yields a [`SocketError::ReadTimeout`] if no item arrives
+    /// within the provided [`Duration`].
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn new(stream: St, duration: Duration) -> Self {
+        Self {
+            stream,
+            deadline: tokio::time::sleep(duration),
+            duration,
+        }
+    }
+
+    /// Maximum [`Duration`] to wait for each item.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl<St, T, E> Stream for Timeout<St>
+where
+    St: Stream<Item = Result<T, E>>,
+    E: From<SocketError>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let next = match this.stream.poll_next(cx) {
+            Poll::Ready(Some(item)) => item,
+            Poll::Ready(None) => return Poll::Ready(None),
+            Poll::Pending => match this.deadline.as_mut().poll(cx) {
+                Poll::Ready(()) => Err(E::from(SocketError::ReadTimeout(*this.duration))),
+                Poll::Pending => return Poll::Pending,
+            },
+        };
+
+        this.deadline.reset(Instant::now() + *this.duration);
+        Poll::Ready(Some(next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn test_timeout_yields_read_timeout() {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<Result<u64, SocketError>>();
+        let mut stream = std::pin::pin!(Timeout::new(stream_from(rx), Duration::from_millis(20)));
+
+        tx.send(Ok(1)).unwrap();
+        assert!(matches!(stream.next().await, Some(Ok(1))));
+
+        // No items within the deadline
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(SocketError::ReadTimeout(duration))) if duration == Duration::from_millis(20)
+        ));
+
+        // Deadline is reset after a timeout, and items continue to flow
+        tx.send(Ok(2)).unwrap();
+        assert!(matches!(stream.next().await, Some(Ok(2))));
+
+        drop(tx);
+        assert!(stream.next().await.is_none());
+    }
+
+    fn stream_from<T>(mut rx: tokio::sync::mpsc::UnboundedReceiver<T>) -> impl Stream<Item = T> {
+        futures::stream::poll_fn(move |cx| rx.poll_recv(cx))
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{
+    error::SocketError,
+    protocol::http::{private::Signer, rest::RestRequest, HttpParser},
+};
+use hmac::Mac;
+use std::{
+    borrow::Cow,
+    io::{Read, Write},
+    net::TcpListener,
+    sync::mpsc,
+    time::Duration,
+};
+
+/// [`HttpParser`] that maps every JSON API error into a [`SocketError::HttpResponse`].
+#[derive(Debug)]
+pub(crate) struct TestParser;
+
+impl HttpParser for TestParser {
+    type ApiError = serde_json::Value;
+    type OutputError = SocketError;
+
+    fn parse_api_error(
+        &self,
+        status: reqwest::StatusCode,
+        error: Self::ApiError,
+    ) -> Self::OutputError {
+        SocketError::HttpResponse(status, error.to_string())
+    }
+}
+
+/// `GET /ping` [`RestRequest`] with a JSON response.
+#[derive(Debug, Clone)]
+pub(crate) struct Ping;
+
+impl RestRequest for Ping {
+    type Response = serde_json::Value;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/ping")
+    }
+}
+
+/// `GET /account` [`RestRequest`] with a JSON response, for signing tests.
+#[derive(Debug, Clone)]
+pub(crate) struct Account;
+
+impl RestRequest for Account {
+    type Response = serde_json::Value;
+    type QueryParams = ();
+    type Body = ();
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/account")
+    }
+}
+
+/// [`Signer`] that signs the provided payload, adding it as the `x-payload` header alongside the
+/// `signature` header.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct PayloadSigner(pub &'static str);
+
+impl Signer for PayloadSigner {
+    type Config<'a> = &'static str;
+
+    fn config<'a, Request>(
+        &'a self,
+        _: Request,
+        _: &reqwest::RequestBuilder,
+    ) -> Result<Self::Config<'a>, SocketError>
+    where
+        Request: RestRequest,
+    {
+        Ok(self.0)
+    }
+
+    fn add_bytes_to_sign<M>(mac: &mut M, config: &Self::Config<'_>)
+    where
+        M: Mac,
+    {
+        mac.update(config.as_bytes());
+    }
+
+    fn build_signed_request(
+        config: Self::Config<'_>,
+        builder: reqwest::RequestBuilder,
+        signature: String,
+    ) -> Result<reqwest::Request, SocketError> {
+        builder
+            .header("x-payload", config)
+            .header("signature", signature)
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/// Minimal HTTP/1.1 server running on its own thread, such that it serves both async & blocking
+/// clients. Every connection is served one response & closed.
+///
+/// Each raw request received (head & body) is made available via
+/// [`next_request`](Self::next_request).
+#[derive(Debug)]
+pub(crate) struct MockServer {
+    pub base_url: String,
+    requests: mpsc::Receiver<String>,
+}
+
+impl MockServer {
+    /// Serve each connection with the response generated from its raw request, until the
+    /// handler returns `None`.
+    pub(crate) fn start<Handler>(mut handler: Handler) -> Self
+    where
+        Handler: FnMut(&str) -> Option<Vec<u8>> + Send + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, requests) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            for socket in listener.incoming() {
+                let mut socket = socket.unwrap();
+                let request = read_request(&mut socket);
+                let Some(response) = handler(&request) else {
+                    break;
+                };
+                let _ = tx.send(request);
+                let _ = socket.write_all(&response);
+            }
+        });
+
+        Self { base_url, requests }
+    }
+
+    /// Serve the provided responses in order, one per connection.
+    pub(crate) fn responses<Responses>(responses: Responses) -> Self
+    where
+        Responses: IntoIterator<Item = Vec<u8>>,
+        Responses::IntoIter: Send + 'static,
+    {
+        let mut responses = responses.into_iter();
+        Self::start(move |_| responses.next())
+    }
+
+    /// Next raw request received, panicking if none is received within 5 seconds.
+    pub(crate) fn next_request(&self) -> String {
+        self.requests
+            .recv_timeout(Duration::from_secs(5))
+            .expect("MockServer received no request")
+    }
+}
+
+/// Construct a raw HTTP/1.1 response with the provided status (eg/ "200 OK"), headers & body,
+/// adding the `content-length` & `connection: close` headers.
+pub(crate) fn http_response<Body>(status: &str, headers: &[(&str, &str)], body: Body) -> Vec<u8>
+where
+    Body: AsRef<[u8]>,
+{
+    let body = body.as_ref();
+    let mut response = format!("HTTP/1.1 {status}\r\n");
+    for (name, value) in headers {
+        response.push_str(&format!("{name}: {value}\r\n"));
+    }
+    response.push_str(&format!(
+        "content-length: {}\r\nconnection: close\r\n\r\n",
+        body.len()
+    ));
+
+    let mut response = response.into_bytes();
+    response.extend_from_slice(body);
+    response
+}
+
+/// Construct a raw `200 OK` HTTP/1.1 response with the provided body.
+pub(crate) fn ok<Body>(body: Body) -> Vec<u8>
+where
+    Body: AsRef<[u8]>,
+{
+    http_response("200 OK", &[], body)
+}
+
+/// Read a full raw request, using the `content-length` header to determine the body length.
+fn read_request(socket: &mut std::net::TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buffer = [0; 1024];
+
+    loop {
+        let read = socket.read(&mut buffer).unwrap_or(0);
+        request.extend_from_slice(&buffer[..read]);
+
+        let text = String::from_utf8_lossy(&request);
+        if let Some(head_end) = text.find("\r\n\r\n") {
+            let content_length = text[..head_end]
+                .lines()
+                .filter_map(|line| line.split_once(':'))
+                .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+                .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+
+            if request.len() >= head_end + 4 + content_length {
+                return text.into_owned();
+            }
+        }
+
+        if read == 0 {
+            return String::from_utf8_lossy(&request).into_owned();
+        }
+    }
+}
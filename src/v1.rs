@@ -0,0 +1,48 @@
+pub use crate::{
+    error::SocketError,
+    metric::{Field, Metric, MetricCallback, MetricSink, NoopMetricSink, Tag},
+    model::{
+        instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+        Exchange, Market, MarketId, Side, SubscriptionId,
+    },
+    protocol::{
+        http::{
+            private::{
+                encoder::{Base64Encoder, Base64UrlEncoder, Encoder, HexEncoder},
+                placement::SignaturePlacement,
+                secret::Secret,
+                RequestSigner, Signer,
+            },
+            public::PublicNoHeaders,
+            rest::{builder::RestClientBuilder, client::RestClient, RestRequest},
+            BuildStrategy, HttpParser,
+        },
+        websocket::{WebSocket, WebSocketParser, WsError, WsMessage},
+        StreamParser,
+    },
+    ErrorPolicy, ExchangeStream, Output, Transformer, Validator,
+};
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_v1_prelude_surface() {
+        // Prelude items are the same types as their internal paths, so code written against
+        // either interoperates
+        let client: crate::protocol::http::rest::client::RestClient<_, _> =
+            RestClient::builder("https://api.x.com", PublicNoHeaders, ())
+                .build()
+                .unwrap();
+        assert_eq!(client.base_url, "https://api.x.com");
+
+        let instrument: crate::model::instrument::Instrument =
+            Instrument::new("btc", "usdt", InstrumentKind::Spot);
+        assert_eq!(instrument.to_string(), "btc_usdt_spot");
+
+        let error: crate::error::SocketError = SocketError::Sink;
+        assert!(matches!(error, SocketError::Sink));
+        assert_eq!(ErrorPolicy::default(), crate::ErrorPolicy::Emit);
+    }
+}